@@ -0,0 +1,25 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo, cpi::set_return_data, program_error::ProgramError,
+        ProgramResult,
+    },
+    pinocchio_token_2022,
+};
+
+/// Forwards the ui amount returned by the token program as own return data
+pub fn amount_to_ui_amount(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let ui_amount = pinocchio_token_2022::instructions::AmountToUiAmount {
+        mint,
+        amount,
+        token_program: token_program.key(),
+    }
+    .invoke()?;
+
+    set_return_data(&ui_amount);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub fn approve(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    // Expected account layout:
+    //   [ source, delegate, authority, ...signers, token_program ]
+    //
+    // Single authority case:
+    //   [ source, delegate, authority (signer), token_program ]
+    //
+    // Multisig case:
+    //   [ source, delegate, authority, signer1, signer2, ... signer n, token_program ]
+
+    if accounts.len() < 4 {
+        Err(ProgramError::NotEnoughAccountKeys)?;
+    }
+
+    let source = &accounts[0];
+    let delegate = &accounts[1];
+    let authority = &accounts[2];
+    let token_program = accounts.last().unwrap();
+    let signers = &accounts[3..accounts.len() - 1];
+
+    pinocchio_token_2022::instructions::Approve {
+        source,
+        delegate,
+        authority,
+        signers,
+        amount,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,32 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub fn burn(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    // Expected account layout:
+    //   [ account, mint, authority, ...signers, token_program ]
+    //
+    // Single authority case:
+    //   [ account, mint, authority (signer), token_program ]
+    //
+    // Multisig case:
+    //   [ account, mint, authority, signer1, signer2, ... signer n, token_program ]
+
+    if accounts.len() < 4 {
+        Err(ProgramError::NotEnoughAccountKeys)?;
+    }
+
+    let account = &accounts[0];
+    let mint = &accounts[1];
+    let authority = &accounts[2];
+    let token_program = accounts.last().unwrap();
+    let signers = &accounts[3..accounts.len() - 1];
+
+    pinocchio_token_2022::instructions::Burn {
+        account,
+        mint,
+        authority,
+        signers,
+        amount,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
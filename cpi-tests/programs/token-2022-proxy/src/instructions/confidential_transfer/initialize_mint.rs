@@ -0,0 +1,48 @@
+use {
+    crate::helpers::from_optional_non_zero_pubkey,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::confidential_transfer,
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn initialize_mint(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let &spl_token_2022_interface::extension::confidential_transfer::instruction::InitializeMintData {
+        authority,
+        auto_approve_new_accounts,
+        ..
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let authority = from_optional_non_zero_pubkey(authority);
+    let auto_approve_new_accounts = bool::from(auto_approve_new_accounts);
+
+    // The auditor ElGamal pubkey is the last field of the decoded data, read as raw
+    // bytes: all zeros means no auditor
+    let mut auditor_elgamal_pubkey = [0; 32];
+    auditor_elgamal_pubkey.copy_from_slice(&instruction_data[instruction_data.len() - 32..]);
+    let auditor_elgamal_pubkey =
+        (auditor_elgamal_pubkey != [0; 32]).then_some(auditor_elgamal_pubkey);
+
+    if pinocchio_token_2022::state::Mint::from_account_info(mint)?.is_initialized() {
+        let confidential_transfer_mint = pinocchio_token_2022::extension::confidential_transfer::state::ConfidentialTransferMint::from_account_info(mint)?;
+
+        if confidential_transfer_mint.authority() != authority.as_ref() {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        return Ok(());
+    }
+
+    confidential_transfer::InitializeConfidentialTransferMint {
+        mint,
+        authority: authority.as_ref(),
+        auto_approve_new_accounts,
+        auditor_elgamal_pubkey: auditor_elgamal_pubkey.as_ref(),
+        token_program: &token_program.key(),
+    }
+    .invoke()
+}
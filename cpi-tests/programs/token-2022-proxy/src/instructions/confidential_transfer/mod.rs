@@ -0,0 +1,3 @@
+pub mod initialize_mint;
+
+pub use initialize_mint::*;
@@ -0,0 +1,39 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo, cpi::set_return_data, program_error::ProgramError,
+        ProgramResult,
+    },
+    pinocchio_token_2022::{self, extension::consts::ExtensionType as PinocchioExtensionType},
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+/// Forwards the account size returned by the token program as own return data
+pub fn get_account_data_size(
+    accounts: &[AccountInfo],
+    extension_types: &[ExtensionType],
+) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let mut pinocchio_extension_types = [PinocchioExtensionType::Uninitialized; 28];
+    let extension_types_len = extension_types.len();
+    if extension_types_len > pinocchio_extension_types.len() {
+        Err(ProgramError::InvalidInstructionData)?
+    }
+
+    for (i, extension_type) in extension_types.iter().enumerate() {
+        pinocchio_extension_types[i] = PinocchioExtensionType::try_from(*extension_type as u16)?;
+    }
+
+    let size = pinocchio_token_2022::instructions::GetAccountDataSize {
+        mint,
+        extension_types: &pinocchio_extension_types[..extension_types_len],
+        token_program: token_program.key(),
+    }
+    .invoke()?;
+
+    set_return_data(&size.to_le_bytes());
+
+    Ok(())
+}
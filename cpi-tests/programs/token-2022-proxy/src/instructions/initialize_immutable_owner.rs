@@ -0,0 +1,24 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn initialize_immutable_owner(accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    if pinocchio_token_2022::state::TokenAccount::from_account_info(account)?.is_initialized() {
+        pinocchio_token_2022::extension::immutable_owner::state::ImmutableOwner::from_account_info(
+            account,
+        )?;
+
+        return Ok(());
+    }
+
+    pinocchio_token_2022::extension::immutable_owner::InitializeImmutableOwner {
+        account,
+        token_program: &token_program.key(),
+    }
+    .invoke()
+}
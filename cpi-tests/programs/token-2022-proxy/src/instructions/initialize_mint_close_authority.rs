@@ -0,0 +1,35 @@
+use {
+    crate::helpers::from_c_option,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+    solana_address::Address,
+    solana_program_option::COption,
+};
+
+pub fn initialize_mint_close_authority(
+    accounts: &[AccountInfo],
+    close_authority: COption<Address>,
+) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let close_authority = from_c_option(close_authority).map(|x| x.to_bytes());
+
+    if pinocchio_token_2022::state::Mint::from_account_info(mint)?.is_initialized() {
+        let state = pinocchio_token_2022::extension::mint_close_authority::state::MintCloseAuthority::from_account_info(mint)?;
+
+        if state.close_authority() != close_authority.as_ref() {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        return Ok(());
+    }
+
+    pinocchio_token_2022::extension::mint_close_authority::InitializeMintCloseAuthority {
+        mint,
+        close_authority: close_authority.as_ref(),
+        token_program: &token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,24 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn initialize_non_transferable_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    if pinocchio_token_2022::state::Mint::from_account_info(mint)?.is_initialized() {
+        pinocchio_token_2022::extension::non_transferable::state::NonTransferable::from_account_info(
+            mint,
+        )?;
+
+        return Ok(());
+    }
+
+    pinocchio_token_2022::extension::non_transferable::InitializeNonTransferableMint {
+        mint,
+        token_program: &token_program.key(),
+    }
+    .invoke()
+}
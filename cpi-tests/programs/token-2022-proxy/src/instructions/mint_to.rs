@@ -0,0 +1,52 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{
+        self,
+        interface::{InterfaceMint, InterfaceTokenAccount, TokenProgram},
+    },
+};
+
+pub fn mint_to(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    // Expected account layout:
+    //   [ mint, account, mint_authority, ...signers, token_program ]
+    //
+    // Single authority case:
+    //   [ mint, account, mint_authority (signer), token_program ]
+    //
+    // Multisig case:
+    //   [ mint, account, mint_authority, signer1, signer2, ... signer n, token_program ]
+    //
+    // The mint may belong to either the Token or the Token-2022 program.
+
+    if accounts.len() < 4 {
+        Err(ProgramError::NotEnoughAccountKeys)?;
+    }
+
+    let mint = &accounts[0];
+    let account = &accounts[1];
+    let mint_authority = &accounts[2];
+    let token_program = accounts.last().unwrap();
+    let signers = &accounts[3..accounts.len() - 1];
+
+    // the token program is the owner of the mint
+    let program = TokenProgram::from_account_info(mint)?;
+    program.check_program(token_program)?;
+
+    if !InterfaceMint::from_account_info(mint)?.is_initialized() {
+        Err(ProgramError::UninitializedAccount)?;
+    }
+
+    if InterfaceTokenAccount::from_account_info(account)?.mint() != mint.key() {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    pinocchio_token_2022::instructions::MintTo {
+        mint,
+        account,
+        mint_authority,
+        signers,
+        amount,
+        token_program: program.id(),
+    }
+    .invoke()
+}
@@ -1,3 +1,4 @@
+pub mod confidential_transfer;
 pub mod cpi_guard;
 pub mod default_account_state;
 pub mod group_member_pointer;
@@ -8,12 +9,36 @@ pub mod scaled_ui_amount;
 pub mod memo_transfer;
 pub mod metadata_pointer;
 pub mod token_group;
+pub mod token_metadata;
+pub mod transfer_fee;
 pub mod transfer_hook;
 
+mod amount_to_ui_amount;
+mod approve;
+mod burn;
+mod get_account_data_size;
+mod initialize_immutable_owner;
 mod initialize_mint;
+mod initialize_mint_close_authority;
+mod initialize_non_transferable_mint;
 mod initialize_permanent_delegate;
 mod initialize_token_account;
+mod mint_to;
+mod reallocate;
+mod transfer_checked;
+mod ui_amount_to_amount;
 
+pub use amount_to_ui_amount::amount_to_ui_amount;
+pub use approve::approve;
+pub use burn::burn;
+pub use get_account_data_size::get_account_data_size;
+pub use initialize_immutable_owner::initialize_immutable_owner;
 pub use initialize_mint::initialize_mint;
+pub use initialize_mint_close_authority::initialize_mint_close_authority;
+pub use initialize_non_transferable_mint::initialize_non_transferable_mint;
 pub use initialize_permanent_delegate::initialize_permanent_delegate;
 pub use initialize_token_account::initialize_token_account;
+pub use mint_to::mint_to;
+pub use reallocate::reallocate;
+pub use transfer_checked::transfer_checked;
+pub use ui_amount_to_amount::ui_amount_to_amount;
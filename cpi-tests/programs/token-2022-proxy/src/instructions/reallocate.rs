@@ -0,0 +1,31 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::consts::ExtensionType as PinocchioExtensionType},
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+pub fn reallocate(accounts: &[AccountInfo], extension_types: &[ExtensionType]) -> ProgramResult {
+    let [account, payer, system_program, owner, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let mut pinocchio_extension_types = [PinocchioExtensionType::Uninitialized; 28];
+    let extension_types_len = extension_types.len();
+    if extension_types_len > pinocchio_extension_types.len() {
+        Err(ProgramError::InvalidInstructionData)?
+    }
+
+    for (i, extension_type) in extension_types.iter().enumerate() {
+        pinocchio_extension_types[i] = PinocchioExtensionType::try_from(*extension_type as u16)?;
+    }
+
+    pinocchio_token_2022::instructions::Reallocate {
+        account,
+        payer,
+        system_program,
+        owner,
+        extension_types: &pinocchio_extension_types[..extension_types_len],
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,18 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn emit(accounts: &[AccountInfo], start: Option<u64>, end: Option<u64>) -> ProgramResult {
+    let [metadata, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::extension::token_metadata::Emit {
+        metadata,
+        start,
+        end,
+        program_id: &token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,43 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn initialize(
+    accounts: &[AccountInfo],
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> ProgramResult {
+    let [metadata, update_authority, mint, mint_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    if let Ok(token_metadata) = unsafe {
+        pinocchio_token_2022::extension::token_metadata::state::TokenMetadata::from_account_info_unchecked(
+            metadata,
+        )
+    } {
+        if token_metadata.update_authority() != Some(update_authority.key())
+            || token_metadata.name() != name
+            || token_metadata.symbol() != symbol
+            || token_metadata.uri() != uri
+        {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        return Ok(());
+    }
+
+    pinocchio_token_2022::extension::token_metadata::Initialize {
+        metadata,
+        update_authority,
+        mint,
+        mint_authority,
+        name,
+        symbol,
+        uri,
+        program_id: &token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,11 @@
+mod emit;
+mod initialize;
+mod remove_key;
+mod update_authority;
+mod update_field;
+
+pub use emit::*;
+pub use initialize::*;
+pub use remove_key::*;
+pub use update_authority::*;
+pub use update_field::*;
@@ -0,0 +1,19 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn remove_key(accounts: &[AccountInfo], idempotent: bool, key: &str) -> ProgramResult {
+    let [metadata, update_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::extension::token_metadata::RemoveKey {
+        metadata,
+        update_authority,
+        idempotent,
+        key,
+        program_id: &token_program.key(),
+    }
+    .invoke()
+}
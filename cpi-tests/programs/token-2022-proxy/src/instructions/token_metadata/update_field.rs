@@ -0,0 +1,27 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::token_metadata::state::Field as PinocchioField},
+    spl_token_metadata_interface::state::Field,
+};
+
+pub fn update_field(accounts: &[AccountInfo], field: &Field, value: &str) -> ProgramResult {
+    let [metadata, update_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let field = match field {
+        Field::Name => PinocchioField::Name,
+        Field::Symbol => PinocchioField::Symbol,
+        Field::Uri => PinocchioField::Uri,
+        Field::Key(key) => PinocchioField::Key(key),
+    };
+
+    pinocchio_token_2022::extension::token_metadata::UpdateField {
+        metadata,
+        update_authority,
+        field,
+        value,
+        program_id: &token_program.key(),
+    }
+    .invoke()
+}
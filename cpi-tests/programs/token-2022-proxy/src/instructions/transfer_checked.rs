@@ -0,0 +1,59 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::{
+        transfer_hook::TransferCheckedWithHook, transfer_preflight::TransferPreflight,
+    },
+};
+
+pub fn transfer_checked(accounts: &[AccountInfo], amount: u64, decimals: u8) -> ProgramResult {
+    // Expected account layout:
+    //   [ from, mint, to, authority, ...signers, ...additional_accounts, token_program ]
+    //
+    // The multisig signers are the leading signer accounts after a non-signer
+    // authority; the remaining accounts are passed for the transfer hook.
+
+    if accounts.len() < 5 {
+        Err(ProgramError::NotEnoughAccountKeys)?;
+    }
+
+    let from = &accounts[0];
+    let mint = &accounts[1];
+    let to = &accounts[2];
+    let authority = &accounts[3];
+    let token_program = accounts.last().unwrap();
+    let remaining_accounts = &accounts[4..accounts.len() - 1];
+
+    let num_signers = if authority.is_signer() {
+        0
+    } else {
+        remaining_accounts
+            .iter()
+            .take_while(|account| account.is_signer())
+            .count()
+    };
+    let (signers, additional_accounts) = remaining_accounts.split_at(num_signers);
+
+    // fail before the CPI if the token program would reject the transfer
+    TransferPreflight {
+        from,
+        mint,
+        to,
+        authority,
+        amount,
+        decimals,
+    }
+    .check()?;
+
+    TransferCheckedWithHook {
+        from,
+        mint,
+        to,
+        authority,
+        signers,
+        additional_accounts,
+        amount,
+        decimals,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,23 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::transfer_fee,
+};
+
+pub fn harvest_withheld_tokens_to_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    // accounts should be: [mint, ...sources, token_program]
+
+    if accounts.len() < 2 {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    }
+
+    let token_program = accounts.last().unwrap(); // token_program is always last
+    let mint = &accounts[0];
+    let sources = &accounts[1..accounts.len() - 1]; // everything between mint and token_program
+
+    transfer_fee::HarvestWithheldTokensToMint {
+        mint,
+        sources,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,49 @@
+use {
+    crate::helpers::from_c_option,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::transfer_fee,
+    solana_address::Address,
+    solana_program_option::COption,
+};
+
+pub fn initialize_transfer_fee_config(
+    accounts: &[AccountInfo],
+    transfer_fee_config_authority: COption<Address>,
+    withdraw_withheld_authority: COption<Address>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let transfer_fee_config_authority =
+        from_c_option(transfer_fee_config_authority).map(|x| x.to_bytes());
+    let withdraw_withheld_authority =
+        from_c_option(withdraw_withheld_authority).map(|x| x.to_bytes());
+
+    if pinocchio_token_2022::state::Mint::from_account_info(mint)?.is_initialized() {
+        let config = transfer_fee::TransferFeeConfig::from_account_info(mint)?;
+        let transfer_fee = config.newer_transfer_fee();
+
+        if config.transfer_fee_config_authority() != transfer_fee_config_authority.as_ref()
+            || config.withdraw_withheld_authority() != withdraw_withheld_authority.as_ref()
+            || transfer_fee.transfer_fee_basis_points() != transfer_fee_basis_points
+            || transfer_fee.maximum_fee() != maximum_fee
+        {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        return Ok(());
+    }
+
+    transfer_fee::InitializeTransferFeeConfig {
+        mint,
+        transfer_fee_config_authority: transfer_fee_config_authority.as_ref(),
+        withdraw_withheld_authority: withdraw_withheld_authority.as_ref(),
+        transfer_fee_basis_points,
+        maximum_fee,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
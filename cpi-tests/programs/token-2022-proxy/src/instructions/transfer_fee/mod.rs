@@ -0,0 +1,13 @@
+mod harvest_withheld_tokens_to_mint;
+mod initialize_transfer_fee_config;
+mod set_transfer_fee;
+mod transfer_checked_with_fee;
+mod withdraw_withheld_tokens_from_accounts;
+mod withdraw_withheld_tokens_from_mint;
+
+pub use harvest_withheld_tokens_to_mint::*;
+pub use initialize_transfer_fee_config::*;
+pub use set_transfer_fee::*;
+pub use transfer_checked_with_fee::*;
+pub use withdraw_withheld_tokens_from_accounts::*;
+pub use withdraw_withheld_tokens_from_mint::*;
@@ -0,0 +1,31 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::transfer_fee,
+};
+
+pub fn set_transfer_fee(
+    accounts: &[AccountInfo],
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> ProgramResult {
+    // accounts should be: [mint, authority, ...signers, token_program]
+
+    if accounts.len() < 4 {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    }
+
+    let token_program = accounts.last().unwrap(); // token_program is always last
+    let mint = &accounts[0];
+    let authority = &accounts[1];
+    let signers = &accounts[2..accounts.len() - 1]; // everything between authority and token_program
+
+    transfer_fee::SetTransferFee {
+        mint,
+        authority,
+        signers,
+        transfer_fee_basis_points,
+        maximum_fee,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
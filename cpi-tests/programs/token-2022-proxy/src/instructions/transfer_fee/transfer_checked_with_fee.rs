@@ -0,0 +1,37 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::transfer_fee,
+};
+
+pub fn transfer_checked_with_fee(
+    accounts: &[AccountInfo],
+    amount: u64,
+    decimals: u8,
+    fee: u64,
+) -> ProgramResult {
+    // accounts should be: [source, mint, destination, authority, ...signers, token_program]
+
+    if accounts.len() < 5 {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    }
+
+    let token_program = accounts.last().unwrap(); // token_program is always last
+    let source = &accounts[0];
+    let mint = &accounts[1];
+    let destination = &accounts[2];
+    let authority = &accounts[3];
+    let signers = &accounts[4..accounts.len() - 1]; // everything between authority and token_program
+
+    transfer_fee::TransferCheckedWithFee {
+        source,
+        mint,
+        destination,
+        authority,
+        signers,
+        amount,
+        decimals,
+        fee,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
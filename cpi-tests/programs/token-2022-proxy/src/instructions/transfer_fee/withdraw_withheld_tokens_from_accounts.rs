@@ -0,0 +1,35 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::transfer_fee,
+};
+
+pub fn withdraw_withheld_tokens_from_accounts(
+    accounts: &[AccountInfo],
+    num_token_accounts: u8,
+) -> ProgramResult {
+    // accounts should be: [mint, destination, authority, ...signers, ...sources, token_program]
+
+    let num_token_accounts = num_token_accounts as usize;
+
+    if accounts.len() < 4 + num_token_accounts {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    }
+
+    let token_program = accounts.last().unwrap(); // token_program is always last
+    let mint = &accounts[0];
+    let destination = &accounts[1];
+    let authority = &accounts[2];
+    let sources_start = accounts.len() - 1 - num_token_accounts;
+    let signers = &accounts[3..sources_start]; // everything between authority and sources
+    let sources = &accounts[sources_start..accounts.len() - 1];
+
+    transfer_fee::WithdrawWithheldTokensFromAccounts {
+        mint,
+        destination,
+        authority,
+        signers,
+        sources,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
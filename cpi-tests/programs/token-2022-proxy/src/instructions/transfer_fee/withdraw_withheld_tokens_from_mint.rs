@@ -0,0 +1,27 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::transfer_fee,
+};
+
+pub fn withdraw_withheld_tokens_from_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    // accounts should be: [mint, destination, authority, ...signers, token_program]
+
+    if accounts.len() < 4 {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    }
+
+    let token_program = accounts.last().unwrap(); // token_program is always last
+    let mint = &accounts[0];
+    let destination = &accounts[1];
+    let authority = &accounts[2];
+    let signers = &accounts[3..accounts.len() - 1]; // everything between authority and token_program
+
+    transfer_fee::WithdrawWithheldTokensFromMint {
+        mint,
+        destination,
+        authority,
+        signers,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
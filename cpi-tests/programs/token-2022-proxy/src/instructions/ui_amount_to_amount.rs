@@ -0,0 +1,25 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo, cpi::set_return_data, program_error::ProgramError,
+        ProgramResult,
+    },
+    pinocchio_token_2022,
+};
+
+/// Forwards the amount returned by the token program as own return data
+pub fn ui_amount_to_amount(accounts: &[AccountInfo], ui_amount: &str) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let amount = pinocchio_token_2022::instructions::UiAmountToAmount {
+        mint,
+        ui_amount,
+        token_program: token_program.key(),
+    }
+    .invoke()?;
+
+    set_return_data(&amount.to_le_bytes());
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ use {
     pinocchio_pubkey::declare_id,
     spl_token_2022_interface::{
         extension::{
+            confidential_transfer::instruction::ConfidentialTransferInstruction,
             cpi_guard::instruction::CpiGuardInstruction,
             default_account_state::instruction::DefaultAccountStateInstruction,
             group_member_pointer::instruction::GroupMemberPointerInstruction,
@@ -16,6 +17,7 @@ use {
             interest_bearing_mint::instruction::InterestBearingMintInstruction,
             pausable::instruction::PausableInstruction,
             scaled_ui_amount::instruction::ScaledUiAmountMintInstruction,
+            transfer_fee::instruction::TransferFeeInstruction,
             transfer_hook::instruction::TransferHookInstruction,
             memo_transfer::instruction::RequiredMemoTransfersInstruction,
             metadata_pointer::instruction::MetadataPointerInstruction,
@@ -26,6 +28,9 @@ use {
         InitializeGroup, InitializeMember, TokenGroupInstruction, UpdateGroupAuthority,
         UpdateGroupMaxSize,
     },
+    spl_token_metadata_interface::instruction::{
+        Emit, Initialize, RemoveKey, TokenMetadataInstruction, UpdateAuthority, UpdateField,
+    },
 };
 
 pub mod helpers;
@@ -54,6 +59,16 @@ pub fn process_instruction(
                 // For Initializing TokenAccount
                 TokenInstruction::InitializeAccount => i::initialize_token_account(accounts),
 
+                TokenInstruction::MintTo { amount } => i::mint_to(accounts, amount),
+
+                TokenInstruction::Burn { amount } => i::burn(accounts, amount),
+
+                TokenInstruction::Approve { amount } => i::approve(accounts, amount),
+
+                TokenInstruction::TransferChecked { amount, decimals } => {
+                    i::transfer_checked(accounts, amount, decimals)
+                }
+
                 TokenInstruction::GroupPointerExtension => {
                     let instruction_data = &instruction_data[1..]; // Remove extension discriminator
                     let ix: GroupPointerInstruction = decode_instruction_type(instruction_data)
@@ -104,6 +119,25 @@ pub fn process_instruction(
                 TokenInstruction::InitializePermanentDelegate { delegate } => {
                     initialize_permanent_delegate(accounts, delegate)
                 }
+                TokenInstruction::InitializeMintCloseAuthority { close_authority } => {
+                    i::initialize_mint_close_authority(accounts, close_authority)
+                }
+                TokenInstruction::InitializeNonTransferableMint => {
+                    i::initialize_non_transferable_mint(accounts)
+                }
+                TokenInstruction::InitializeImmutableOwner => i::initialize_immutable_owner(accounts),
+                TokenInstruction::Reallocate { extension_types } => {
+                    i::reallocate(accounts, &extension_types)
+                }
+                TokenInstruction::GetAccountDataSize { extension_types } => {
+                    i::get_account_data_size(accounts, &extension_types)
+                }
+                TokenInstruction::AmountToUiAmount { amount } => {
+                    i::amount_to_ui_amount(accounts, amount)
+                }
+                TokenInstruction::UiAmountToAmount { ui_amount } => {
+                    i::ui_amount_to_amount(accounts, ui_amount)
+                }
                 TokenInstruction::CpiGuardExtension => {
                     let instruction_data = &instruction_data[1..]; // Remove extension discriminator
                     let ix: CpiGuardInstruction = decode_instruction_type(instruction_data)
@@ -167,6 +201,68 @@ pub fn process_instruction(
                     }
                 }
 
+                TokenInstruction::TransferFeeExtension => {
+                    let instruction_data = &instruction_data[1..]; // Remove extension discriminator
+                    let ix = TransferFeeInstruction::unpack(instruction_data)
+                        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+                    match ix {
+                        TransferFeeInstruction::InitializeTransferFeeConfig {
+                            transfer_fee_config_authority,
+                            withdraw_withheld_authority,
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        } => i::transfer_fee::initialize_transfer_fee_config(
+                            accounts,
+                            transfer_fee_config_authority,
+                            withdraw_withheld_authority,
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        ),
+                        TransferFeeInstruction::TransferCheckedWithFee {
+                            amount,
+                            decimals,
+                            fee,
+                        } => i::transfer_fee::transfer_checked_with_fee(
+                            accounts, amount, decimals, fee,
+                        ),
+                        TransferFeeInstruction::WithdrawWithheldTokensFromMint => {
+                            i::transfer_fee::withdraw_withheld_tokens_from_mint(accounts)
+                        }
+                        TransferFeeInstruction::WithdrawWithheldTokensFromAccounts {
+                            num_token_accounts,
+                        } => i::transfer_fee::withdraw_withheld_tokens_from_accounts(
+                            accounts,
+                            num_token_accounts,
+                        ),
+                        TransferFeeInstruction::HarvestWithheldTokensToMint => {
+                            i::transfer_fee::harvest_withheld_tokens_to_mint(accounts)
+                        }
+                        TransferFeeInstruction::SetTransferFee {
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        } => i::transfer_fee::set_transfer_fee(
+                            accounts,
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        ),
+                    }
+                }
+
+                TokenInstruction::ConfidentialTransferExtension => {
+                    let instruction_data = &instruction_data[1..]; // Remove extension discriminator
+                    let ix: ConfidentialTransferInstruction =
+                        decode_instruction_type(instruction_data)
+                            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+                    match ix {
+                        ConfidentialTransferInstruction::InitializeMint => {
+                            i::confidential_transfer::initialize_mint(accounts, instruction_data)
+                        }
+                        _ => Err(ProgramError::InvalidInstructionData),
+                    }
+                }
+
                 TokenInstruction::TransferHookExtension => {
                     let instruction_data = &instruction_data[1..]; // Remove extension discriminator
                     let ix: TransferHookInstruction = decode_instruction_type(instruction_data)
@@ -235,7 +331,26 @@ pub fn process_instruction(
                         i::token_group::initialize_member(accounts)
                     }
                 },
-                _ => Err(ProgramError::InvalidInstructionData)?,
+                // try to match TokenMetadataInstruction
+                _ => match TokenMetadataInstruction::unpack(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?
+                {
+                    TokenMetadataInstruction::Initialize(Initialize { name, symbol, uri }) => {
+                        i::token_metadata::initialize(accounts, &name, &symbol, &uri)
+                    }
+                    TokenMetadataInstruction::UpdateField(UpdateField { field, value }) => {
+                        i::token_metadata::update_field(accounts, &field, &value)
+                    }
+                    TokenMetadataInstruction::RemoveKey(RemoveKey { idempotent, key }) => {
+                        i::token_metadata::remove_key(accounts, idempotent, &key)
+                    }
+                    TokenMetadataInstruction::UpdateAuthority(UpdateAuthority {
+                        new_authority,
+                    }) => i::token_metadata::update_authority(accounts, new_authority),
+                    TokenMetadataInstruction::Emit(Emit { start, end }) => {
+                        i::token_metadata::emit(accounts, start, end)
+                    }
+                },
             }
         }
     }
@@ -0,0 +1,39 @@
+#![allow(unexpected_cfgs)]
+
+use {
+    pinocchio::{
+        account_info::AccountInfo, entrypoint, program_error::ProgramError, pubkey::Pubkey,
+        ProgramResult,
+    },
+    pinocchio_pubkey::declare_id,
+    pinocchio_token_2022::extension::transfer_hook_interface::{
+        check_transferring, TransferHookInterfaceInstruction,
+    },
+};
+
+entrypoint!(process_instruction);
+declare_id!("H89a61eCPGmTTihXU1SDecFjWeEfjatAEpuWPepYqGuP");
+
+/// Accepts every `Execute` instruction invoked by the token program.
+///
+/// The extra accounts are listed by a validation account written directly by
+/// the tests, so the validation account instructions are not supported.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    match TransferHookInterfaceInstruction::unpack(instruction_data)? {
+        TransferHookInterfaceInstruction::Execute { .. } => {
+            // Expected account layout:
+            //   [ source, mint, destination, authority, validation, ...extra_accounts ]
+
+            if accounts.len() < 5 {
+                Err(ProgramError::NotEnoughAccountKeys)?;
+            }
+
+            check_transferring(&accounts[0])
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
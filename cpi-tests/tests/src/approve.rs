@@ -0,0 +1,103 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            approve::Token2022ApproveExtension, initialize_mint::Token2022InitializeMintExtension,
+            initialize_multisig::Token2022InitializeMultisigExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            token_account::Token2022TokenAccountExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+};
+
+#[test]
+fn proxy_approve() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let (_, account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+    let account_pubkey = &account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_approve(
+        Target::Proxy,
+        AppUser::Alice,
+        account_pubkey,
+        &AppUser::Bob.pubkey(),
+        &AppUser::Alice.pubkey(),
+        &[],
+        500,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account_delegated_amount(Target::Spl, account_pubkey)?,
+        500
+    );
+    assert_eq!(
+        app.token_2022_query_token_account_delegated_amount(Target::Proxy, account_pubkey)?,
+        500
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_approve_with_multisig() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    // the token account is owned by a multisig with 3 signers, requiring 2 signatures
+    let (_, multisig_pubkey) = app.token_2022_try_create_and_init_multisig(
+        Target::Spl,
+        AppUser::Admin,
+        2,
+        &[
+            AppUser::Admin.pubkey(),
+            AppUser::Alice.pubkey(),
+            AppUser::Bob.pubkey(),
+        ],
+    )?;
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let (_, account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &multisig_pubkey,
+        mint_pubkey,
+        &[],
+    )?;
+    let account_pubkey = &account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_approve(
+        Target::Proxy,
+        AppUser::Admin,
+        account_pubkey,
+        &AppUser::Bob.pubkey(),
+        &multisig_pubkey,
+        &[AppUser::Admin, AppUser::Alice],
+        500,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account_delegated_amount(Target::Proxy, account_pubkey)?,
+        500
+    );
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            confidential_transfer::Token2022ConfidentialTransferExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+        },
+        suite::{
+            core::App,
+            types::{to_optional_non_zero_pubkey, AppUser, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+#[test]
+fn initialize_confidential_transfer_mint() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ConfidentialTransferMint]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+    let mint_authority = AppUser::Admin;
+    let confidential_transfer_authority = AppUser::Alice;
+
+    app.token_2022_try_initialize_confidential_transfer_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        Some(&confidential_transfer_authority.pubkey()),
+        true,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &mint_authority.pubkey(),
+        None,
+    )?;
+
+    let confidential_transfer_mint =
+        app.token_2022_query_confidential_transfer_mint(Target::Spl, mint_pubkey)?;
+
+    assert_eq!(
+        confidential_transfer_mint.authority,
+        to_optional_non_zero_pubkey(Some(&confidential_transfer_authority.pubkey()))
+    );
+    assert_eq!(
+        confidential_transfer_mint.auto_approve_new_accounts,
+        true.into()
+    );
+
+    // the pinocchio reader agrees with the SPL one
+    assert_eq!(
+        app.token_2022_query_confidential_transfer_mint(Target::Proxy, mint_pubkey)?,
+        confidential_transfer_mint
+    );
+
+    Ok(())
+}
+
+#[test]
+fn initialize_confidential_transfer_mint_proxy() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ConfidentialTransferMint]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+    let mint_authority = AppUser::Admin;
+
+    // Initialize confidential transfer extension via proxy, without authority
+    app.token_2022_try_initialize_confidential_transfer_mint(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        None,
+        false,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &mint_authority.pubkey(),
+        None,
+    )?;
+
+    let confidential_transfer_mint =
+        app.token_2022_query_confidential_transfer_mint(Target::Spl, mint_pubkey)?;
+
+    assert_eq!(
+        confidential_transfer_mint.authority,
+        to_optional_non_zero_pubkey(None)
+    );
+    assert_eq!(
+        confidential_transfer_mint.auto_approve_new_accounts,
+        false.into()
+    );
+
+    assert_eq!(
+        app.token_2022_query_confidential_transfer_mint(Target::Proxy, mint_pubkey)?,
+        confidential_transfer_mint
+    );
+
+    Ok(())
+}
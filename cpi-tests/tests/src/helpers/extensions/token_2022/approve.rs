@@ -0,0 +1,134 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, SolPubkey, Target, TestError,
+            TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    solana_keypair::Keypair,
+    spl_token_2022_interface::{extension::StateWithExtensions, state::Account},
+};
+
+pub trait Token2022ApproveExtension {
+    /// `signers` are the multisig signers; leave it empty for a single authority
+    /// signing as `sender`
+    #[allow(clippy::too_many_arguments)]
+    fn token_2022_try_approve(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        delegate: &Pubkey,
+        authority: &Pubkey,
+        signers: &[AppUser],
+        amount: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_query_token_account_delegated_amount(
+        &self,
+        target: Target,
+        account: &Pubkey,
+    ) -> TestResult<u64>;
+}
+
+impl Token2022ApproveExtension for App {
+    fn token_2022_try_approve(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        delegate: &Pubkey,
+        authority: &Pubkey,
+        signers: &[AppUser],
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let mut signer_keypairs: Vec<Keypair> = vec![sender.keypair()];
+        signer_keypairs.extend(
+            signers
+                .iter()
+                .filter(|x| x.pubkey() != sender.pubkey())
+                .map(|x| x.keypair()),
+        );
+        let authority_signers: Vec<_> = signers
+            .iter()
+            .map(|x| pin_pubkey_to_addr(&x.pubkey().to_bytes()))
+            .collect();
+        let authority_signer_refs: Vec<_> = authority_signers.iter().collect();
+
+        let ix = spl_token_2022_interface::instruction::approve(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(source),
+            &pin_pubkey_to_addr(delegate),
+            &pin_pubkey_to_addr(authority),
+            &authority_signer_refs,
+            amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            &signer_keypairs,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_query_token_account_delegated_amount(
+        &self,
+        target: Target,
+        account: &Pubkey,
+    ) -> TestResult<u64> {
+        let data = &get_account_data(self, account)?;
+
+        match target {
+            Target::Spl => StateWithExtensions::<Account>::unpack(data)
+                .map(|x| x.base.delegated_amount)
+                .map_err(TestError::from_raw_error),
+            Target::Proxy => {
+                use pinocchio_token_2022::state::TokenAccount as PinocchioTokenAccount;
+
+                if data.len() < PinocchioTokenAccount::BASE_LEN {
+                    Err(TestError::from_raw_error("Invalid token account data"))?;
+                }
+
+                Ok(unsafe { PinocchioTokenAccount::from_bytes_unchecked(data) }.delegated_amount())
+            }
+        }
+    }
+}
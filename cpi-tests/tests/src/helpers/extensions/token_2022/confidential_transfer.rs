@@ -0,0 +1,137 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, to_optional_non_zero_pubkey, AppUser, Target,
+            TestError, TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    spl_pod::bytemuck::pod_from_bytes,
+    spl_token_2022_interface::{
+        extension::{
+            confidential_transfer::ConfidentialTransferMint, BaseStateWithExtensions,
+            StateWithExtensions,
+        },
+        state::Mint,
+    },
+};
+
+pub trait Token2022ConfidentialTransferExtension {
+    fn token_2022_try_initialize_confidential_transfer_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        authority: Option<&Pubkey>,
+        auto_approve_new_accounts: bool,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_query_confidential_transfer_mint(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<ConfidentialTransferMint>;
+}
+
+impl Token2022ConfidentialTransferExtension for App {
+    fn token_2022_try_initialize_confidential_transfer_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        authority: Option<&Pubkey>,
+        auto_approve_new_accounts: bool,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix =
+            spl_token_2022_interface::extension::confidential_transfer::instruction::initialize_mint(
+                &token_2022_program.to_bytes().into(),
+                &pin_pubkey_to_addr(mint),
+                authority.map(|a| pin_pubkey_to_addr(a)),
+                auto_approve_new_accounts,
+                None,
+            )
+            .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_query_confidential_transfer_mint(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<ConfidentialTransferMint> {
+        let data = &get_account_data(self, mint)?;
+
+        match target {
+            Target::Spl => {
+                // parse the mint account with extensions
+                let mint_with_extensions =
+                    StateWithExtensions::<Mint>::unpack(data).map_err(TestError::from_raw_error)?;
+
+                // get the ConfidentialTransferMint extension
+                mint_with_extensions
+                    .get_extension::<ConfidentialTransferMint>()
+                    .map(|&x| x)
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::confidential_transfer::state::ConfidentialTransferMint as PinocchioConfidentialTransferMint;
+
+                let state = PinocchioConfidentialTransferMint::from_bytes(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                // a zeroed ElGamal pubkey stands for no auditor
+                let auditor_elgamal_pubkey = state.auditor_elgamal_pubkey().copied().unwrap_or([0; 32]);
+
+                Ok(ConfidentialTransferMint {
+                    authority: to_optional_non_zero_pubkey(state.authority()),
+                    auto_approve_new_accounts: state.auto_approve_new_accounts().into(),
+                    auditor_elgamal_pubkey: *pod_from_bytes(&auditor_elgamal_pubkey)
+                        .map_err(TestError::from_raw_error)?,
+                })
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@ use {
     pinocchio::pubkey::Pubkey,
     solana_keypair::Keypair,
     solana_program_pack::Pack,
+    solana_signer::Signer,
     spl_token_2022_interface::{instruction::MAX_SIGNERS, state::Multisig},
 };
 
@@ -25,6 +26,15 @@ pub trait Token2022InitializeMultisigExtension {
         required_signers: u8,
         signer_pubkeys: &[Pubkey],
     ) -> TestResult<TransactionMetadata>;
+
+    /// dev: quickly create and initialize a multisig; returns (TransactionMetadata, multisig pubkey)
+    fn token_2022_try_create_and_init_multisig(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        required_signers: u8,
+        signer_pubkeys: &[Pubkey],
+    ) -> TestResult<(TransactionMetadata, Pubkey)>;
 }
 
 impl Token2022InitializeMultisigExtension for App {
@@ -105,4 +115,25 @@ impl Token2022InitializeMultisigExtension for App {
             self.is_log_displayed,
         )
     }
+
+    fn token_2022_try_create_and_init_multisig(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        required_signers: u8,
+        signer_pubkeys: &[Pubkey],
+    ) -> TestResult<(TransactionMetadata, Pubkey)> {
+        let (_, multisig_keypair) = self.token_2022_try_create_multisig(sender, None)?;
+        let multisig = multisig_keypair.pubkey().to_bytes();
+
+        let tx_metadata = self.token_2022_try_initialize_multisig(
+            target,
+            sender,
+            &multisig,
+            required_signers,
+            signer_pubkeys,
+        )?;
+
+        Ok((tx_metadata, multisig))
+    }
 }
@@ -12,8 +12,11 @@ use {
     litesvm::types::TransactionMetadata,
     pinocchio::pubkey::Pubkey,
     spl_token_2022_interface::{
-        extension::{pausable::PausableConfig, BaseStateWithExtensions, StateWithExtensions},
-        state::Mint,
+        extension::{
+            pausable::{PausableAccount, PausableConfig},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::{Account, Mint},
     },
 };
 
@@ -63,6 +66,12 @@ pub trait Token2022PausableExtension {
         target: Target,
         mint: &Pubkey,
     ) -> TestResult<PausableConfig>;
+
+    fn token_2022_query_pausable_account(
+        &self,
+        target: Target,
+        account: &Pubkey,
+    ) -> TestResult<bool>;
 }
 
 impl Token2022PausableExtension for App {
@@ -388,4 +397,29 @@ impl Token2022PausableExtension for App {
             }
         }
     }
+
+    fn token_2022_query_pausable_account(
+        &self,
+        target: Target,
+        account: &Pubkey,
+    ) -> TestResult<bool> {
+        let data = &get_account_data(self, account)?;
+
+        match target {
+            Target::Spl => {
+                // parse the token account with extensions
+                let account_with_extensions = StateWithExtensions::<Account>::unpack(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                Ok(account_with_extensions
+                    .get_extension::<PausableAccount>()
+                    .is_ok())
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::pausable::state::PausableAccount as PinocchioPausableAccount;
+
+                Ok(PinocchioPausableAccount::is_present(data))
+            }
+        }
+    }
 }
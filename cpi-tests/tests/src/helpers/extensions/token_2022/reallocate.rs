@@ -0,0 +1,153 @@
+use {
+    crate::helpers::suite::{
+        core::{extension::send_tx, App, ProgramId},
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, SolPubkey, Target, TestError,
+            TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+pub trait Token2022ReallocateExtension {
+    fn token_2022_try_reallocate(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        owner: AppUser,
+        extension_types: &[ExtensionType],
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_get_account_data_size(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        extension_types: &[ExtensionType],
+    ) -> TestResult<u64>;
+}
+
+impl Token2022ReallocateExtension for App {
+    fn token_2022_try_reallocate(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        owner: AppUser,
+        extension_types: &[ExtensionType],
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair(), &owner.keypair()];
+
+        let ix = spl_token_2022_interface::instruction::reallocate(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            &sender.pubkey().to_bytes().into(),
+            &owner.pubkey().to_bytes().into(),
+            &[],
+            extension_types,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_get_account_data_size(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        extension_types: &[ExtensionType],
+    ) -> TestResult<u64> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::instruction::get_account_data_size(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            extension_types,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        let tx_metadata = send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )?;
+
+        // the proxy forwards the token program return data as its own
+        tx_metadata
+            .return_data
+            .data
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(TestError::from_raw_error)
+    }
+}
@@ -0,0 +1,372 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, pin_to_sol_pubkey, to_optional_non_zero_pubkey,
+            AppUser, SolPubkey, Target, TestError, TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    spl_token_2022_interface::{
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint,
+    },
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+};
+
+pub trait Token2022TokenMetadataExtension {
+    #[allow(clippy::too_many_arguments)]
+    fn token_2022_try_initialize_token_metadata(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+        update_authority: &Pubkey,
+        mint: &Pubkey,
+        mint_authority: AppUser,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_update_token_metadata_field(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+        update_authority: AppUser,
+        field: Field,
+        value: &str,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_remove_token_metadata_key(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+        update_authority: AppUser,
+        key: &str,
+        idempotent: bool,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_update_token_metadata_authority(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+        current_authority: AppUser,
+        new_authority: Option<&Pubkey>,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_query_token_metadata(
+        &self,
+        target: Target,
+        metadata: &Pubkey,
+    ) -> TestResult<TokenMetadata>;
+}
+
+impl Token2022TokenMetadataExtension for App {
+    fn token_2022_try_initialize_token_metadata(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+        update_authority: &Pubkey,
+        mint: &Pubkey,
+        mint_authority: AppUser,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair(), &mint_authority.keypair()];
+
+        let token_metadata = TokenMetadata {
+            update_authority: to_optional_non_zero_pubkey(Some(update_authority)),
+            mint: pin_pubkey_to_addr(mint),
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: uri.to_string(),
+            additional_metadata: vec![],
+        };
+        let lamports = self
+            .litesvm
+            .get_sysvar::<solana_program::sysvar::rent::Rent>()
+            .minimum_balance(
+                token_metadata
+                    .tlv_size_of()
+                    .map_err(TestError::from_raw_error)?,
+            );
+        self.transfer_sol(sender, &pin_to_sol_pubkey(metadata), lamports)?;
+
+        let ix = spl_token_metadata_interface::instruction::initialize(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(metadata),
+            &pin_pubkey_to_addr(update_authority),
+            &pin_pubkey_to_addr(mint),
+            &SolPubkey::pubkey(&mint_authority).to_bytes().into(),
+            name.to_string(),
+            symbol.to_string(),
+            uri.to_string(),
+        );
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_update_token_metadata_field(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+        update_authority: AppUser,
+        field: Field,
+        value: &str,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair(), &update_authority.keypair()];
+
+        // fund the account for the worst case of a new key/value pair
+        let key_len = match &field {
+            Field::Key(key) => key.len(),
+            _ => 0,
+        };
+        let lamports = self
+            .litesvm
+            .get_sysvar::<solana_program::sysvar::rent::Rent>()
+            .minimum_balance(key_len + value.len() + 8);
+        self.transfer_sol(sender, &pin_to_sol_pubkey(metadata), lamports)?;
+
+        let ix = spl_token_metadata_interface::instruction::update_field(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(metadata),
+            &SolPubkey::pubkey(&update_authority).to_bytes().into(),
+            field,
+            value.to_string(),
+        );
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_remove_token_metadata_key(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+        update_authority: AppUser,
+        key: &str,
+        idempotent: bool,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair(), &update_authority.keypair()];
+
+        let ix = spl_token_metadata_interface::instruction::remove_key(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(metadata),
+            &SolPubkey::pubkey(&update_authority).to_bytes().into(),
+            key.to_string(),
+            idempotent,
+        );
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_update_token_metadata_authority(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+        current_authority: AppUser,
+        new_authority: Option<&Pubkey>,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair(), &current_authority.keypair()];
+
+        let ix = spl_token_metadata_interface::instruction::update_authority(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(metadata),
+            &SolPubkey::pubkey(&current_authority).to_bytes().into(),
+            to_optional_non_zero_pubkey(new_authority),
+        );
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_query_token_metadata(
+        &self,
+        target: Target,
+        metadata: &Pubkey,
+    ) -> TestResult<TokenMetadata> {
+        let data = &get_account_data(self, metadata)?;
+
+        match target {
+            Target::Spl => {
+                // Parse the mint account with extensions
+                let mint_with_extensions =
+                    StateWithExtensions::<Mint>::unpack(data).map_err(TestError::from_raw_error)?;
+
+                // Deserialize the variable-length extension
+                mint_with_extensions
+                    .get_variable_len_extension::<TokenMetadata>()
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::token_metadata::state::TokenMetadata as PinocchioTokenMetadata;
+
+                let state =
+                    PinocchioTokenMetadata::from_bytes(data).map_err(TestError::from_raw_error)?;
+
+                Ok(TokenMetadata {
+                    update_authority: to_optional_non_zero_pubkey(state.update_authority()),
+                    mint: pin_pubkey_to_addr(state.mint()),
+                    name: state.name().to_string(),
+                    symbol: state.symbol().to_string(),
+                    uri: state.uri().to_string(),
+                    additional_metadata: state
+                        .additional_metadata()
+                        .map(|(key, value)| (key.to_string(), value.to_string()))
+                        .collect(),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use {
+    crate::helpers::suite::{
+        core::{extension::send_tx, App, ProgramId},
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, SolPubkey, Target, TestError,
+            TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    solana_instruction::AccountMeta,
+    solana_keypair::Keypair,
+};
+
+pub trait Token2022TransferCheckedExtension {
+    /// `signers` are the multisig signers; leave it empty for a single authority
+    /// signing as `sender`
+    #[allow(clippy::too_many_arguments)]
+    fn token_2022_try_transfer_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        signers: &[AppUser],
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// `additional_accounts` are the extra accounts required by the transfer hook,
+    /// followed by its validation account and program
+    #[allow(clippy::too_many_arguments)]
+    fn token_2022_try_transfer_checked_with_hook(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        signers: &[AppUser],
+        additional_accounts: &[AccountMeta],
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+}
+
+impl Token2022TransferCheckedExtension for App {
+    fn token_2022_try_transfer_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        signers: &[AppUser],
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        self.token_2022_try_transfer_checked_with_hook(
+            target,
+            sender,
+            source,
+            mint,
+            destination,
+            authority,
+            signers,
+            &[],
+            amount,
+            decimals,
+        )
+    }
+
+    fn token_2022_try_transfer_checked_with_hook(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        signers: &[AppUser],
+        additional_accounts: &[AccountMeta],
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let mut signer_keypairs: Vec<Keypair> = vec![sender.keypair()];
+        signer_keypairs.extend(
+            signers
+                .iter()
+                .filter(|x| x.pubkey() != sender.pubkey())
+                .map(|x| x.keypair()),
+        );
+        let authority_signers: Vec<_> = signers
+            .iter()
+            .map(|x| pin_pubkey_to_addr(&x.pubkey().to_bytes()))
+            .collect();
+        let authority_signer_refs: Vec<_> = authority_signers.iter().collect();
+
+        let ix = spl_token_2022_interface::instruction::transfer_checked(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(source),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(destination),
+            &pin_pubkey_to_addr(authority),
+            &authority_signer_refs,
+            amount,
+            decimals,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+        ix_legacy.accounts.extend_from_slice(additional_accounts);
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy
+                .accounts
+                .push(AccountMeta::new_readonly(token_2022_program, false));
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            &signer_keypairs,
+            self.is_log_displayed,
+        )
+    }
+}
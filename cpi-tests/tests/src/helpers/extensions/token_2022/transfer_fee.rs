@@ -0,0 +1,530 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, to_optional_non_zero_pubkey, AppUser,
+            SolPubkey, Target, TestError, TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    spl_token_2022_interface::{
+        extension::{
+            transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::{Account, Mint},
+    },
+};
+
+pub trait Token2022TransferFeeExtension {
+    #[allow(clippy::too_many_arguments)]
+    fn token_2022_try_initialize_transfer_fee_config(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        transfer_fee_config_authority: Option<&Pubkey>,
+        withdraw_withheld_authority: Option<&Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_set_transfer_fee(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        authority: &Pubkey,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn token_2022_try_transfer_checked_with_fee(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_withdraw_withheld_tokens_from_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        destination: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_withdraw_withheld_tokens_from_accounts(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        sources: &[Pubkey],
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_harvest_withheld_tokens_to_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        sources: &[Pubkey],
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_query_transfer_fee_config(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<TransferFeeConfig>;
+
+    fn token_2022_query_transfer_fee_amount(
+        &self,
+        target: Target,
+        account: &Pubkey,
+    ) -> TestResult<u64>;
+}
+
+impl Token2022TransferFeeExtension for App {
+    fn token_2022_try_initialize_transfer_fee_config(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        transfer_fee_config_authority: Option<&Pubkey>,
+        withdraw_withheld_authority: Option<&Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix =
+            spl_token_2022_interface::extension::transfer_fee::instruction::initialize_transfer_fee_config(
+                &token_2022_program.to_bytes().into(),
+                &pin_pubkey_to_addr(mint),
+                transfer_fee_config_authority
+                    .map(pin_pubkey_to_addr)
+                    .as_ref(),
+                withdraw_withheld_authority.map(pin_pubkey_to_addr).as_ref(),
+                transfer_fee_basis_points,
+                maximum_fee,
+            )
+            .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_set_transfer_fee(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        authority: &Pubkey,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+        let authority_signers = &[&pin_pubkey_to_addr(&SolPubkey::pubkey(&sender).to_bytes())];
+
+        let ix = spl_token_2022_interface::extension::transfer_fee::instruction::set_transfer_fee(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(authority),
+            authority_signers,
+            transfer_fee_basis_points,
+            maximum_fee,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_transfer_checked_with_fee(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::extension::transfer_fee::instruction::transfer_checked_with_fee(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(source),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(destination),
+            &pin_pubkey_to_addr(&SolPubkey::pubkey(&sender).to_bytes()),
+            &[],
+            amount,
+            decimals,
+            fee,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_withdraw_withheld_tokens_from_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        destination: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(destination),
+            &pin_pubkey_to_addr(&SolPubkey::pubkey(&sender).to_bytes()),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_withdraw_withheld_tokens_from_accounts(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        sources: &[Pubkey],
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+        let sources: Vec<_> = sources.iter().map(pin_pubkey_to_addr).collect();
+
+        let ix = spl_token_2022_interface::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_accounts(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(destination),
+            &pin_pubkey_to_addr(&SolPubkey::pubkey(&sender).to_bytes()),
+            &[],
+            &sources.iter().collect::<Vec<_>>(),
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_harvest_withheld_tokens_to_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        sources: &[Pubkey],
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+        let sources: Vec<_> = sources.iter().map(pin_pubkey_to_addr).collect();
+
+        let ix = spl_token_2022_interface::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sources.iter().collect::<Vec<_>>(),
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_query_transfer_fee_config(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<TransferFeeConfig> {
+        let data = &get_account_data(self, mint)?;
+
+        match target {
+            Target::Spl => {
+                // parse the mint account with extensions
+                let mint_with_extensions =
+                    StateWithExtensions::<Mint>::unpack(data).map_err(TestError::from_raw_error)?;
+
+                // get the TransferFeeConfig extension
+                mint_with_extensions
+                    .get_extension::<TransferFeeConfig>()
+                    .map(|&x| x)
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::transfer_fee::state::{
+                    TransferFee as PinocchioTransferFee,
+                    TransferFeeConfig as PinocchioTransferFeeConfig,
+                };
+
+                let state = PinocchioTransferFeeConfig::from_bytes(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                let to_transfer_fee = |transfer_fee: &PinocchioTransferFee| TransferFee {
+                    epoch: transfer_fee.epoch().into(),
+                    maximum_fee: transfer_fee.maximum_fee().into(),
+                    transfer_fee_basis_points: transfer_fee.transfer_fee_basis_points().into(),
+                };
+
+                Ok(TransferFeeConfig {
+                    transfer_fee_config_authority: to_optional_non_zero_pubkey(
+                        state.transfer_fee_config_authority(),
+                    ),
+                    withdraw_withheld_authority: to_optional_non_zero_pubkey(
+                        state.withdraw_withheld_authority(),
+                    ),
+                    withheld_amount: state.withheld_amount().into(),
+                    older_transfer_fee: to_transfer_fee(state.older_transfer_fee()),
+                    newer_transfer_fee: to_transfer_fee(state.newer_transfer_fee()),
+                })
+            }
+        }
+    }
+
+    fn token_2022_query_transfer_fee_amount(
+        &self,
+        target: Target,
+        account: &Pubkey,
+    ) -> TestResult<u64> {
+        let data = &get_account_data(self, account)?;
+
+        match target {
+            Target::Spl => {
+                let account_with_extensions = StateWithExtensions::<Account>::unpack(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                account_with_extensions
+                    .get_extension::<TransferFeeAmount>()
+                    .map(|x| u64::from(x.withheld_amount))
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::transfer_fee::state::TransferFeeAmount as PinocchioTransferFeeAmount;
+
+                PinocchioTransferFeeAmount::from_bytes(data)
+                    .map(|x| x.withheld_amount())
+                    .map_err(TestError::from_raw_error)
+            }
+        }
+    }
+}
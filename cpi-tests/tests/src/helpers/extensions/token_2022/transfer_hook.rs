@@ -12,8 +12,11 @@ use {
     litesvm::types::TransactionMetadata,
     pinocchio::pubkey::Pubkey,
     spl_token_2022_interface::{
-        extension::{transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions},
-        state::Mint,
+        extension::{
+            transfer_hook::{TransferHook, TransferHookAccount},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::{Account, Mint},
     },
 };
 
@@ -50,6 +53,12 @@ pub trait Token2022TransferHookExtension {
         target: Target,
         mint: &Pubkey,
     ) -> TestResult<TransferHook>;
+
+    fn token_2022_query_transfer_hook_account(
+        &self,
+        target: Target,
+        account: &Pubkey,
+    ) -> TestResult<TransferHookAccount>;
 }
 
 impl Token2022TransferHookExtension for App {
@@ -263,6 +272,36 @@ impl Token2022TransferHookExtension for App {
             }
         }
     }
-}
 
+    fn token_2022_query_transfer_hook_account(
+        &self,
+        target: Target,
+        account: &Pubkey,
+    ) -> TestResult<TransferHookAccount> {
+        let data = &get_account_data(self, account)?;
 
+        match target {
+            Target::Spl => {
+                // parse the token account with extensions
+                let account_with_extensions = StateWithExtensions::<Account>::unpack(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                // get the TransferHookAccount extension
+                account_with_extensions
+                    .get_extension::<TransferHookAccount>()
+                    .map(|&x| x)
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::transfer_hook::state::TransferHookAccount as PinocchioTransferHookAccount;
+
+                let state = PinocchioTransferHookAccount::from_bytes(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                Ok(TransferHookAccount {
+                    transferring: state.transferring().into(),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use {
+    crate::helpers::suite::{
+        core::{extension::send_tx, App, ProgramId},
+        types::{addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, Target, TestError, TestResult},
+    },
+    pinocchio::pubkey::Pubkey,
+};
+
+pub trait Token2022UiAmountExtension {
+    fn token_2022_try_amount_to_ui_amount(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<String>;
+
+    fn token_2022_try_ui_amount_to_amount(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        ui_amount: &str,
+    ) -> TestResult<u64>;
+}
+
+impl Token2022UiAmountExtension for App {
+    fn token_2022_try_amount_to_ui_amount(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<String> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::instruction::amount_to_ui_amount(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        let tx_metadata = send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )?;
+
+        // the proxy forwards the token program return data as its own
+        String::from_utf8(tx_metadata.return_data.data).map_err(TestError::from_raw_error)
+    }
+
+    fn token_2022_try_ui_amount_to_amount(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        ui_amount: &str,
+    ) -> TestResult<u64> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::instruction::ui_amount_to_amount(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            ui_amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        let tx_metadata = send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )?;
+
+        // the proxy forwards the token program return data as its own
+        tx_metadata
+            .return_data
+            .data
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(TestError::from_raw_error)
+    }
+}
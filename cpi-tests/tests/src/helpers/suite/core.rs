@@ -23,6 +23,7 @@ use {
 };
 
 pub const PROGRAM_NAME_TOKEN_2022_PROXY: &str = "token_2022_proxy";
+pub const PROGRAM_NAME_TRANSFER_HOOK: &str = "transfer_hook";
 
 pub struct ProgramId {
     // 3rd party
@@ -33,6 +34,7 @@ pub struct ProgramId {
 
     // custom
     pub token_2022_proxy: Pubkey,
+    pub transfer_hook: Pubkey,
 }
 
 pub struct App {
@@ -57,6 +59,7 @@ impl App {
 
             // custom
             token_2022_proxy: token_2022_proxy::ID.into(),
+            transfer_hook: transfer_hook::ID.into(),
         };
 
         // upload custom programs
@@ -65,6 +68,11 @@ impl App {
             PROGRAM_NAME_TOKEN_2022_PROXY,
             &program_id.token_2022_proxy,
         );
+        upload_program(
+            &mut litesvm,
+            PROGRAM_NAME_TRANSFER_HOOK,
+            &program_id.transfer_hook,
+        );
 
         Self {
             litesvm,
@@ -263,6 +263,18 @@ impl TestError {
         None
     }
 
+    /// Returns the message of the error matching the custom program error code of `info`,
+    /// if the code is a known token-2022 or token interface error
+    pub fn custom_program_error_message(&self) -> Option<&'static str> {
+        const ERROR_PREFIX: &str = "custom program error: 0x";
+
+        let hex_str = self.info.strip_prefix(ERROR_PREFIX)?;
+
+        u32::from_str_radix(hex_str, 16)
+            .ok()
+            .and_then(decode_custom_program_error)
+    }
+
     pub fn parse_program_error(logs: &[String]) -> Option<&str> {
         const ERROR_PREFIX: &str = "failed: ";
 
@@ -278,6 +290,32 @@ impl TestError {
     }
 }
 
+/// Maps a custom program error code to the message of the matching token-2022, token group,
+/// token metadata or transfer hook error
+pub fn decode_custom_program_error(code: u32) -> Option<&'static str> {
+    use {
+        pinocchio::program_error::ToStr,
+        pinocchio_token_2022::{
+            error::TokenError,
+            extension::{
+                token_group::TokenGroupError, token_metadata::TokenMetadataError,
+                transfer_hook_interface::error::TransferHookError,
+            },
+        },
+    };
+
+    TokenError::try_from(code)
+        .map(|error| error.to_str::<TokenError>())
+        .or_else(|_| TokenGroupError::try_from(code).map(|error| error.to_str::<TokenGroupError>()))
+        .or_else(|_| {
+            TokenMetadataError::try_from(code).map(|error| error.to_str::<TokenMetadataError>())
+        })
+        .or_else(|_| {
+            TransferHookError::try_from(code).map(|error| error.to_str::<TransferHookError>())
+        })
+        .ok()
+}
+
 /// to switch between SPL and Proxy programs
 pub enum Target {
     /// execute token-2022 instruction directly, read token-2022 state using spl interface
@@ -0,0 +1,49 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            immutable_owner::Token2022ImmutableOwnerExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+#[test]
+fn proxy_initialize_immutable_owner() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let (_, account_keypair) = app.token_2022_try_create_token_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ImmutableOwner]),
+    )?;
+    let account_pubkey = &account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_immutable_owner(Target::Proxy, AppUser::Admin, account_pubkey)?;
+
+    app.token_2022_try_initialize_token_account(
+        Target::Spl,
+        AppUser::Admin,
+        account_pubkey,
+        &mint_pubkey,
+        &AppUser::Alice.pubkey(),
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_immutable_owner(Target::Spl, account_pubkey)?,
+        true
+    );
+    assert_eq!(
+        app.token_2022_query_immutable_owner(Target::Proxy, account_pubkey)?,
+        true
+    );
+
+    Ok(())
+}
@@ -6,8 +6,8 @@ use {
             interest_bearing_mint::Token2022InterestBearingMintExtension,
         },
         suite::{
-            core::App,
-            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestResult},
+            core::{extension::get_account_data, App},
+            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestError, TestResult},
         },
     },
     pretty_assertions::assert_eq,
@@ -366,3 +366,73 @@ fn proxy_initialize_and_update_interest_bearing_mint_with_multisig() -> TestResu
 
     Ok(())
 }
+
+#[test]
+fn interest_bearing_mint_ui_amount_conversions() -> TestResult<()> {
+    use pinocchio_token_2022::extension::interest_bearing_mint::state::InterestBearingConfig as PinocchioInterestBearingConfig;
+
+    const SECONDS_PER_YEAR: i64 = 31_556_736;
+
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::InterestBearingConfig]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+
+    app.token_2022_try_initialize_interest_bearing_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        None,
+        500,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let config = app.token_2022_query_interest_bearing_mint(Target::Spl, mint_pubkey)?;
+    let data = &get_account_data(&app, mint_pubkey)?;
+    let pinocchio_config =
+        PinocchioInterestBearingConfig::from_bytes(data).map_err(TestError::from_raw_error)?;
+
+    let start = i64::from(config.initialization_timestamp);
+    let buffer = &mut [0u8; 64];
+
+    for unix_timestamp in [
+        start,
+        start + 1,
+        start + SECONDS_PER_YEAR,
+        start + 10 * SECONDS_PER_YEAR,
+    ] {
+        for amount in [0, 1, 1_500_000, u64::MAX / 3] {
+            let ui_amount = config
+                .amount_to_ui_amount(amount, decimals, unix_timestamp)
+                .unwrap();
+
+            assert_eq!(
+                pinocchio_config.amount_to_ui_amount(amount, decimals, unix_timestamp, buffer),
+                Some(ui_amount.as_str())
+            );
+            assert_eq!(
+                pinocchio_config
+                    .try_ui_amount_into_amount(&ui_amount, decimals, unix_timestamp)
+                    .map_err(TestError::from_raw_error)?,
+                config
+                    .try_ui_amount_into_amount(&ui_amount, decimals, unix_timestamp)
+                    .map_err(TestError::from_raw_error)?
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,10 @@
 #[cfg(test)]
+pub mod approve;
+#[cfg(test)]
+pub mod burn;
+#[cfg(test)]
+pub mod confidential_transfer;
+#[cfg(test)]
 pub mod cpi_guard;
 #[cfg(test)]
 pub mod default_account_state;
@@ -7,6 +13,8 @@ pub mod group_member_pointer;
 #[cfg(test)]
 pub mod group_pointer;
 #[cfg(test)]
+pub mod immutable_owner;
+#[cfg(test)]
 pub mod initialize_mint;
 #[cfg(test)]
 pub mod initialize_token_account;
@@ -15,38 +23,68 @@ pub mod memo_transfer;
 #[cfg(test)]
 pub mod metadata_pointer;
 #[cfg(test)]
+pub mod mint_close_authority;
+#[cfg(test)]
+pub mod mint_to;
+#[cfg(test)]
+pub mod multiple_extensions;
+#[cfg(test)]
+pub mod non_transferable;
+#[cfg(test)]
 pub mod permanent_delegate;
 #[cfg(test)]
 pub mod pausable;
 #[cfg(test)]
+pub mod reallocate;
+#[cfg(test)]
 pub mod scaled_ui_amount;
 #[cfg(test)]
 pub mod token_group;
 #[cfg(test)]
 pub mod token_group_member;
 #[cfg(test)]
+pub mod token_metadata;
+#[cfg(test)]
+pub mod transfer_checked;
+#[cfg(test)]
+pub mod transfer_fee;
+#[cfg(test)]
 pub mod transfer_hook;
 #[cfg(test)]
+pub mod ui_amount;
+#[cfg(test)]
 pub mod interest_bearing_mint;
 
 pub mod helpers {
     pub mod extensions {
         pub mod token_2022 {
+            pub mod approve;
+            pub mod burn;
+            pub mod confidential_transfer;
             pub mod cpi_guard;
             pub mod default_account_state;
             pub mod group_member_pointer;
             pub mod group_pointer;
+            pub mod immutable_owner;
             pub mod initialize_mint;
             pub mod initialize_multisig;
             pub mod initialize_token_account;
             pub mod memo_transfer;
             pub mod metadata_pointer;
+            pub mod mint_close_authority;
+            pub mod mint_to;
+            pub mod non_transferable;
             pub mod permanent_delegate;
             pub mod pausable;
+            pub mod reallocate;
             pub mod scaled_ui_amount;
             pub mod token_account;
             pub mod token_group;
+            pub mod token_metadata;
+            pub mod transfer_checked;
+            pub mod transfer_fee;
             pub mod transfer_hook;
+            pub mod ui_amount;
             pub mod interest_bearing_mint;
         }
     }
@@ -0,0 +1,60 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            mint_close_authority::Token2022MintCloseAuthorityExtension,
+        },
+        suite::{
+            core::App,
+            types::{to_optional_non_zero_pubkey, AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+#[test]
+fn proxy_initialize_mint_close_authority() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    for close_authority in [Some(AppUser::Alice.pubkey()), None] {
+        let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+            AppUser::Admin,
+            None,
+            Some(&[ExtensionType::MintCloseAuthority]),
+        )?;
+        let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+        app.token_2022_try_initialize_mint_close_authority(
+            Target::Proxy,
+            AppUser::Admin,
+            mint_pubkey,
+            close_authority.as_ref(),
+        )?;
+
+        app.token_2022_try_initialize_mint(
+            Target::Spl,
+            AppUser::Admin,
+            mint_pubkey,
+            6,
+            &AppUser::Admin.pubkey(),
+            None,
+        )?;
+
+        let expected = to_optional_non_zero_pubkey(close_authority.as_ref());
+
+        assert_eq!(
+            app.token_2022_query_mint_close_authority(Target::Spl, mint_pubkey)?
+                .close_authority,
+            expected
+        );
+        assert_eq!(
+            app.token_2022_query_mint_close_authority(Target::Proxy, mint_pubkey)?
+                .close_authority,
+            expected
+        );
+    }
+
+    Ok(())
+}
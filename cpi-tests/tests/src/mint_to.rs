@@ -0,0 +1,220 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_multisig::Token2022InitializeMultisigExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            mint_to::Token2022MintToExtension, token_account::Token2022TokenAccountExtension,
+        },
+        suite::{
+            core::App,
+            types::{
+                pin_to_sol_pubkey, AppToken, AppUser, PinPubkey, Target, TestError, TestResult,
+            },
+        },
+    },
+    pinocchio_token_2022::instructions::MAX_MULTISIG_SIGNERS,
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+};
+
+#[test]
+fn proxy_mint_to() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let (_, account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+    let account_pubkey = &account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(
+        Target::Proxy,
+        AppUser::Admin,
+        &mint_pubkey,
+        account_pubkey,
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Spl, account_pubkey)?,
+        1_000
+    );
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, account_pubkey)?,
+        1_000
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_mint_to_with_multisig() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    // create a multisig mint authority with 3 signers, requiring 2 signatures
+    let (_, multisig_kp) = app.token_2022_try_create_multisig(AppUser::Admin, None)?;
+    let multisig_pubkey = &multisig_kp.pubkey().to_bytes();
+    app.token_2022_try_initialize_multisig(
+        Target::Spl,
+        AppUser::Admin,
+        multisig_pubkey,
+        2,
+        &[
+            AppUser::Admin.pubkey(),
+            AppUser::Alice.pubkey(),
+            AppUser::Bob.pubkey(),
+        ],
+    )?;
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        multisig_pubkey,
+        None,
+    )?;
+
+    let (_, account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[],
+    )?;
+    let account_pubkey = &account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        account_pubkey,
+        multisig_pubkey,
+        &[AppUser::Alice, AppUser::Bob],
+        1_000,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, account_pubkey)?,
+        1_000
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_mint_to_with_too_many_signers() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, multisig_pubkey) = app.token_2022_try_create_and_init_multisig(
+        Target::Spl,
+        AppUser::Admin,
+        1,
+        &[AppUser::Alice.pubkey()],
+    )?;
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &multisig_pubkey,
+        None,
+    )?;
+
+    let (_, account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[],
+    )?;
+    let account_pubkey = &account_keypair.pubkey().to_bytes();
+
+    // the CPI rejects more signer accounts than a multisig can hold
+    let res = app
+        .token_2022_try_mint_to(
+            Target::Proxy,
+            AppUser::Admin,
+            mint_pubkey,
+            account_pubkey,
+            &multisig_pubkey,
+            &[AppUser::Alice; MAX_MULTISIG_SIGNERS + 1],
+            1_000,
+        )
+        .unwrap_err();
+    assert_eq!(
+        res,
+        TestError {
+            info: "invalid program argument".to_string(),
+            index: None,
+        },
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_mint_to_token_program_mint() -> TestResult<()> {
+    let mut app = App::new(false);
+    let token_program = app.program_id.token_program.to_bytes();
+    let mint = &pin_to_sol_pubkey(&AppToken::USDC.pubkey());
+    let alice = &pin_to_sol_pubkey(&AppUser::Alice.pubkey());
+    let ata = App::get_ata(alice, mint);
+    let balance_before = app.get_ata_token_balance(alice, mint);
+
+    // the admin is the mint authority of the app tokens, owned by the Token program
+    app.token_2022_try_mint_to_with_program(
+        Target::Proxy,
+        AppUser::Admin,
+        &token_program,
+        &mint.to_bytes(),
+        &ata.to_bytes(),
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000,
+    )?;
+
+    assert_eq!(
+        app.get_ata_token_balance(alice, mint),
+        balance_before + 1_000
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_mint_to_token_program_mint_with_wrong_program() -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = &pin_to_sol_pubkey(&AppToken::USDC.pubkey());
+    let alice = &pin_to_sol_pubkey(&AppUser::Alice.pubkey());
+    let ata = App::get_ata(alice, mint);
+
+    // the mint is owned by the Token program, not by Token-2022
+    let res = app
+        .token_2022_try_mint_to(
+            Target::Proxy,
+            AppUser::Admin,
+            &mint.to_bytes(),
+            &ata.to_bytes(),
+            &AppUser::Admin.pubkey(),
+            &[],
+            1_000,
+        )
+        .unwrap_err();
+    assert_eq!(
+        res,
+        TestError {
+            info: "incorrect program id for instruction".to_string(),
+            index: None,
+        },
+    );
+
+    Ok(())
+}
@@ -0,0 +1,257 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            pausable::Token2022PausableExtension, token_account::Token2022TokenAccountExtension,
+            transfer_hook::Token2022TransferHookExtension,
+        },
+        suite::{
+            core::App,
+            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    pinocchio_token_2022::{
+        extension::consts::ExtensionType as PinocchioExtensionType,
+        state::{Mint as PinocchioMint, TokenAccount as PinocchioTokenAccount},
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022_interface::{
+        extension::{
+            metadata_pointer::MetadataPointer, pausable::PausableConfig,
+            transfer_hook::TransferHook, ExtensionType,
+        },
+        state::{Account, Mint},
+    },
+};
+
+#[test]
+fn proxy_query_extensions_in_any_order() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[
+            ExtensionType::TransferHook,
+            ExtensionType::MetadataPointer,
+            ExtensionType::Pausable,
+        ]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey();
+    let decimals: u8 = 6;
+    let mint_authority = AppUser::Admin;
+    let freeze_authority = Some(AppUser::Admin.pubkey());
+    let pause_authority = &AppUser::Admin.pubkey();
+    let transfer_hook_authority = &AppUser::Alice.pubkey();
+    let transfer_hook_program = &AppUser::Bob.pubkey();
+
+    // initialize extensions in reverse order, so the transfer hook is the last TLV entry
+    app.token_2022_try_initialize_pausable(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        pause_authority,
+    )?;
+
+    app.token_2022_try_initialize_metadata_pointer(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        None,
+        Some(mint_pubkey),
+    )?;
+
+    app.token_2022_try_initialize_transfer_hook(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        Some(transfer_hook_authority),
+        Some(transfer_hook_program),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &mint_authority.pubkey(),
+        freeze_authority.as_ref(),
+    )?;
+
+    let pausable_config = PausableConfig {
+        authority: OptionalNonZeroPubkey(pin_pubkey_to_addr(pause_authority)),
+        paused: false.into(),
+    };
+    let metadata_pointer = MetadataPointer {
+        authority: OptionalNonZeroPubkey::default(),
+        metadata_address: OptionalNonZeroPubkey(pin_pubkey_to_addr(mint_pubkey)),
+    };
+    let transfer_hook = TransferHook {
+        authority: OptionalNonZeroPubkey(pin_pubkey_to_addr(transfer_hook_authority)),
+        program_id: OptionalNonZeroPubkey(pin_pubkey_to_addr(transfer_hook_program)),
+    };
+
+    // query via proxy
+    assert_eq!(
+        app.token_2022_query_pausable_config(Target::Proxy, mint_pubkey)?,
+        pausable_config
+    );
+    assert_eq!(
+        app.token_2022_query_metadata_pointer(Target::Proxy, mint_pubkey)?,
+        metadata_pointer
+    );
+    assert_eq!(
+        app.token_2022_query_transfer_hook(Target::Proxy, mint_pubkey)?,
+        transfer_hook
+    );
+
+    // verify with SPL query
+    assert_eq!(
+        app.token_2022_query_pausable_config(Target::Spl, mint_pubkey)?,
+        pausable_config
+    );
+    assert_eq!(
+        app.token_2022_query_metadata_pointer(Target::Spl, mint_pubkey)?,
+        metadata_pointer
+    );
+    assert_eq!(
+        app.token_2022_query_transfer_hook(Target::Spl, mint_pubkey)?,
+        transfer_hook
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_reject_token_account_as_mint() -> TestResult<()> {
+    let mut app = App::new(false);
+    let owner = AppUser::Admin;
+
+    let (_, mint_kp) = app.token_2022_try_create_mint_account(owner, None, None)?;
+    let mint_pubkey = &mint_kp.pubkey();
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    // token account with extensions, so it is longer than a base token account
+    let (_, token_account_kp) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &owner.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::CpiGuard],
+    )?;
+    let token_account_pubkey = &token_account_kp.pubkey();
+
+    // the proxy reads the mint state first, which must reject the account type
+    let res = app
+        .token_2022_try_initialize_pausable(
+            Target::Proxy,
+            AppUser::Admin,
+            token_account_pubkey,
+            &AppUser::Admin.pubkey(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        res,
+        TestError {
+            info: "invalid account data for instruction".to_string(),
+            index: None,
+        },
+    );
+
+    Ok(())
+}
+
+#[test]
+fn account_len_matches_spl() -> TestResult<()> {
+    let mint_cases: &[(&[PinocchioExtensionType], &[ExtensionType])] = &[
+        (&[], &[]),
+        (
+            &[
+                PinocchioExtensionType::TransferHook,
+                PinocchioExtensionType::MetadataPointer,
+                PinocchioExtensionType::Pausable,
+            ],
+            &[
+                ExtensionType::TransferHook,
+                ExtensionType::MetadataPointer,
+                ExtensionType::Pausable,
+            ],
+        ),
+        // duplicated extensions only take space once
+        (
+            &[
+                PinocchioExtensionType::InterestBearingConfig,
+                PinocchioExtensionType::InterestBearingConfig,
+            ],
+            &[ExtensionType::InterestBearingConfig],
+        ),
+        // adds up to the multisig length, so it needs padding
+        (
+            &[
+                PinocchioExtensionType::TransferHook,
+                PinocchioExtensionType::TokenGroup,
+                PinocchioExtensionType::Pausable,
+            ],
+            &[
+                ExtensionType::TransferHook,
+                ExtensionType::TokenGroup,
+                ExtensionType::Pausable,
+            ],
+        ),
+    ];
+
+    for (pinocchio_extensions, spl_extensions) in mint_cases {
+        assert_eq!(
+            PinocchioMint::get_account_len(pinocchio_extensions)
+                .map_err(TestError::from_raw_error)?,
+            ExtensionType::try_calculate_account_len::<Mint>(spl_extensions)
+                .map_err(TestError::from_raw_error)?
+        );
+    }
+
+    let account_cases: &[(&[PinocchioExtensionType], &[ExtensionType])] = &[
+        (&[], &[]),
+        (
+            &[PinocchioExtensionType::ImmutableOwner],
+            &[ExtensionType::ImmutableOwner],
+        ),
+        (
+            &[
+                PinocchioExtensionType::MemoTransfer,
+                PinocchioExtensionType::CpiGuard,
+                PinocchioExtensionType::TransferHookAccount,
+                PinocchioExtensionType::PausableAccount,
+            ],
+            &[
+                ExtensionType::MemoTransfer,
+                ExtensionType::CpiGuard,
+                ExtensionType::TransferHookAccount,
+                ExtensionType::PausableAccount,
+            ],
+        ),
+    ];
+
+    for (pinocchio_extensions, spl_extensions) in account_cases {
+        assert_eq!(
+            PinocchioTokenAccount::get_account_len(pinocchio_extensions)
+                .map_err(TestError::from_raw_error)?,
+            ExtensionType::try_calculate_account_len::<Account>(spl_extensions)
+                .map_err(TestError::from_raw_error)?
+        );
+    }
+
+    // variable length extensions can not be sized up front
+    assert!(PinocchioMint::get_account_len(&[PinocchioExtensionType::TokenMetadata]).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            non_transferable::Token2022NonTransferableExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+#[test]
+fn proxy_initialize_non_transferable_mint() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::NonTransferable]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_non_transferable_mint(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_non_transferable(Target::Spl, mint_pubkey)?,
+        true
+    );
+    assert_eq!(
+        app.token_2022_query_non_transferable(Target::Proxy, mint_pubkey)?,
+        true
+    );
+
+    Ok(())
+}
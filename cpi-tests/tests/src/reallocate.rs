@@ -0,0 +1,76 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_token_account::Token2022InitializeAccountExtension,
+            reallocate::Token2022ReallocateExtension,
+            token_account::Token2022TokenAccountExtension,
+        },
+        suite::{
+            core::{extension::get_account_data, App},
+            types::{AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::{extension::ExtensionType, state::Account},
+};
+
+#[test]
+fn proxy_get_account_data_size() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let extension_types = &[ExtensionType::MemoTransfer, ExtensionType::CpiGuard];
+
+    let expected =
+        ExtensionType::try_calculate_account_len::<Account>(extension_types).unwrap() as u64;
+
+    assert_eq!(
+        app.token_2022_try_get_account_data_size(
+            Target::Spl,
+            AppUser::Admin,
+            &mint_pubkey,
+            extension_types
+        )?,
+        expected
+    );
+    assert_eq!(
+        app.token_2022_try_get_account_data_size(
+            Target::Proxy,
+            AppUser::Admin,
+            &mint_pubkey,
+            extension_types
+        )?,
+        expected
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_reallocate() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let (_, account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+    let account_pubkey = &account_keypair.pubkey().to_bytes();
+    let extension_types = &[ExtensionType::MemoTransfer];
+
+    app.token_2022_try_reallocate(
+        Target::Proxy,
+        AppUser::Admin,
+        account_pubkey,
+        AppUser::Alice,
+        extension_types,
+    )?;
+
+    assert_eq!(
+        get_account_data(&app, account_pubkey)?.len(),
+        ExtensionType::try_calculate_account_len::<Account>(extension_types).unwrap()
+    );
+
+    Ok(())
+}
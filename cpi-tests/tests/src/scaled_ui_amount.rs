@@ -4,10 +4,11 @@ use {
             scaled_ui_amount::Token2022ScaledUiAmountExtension,
             initialize_mint::Token2022InitializeMintExtension,
             initialize_multisig::Token2022InitializeMultisigExtension,
+            ui_amount::Token2022UiAmountExtension,
         },
         suite::{
             core::App,
-            types::{AppUser, PinPubkey, Target, TestResult},
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
         },
     },
     pretty_assertions::assert_eq,
@@ -272,3 +273,88 @@ fn initialize_and_update_scaled_ui_amount_multisig() -> TestResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn scaled_ui_amount_ui_amount_conversions() -> TestResult<()> {
+    use pinocchio_token_2022::extension::scaled_ui_amount::state::ScaledUiAmountConfig as PinocchioScaledUiAmountConfig;
+
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ScaledUiAmount]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+
+    app.token_2022_try_initialize_scaled_ui_amount(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        1.5,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let config = app.token_2022_query_scaled_ui_amount(Target::Spl, mint_pubkey)?;
+    let pinocchio_config = PinocchioScaledUiAmountConfig::new(
+        &AppUser::Admin.pubkey(),
+        config.multiplier.into(),
+        config.new_multiplier_effective_timestamp.into(),
+        config.new_multiplier.into(),
+    );
+
+    let buffer = &mut [0u8; 64];
+
+    // the local conversions match the token program ones
+    for amount in [1, 1_500_000, 123_456_789] {
+        let ui_amount =
+            app.token_2022_try_amount_to_ui_amount(Target::Spl, AppUser::Admin, mint_pubkey, amount)?;
+
+        assert_eq!(
+            pinocchio_config.amount_to_ui_amount(amount, decimals, 0, buffer),
+            Some(ui_amount.as_str())
+        );
+        assert_eq!(
+            pinocchio_config
+                .try_ui_amount_into_amount(&ui_amount, decimals, 0)
+                .map_err(TestError::from_raw_error)?,
+            app.token_2022_try_ui_amount_to_amount(
+                Target::Spl,
+                AppUser::Admin,
+                mint_pubkey,
+                &ui_amount
+            )?
+        );
+    }
+
+    // the new multiplier applies from its effective timestamp
+    let pinocchio_config = PinocchioScaledUiAmountConfig::new(
+        pinocchio_config.authority(),
+        pinocchio_config.multiplier(),
+        2000,
+        3.0,
+    );
+
+    assert_eq!(pinocchio_config.current_multiplier(1999), 1.5);
+    assert_eq!(pinocchio_config.current_multiplier(2000), 3.0);
+    assert_eq!(
+        pinocchio_config.amount_to_ui_amount(1_000_000, decimals, 1999, buffer),
+        Some("1.5")
+    );
+    assert_eq!(
+        pinocchio_config.amount_to_ui_amount(1_000_000, decimals, 2000, buffer),
+        Some("3")
+    );
+
+    Ok(())
+}
@@ -7,13 +7,22 @@ use {
         },
         suite::{
             core::App,
-            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestResult},
+            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestError, TestResult},
         },
     },
+    pinocchio_token_2022::extension::{
+        consts::AccountType,
+        tlv::{get_tlv_len, get_used_len, init_extension, set_account_type, TLV_START},
+        token_group::state::TokenGroup as PinocchioTokenGroup,
+    },
     pretty_assertions::assert_eq,
     solana_signer::Signer,
-    spl_pod::optional_keys::OptionalNonZeroPubkey,
-    spl_token_2022_interface::extension::ExtensionType,
+    spl_pod::{bytemuck::pod_from_bytes, optional_keys::OptionalNonZeroPubkey},
+    spl_token_2022_interface::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::Mint,
+    },
+    spl_token_group_interface::state::TokenGroup,
 };
 
 #[test]
@@ -170,3 +179,48 @@ fn proxy_initialize_token_group() -> TestResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn write_token_group_into_zeroed_buffer() -> TestResult<()> {
+    let mint_pubkey = AppUser::Alice.pubkey();
+    let update_authority = AppUser::Admin.pubkey();
+    let max_size = 10;
+
+    let mut data = vec![0; TLV_START + get_tlv_len::<PinocchioTokenGroup>()];
+    // mark the base mint as initialized, `is_initialized` is at offset [45]
+    data[45] = 1;
+    set_account_type(&mut data, AccountType::Mint).map_err(TestError::from_raw_error)?;
+
+    *init_extension::<PinocchioTokenGroup>(&mut data).map_err(TestError::from_raw_error)? =
+        PinocchioTokenGroup::new(&mint_pubkey, Some(&update_authority), max_size);
+    PinocchioTokenGroup::from_bytes_mut(&mut data)
+        .and_then(|token_group| token_group.increment_size())
+        .map_err(TestError::from_raw_error)?;
+
+    assert_eq!(
+        get_used_len(&data).map_err(TestError::from_raw_error)?,
+        data.len()
+    );
+
+    // verify with SPL parser
+    let mint_with_extensions =
+        StateWithExtensions::<Mint>::unpack(&data).map_err(TestError::from_raw_error)?;
+    let extension_bytes = mint_with_extensions
+        .get_extension_bytes::<TokenGroup>()
+        .map_err(TestError::from_raw_error)?;
+    let token_group =
+        pod_from_bytes::<TokenGroup>(extension_bytes).map_err(TestError::from_raw_error)?;
+
+    let mut expected = TokenGroup::new(
+        &pin_pubkey_to_addr(&mint_pubkey),
+        OptionalNonZeroPubkey(pin_pubkey_to_addr(&update_authority)),
+        max_size,
+    );
+    expected
+        .increment_size()
+        .map_err(TestError::from_raw_error)?;
+
+    assert_eq!(token_group, &expected);
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            token_metadata::Token2022TokenMetadataExtension,
+        },
+        suite::{
+            core::App,
+            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022_interface::extension::ExtensionType,
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+};
+
+fn create_mint_with_metadata_pointer(app: &mut App) -> TestResult<pinocchio::pubkey::Pubkey> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::MetadataPointer]),
+    )?;
+    let mint_pubkey = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_metadata_pointer(
+        Target::Spl,
+        AppUser::Admin,
+        &mint_pubkey,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&mint_pubkey),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    Ok(mint_pubkey)
+}
+
+#[test]
+fn proxy_initialize_token_metadata() -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint_pubkey = &create_mint_with_metadata_pointer(&mut app)?;
+
+    app.token_2022_try_initialize_token_metadata(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        mint_pubkey,
+        AppUser::Admin,
+        "Token",
+        "TKN",
+        "https://example.com/token.json",
+    )?;
+
+    let expected = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey(pin_pubkey_to_addr(&AppUser::Admin.pubkey())),
+        mint: pin_pubkey_to_addr(mint_pubkey),
+        name: "Token".to_string(),
+        symbol: "TKN".to_string(),
+        uri: "https://example.com/token.json".to_string(),
+        additional_metadata: vec![],
+    };
+
+    assert_eq!(
+        app.token_2022_query_token_metadata(Target::Spl, mint_pubkey)?,
+        expected
+    );
+    assert_eq!(
+        app.token_2022_query_token_metadata(Target::Proxy, mint_pubkey)?,
+        expected
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_update_token_metadata() -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint_pubkey = &create_mint_with_metadata_pointer(&mut app)?;
+
+    app.token_2022_try_initialize_token_metadata(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        mint_pubkey,
+        AppUser::Admin,
+        "Token",
+        "TKN",
+        "https://example.com/token.json",
+    )?;
+
+    app.token_2022_try_update_token_metadata_field(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        AppUser::Admin,
+        Field::Name,
+        "Renamed Token",
+    )?;
+    for (key, value) in [("color", "blue"), ("size", "large")] {
+        app.token_2022_try_update_token_metadata_field(
+            Target::Proxy,
+            AppUser::Admin,
+            mint_pubkey,
+            AppUser::Admin,
+            Field::Key(key.to_string()),
+            value,
+        )?;
+    }
+    app.token_2022_try_remove_token_metadata_key(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        AppUser::Admin,
+        "color",
+        false,
+    )?;
+
+    let token_metadata = app.token_2022_query_token_metadata(Target::Proxy, mint_pubkey)?;
+    assert_eq!(token_metadata.name, "Renamed Token");
+    assert_eq!(
+        token_metadata.additional_metadata,
+        vec![("size".to_string(), "large".to_string())]
+    );
+    assert_eq!(
+        app.token_2022_query_token_metadata(Target::Spl, mint_pubkey)?,
+        token_metadata
+    );
+
+    app.token_2022_try_update_token_metadata_authority(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        AppUser::Admin,
+        None,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_metadata(Target::Proxy, mint_pubkey)?
+            .update_authority,
+        OptionalNonZeroPubkey::default()
+    );
+
+    Ok(())
+}
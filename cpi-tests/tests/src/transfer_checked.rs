@@ -0,0 +1,672 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            approve::Token2022ApproveExtension, cpi_guard::Token2022CpiGuardExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_multisig::Token2022InitializeMultisigExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            mint_to::Token2022MintToExtension, pausable::Token2022PausableExtension,
+            token_account::Token2022TokenAccountExtension,
+            transfer_checked::Token2022TransferCheckedExtension,
+            transfer_hook::Token2022TransferHookExtension,
+        },
+        suite::{
+            core::{extension::send_tx, App},
+            types::{pin_to_sol_pubkey, AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    pinocchio_token_2022::extension::transfer_hook::{
+        ExtraAccountMeta, EXECUTE_DISCRIMINATOR, EXTRA_ACCOUNT_METAS_SEED,
+    },
+    pretty_assertions::assert_eq,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::{transfer_hook::TransferHookAccount, ExtensionType},
+};
+
+#[test]
+fn proxy_transfer_checked() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let (_, source_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+    let source_pubkey = &source_keypair.pubkey().to_bytes();
+    let (_, destination_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+    let destination_pubkey = &destination_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        &mint_pubkey,
+        source_pubkey,
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000,
+    )?;
+
+    app.token_2022_try_transfer_checked(
+        Target::Proxy,
+        AppUser::Alice,
+        source_pubkey,
+        &mint_pubkey,
+        destination_pubkey,
+        &AppUser::Alice.pubkey(),
+        &[],
+        400,
+        6,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, source_pubkey)?,
+        600
+    );
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, destination_pubkey)?,
+        400
+    );
+
+    // the accounts of a mint without the pausable extension are not flagged
+    for target in [Target::Spl, Target::Proxy] {
+        assert!(!app.token_2022_query_pausable_account(target, source_pubkey)?);
+    }
+
+    // the pre-flight reports the same error as the token program
+    for target in [Target::Spl, Target::Proxy] {
+        let res = app
+            .token_2022_try_transfer_checked(
+                target,
+                AppUser::Alice,
+                source_pubkey,
+                &mint_pubkey,
+                destination_pubkey,
+                &AppUser::Alice.pubkey(),
+                &[],
+                400,
+                9,
+            )
+            .unwrap_err();
+        assert_eq!(
+            res,
+            TestError {
+                info: "custom program error: 0x12".to_string(),
+                index: None,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn proxy_transfer_checked_with_multisig() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    // the source account is owned by a multisig with 3 signers, requiring 2 signatures
+    let (_, multisig_pubkey) = app.token_2022_try_create_and_init_multisig(
+        Target::Spl,
+        AppUser::Admin,
+        2,
+        &[
+            AppUser::Admin.pubkey(),
+            AppUser::Alice.pubkey(),
+            AppUser::Bob.pubkey(),
+        ],
+    )?;
+
+    let (_, source_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &multisig_pubkey,
+        &mint_pubkey,
+        &[],
+    )?;
+    let source_pubkey = &source_keypair.pubkey().to_bytes();
+    let (_, destination_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+    let destination_pubkey = &destination_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        &mint_pubkey,
+        source_pubkey,
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000,
+    )?;
+
+    app.token_2022_try_transfer_checked(
+        Target::Proxy,
+        AppUser::Admin,
+        source_pubkey,
+        &mint_pubkey,
+        destination_pubkey,
+        &multisig_pubkey,
+        &[AppUser::Alice, AppUser::Bob],
+        400,
+        6,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, source_pubkey)?,
+        600
+    );
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, destination_pubkey)?,
+        400
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_transfer_checked_paused_mint() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::Pausable]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_pausable(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+    )?;
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let (_, source_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::PausableAccount],
+    )?;
+    let source_pubkey = &source_keypair.pubkey().to_bytes();
+    let (_, destination_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::PausableAccount],
+    )?;
+    let destination_pubkey = &destination_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        source_pubkey,
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000,
+    )?;
+    app.token_2022_try_pause(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+    )?;
+
+    for target in [Target::Spl, Target::Proxy] {
+        assert!(app.token_2022_query_pausable_account(target, source_pubkey)?);
+    }
+
+    for target in [Target::Spl, Target::Proxy] {
+        let res = app
+            .token_2022_try_transfer_checked(
+                target,
+                AppUser::Alice,
+                source_pubkey,
+                mint_pubkey,
+                destination_pubkey,
+                &AppUser::Alice.pubkey(),
+                &[],
+                400,
+                6,
+            )
+            .unwrap_err();
+        assert_eq!(
+            res,
+            TestError {
+                info: "custom program error: 0x43".to_string(),
+                index: None,
+            },
+        );
+        assert_eq!(
+            res.custom_program_error_message(),
+            Some("Transferring, minting, and burning is paused on this mint")
+        );
+    }
+
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, source_pubkey)?,
+        1_000
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_transfer_checked_cpi_guard() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let (_, source_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint_pubkey,
+        &[ExtensionType::CpiGuard],
+    )?;
+    let source_pubkey = &source_keypair.pubkey().to_bytes();
+    let (_, destination_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+    let destination_pubkey = &destination_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        &mint_pubkey,
+        source_pubkey,
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000,
+    )?;
+    app.token_2022_try_enable_cpi_guard(Target::Spl, AppUser::Alice, source_pubkey)?;
+
+    // the owner can no longer transfer through a CPI
+    let res = app
+        .token_2022_try_transfer_checked(
+            Target::Proxy,
+            AppUser::Alice,
+            source_pubkey,
+            &mint_pubkey,
+            destination_pubkey,
+            &AppUser::Alice.pubkey(),
+            &[],
+            400,
+            6,
+        )
+        .unwrap_err();
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x2a".to_string(),
+            index: None,
+        },
+    );
+    assert_eq!(
+        res.custom_program_error_message(),
+        Some("CPI Guard is enabled, and a program attempted to transfer user funds via CPI without using a delegate")
+    );
+
+    // but still can transfer directly
+    app.token_2022_try_transfer_checked(
+        Target::Spl,
+        AppUser::Alice,
+        source_pubkey,
+        &mint_pubkey,
+        destination_pubkey,
+        &AppUser::Alice.pubkey(),
+        &[],
+        400,
+        6,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, destination_pubkey)?,
+        400
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_transfer_checked_cpi_guard_owner_delegate() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let (_, source_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint_pubkey,
+        &[ExtensionType::CpiGuard],
+    )?;
+    let source_pubkey = &source_keypair.pubkey().to_bytes();
+    let (_, destination_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+    let destination_pubkey = &destination_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        &mint_pubkey,
+        source_pubkey,
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000,
+    )?;
+
+    // the owner approves themselves as the delegate of the account
+    app.token_2022_try_approve(
+        Target::Spl,
+        AppUser::Alice,
+        source_pubkey,
+        &AppUser::Alice.pubkey(),
+        &AppUser::Alice.pubkey(),
+        &[],
+        1_000,
+    )?;
+    app.token_2022_try_enable_cpi_guard(Target::Spl, AppUser::Alice, source_pubkey)?;
+
+    // the owner is still blocked when signing as the delegate
+    let res = app
+        .token_2022_try_transfer_checked(
+            Target::Proxy,
+            AppUser::Alice,
+            source_pubkey,
+            &mint_pubkey,
+            destination_pubkey,
+            &AppUser::Alice.pubkey(),
+            &[],
+            400,
+            6,
+        )
+        .unwrap_err();
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x2a".to_string(),
+            index: None,
+        },
+    );
+
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, source_pubkey)?,
+        1_000
+    );
+    assert_eq!(
+        app.token_2022_query_token_account_delegated_amount(Target::Proxy, source_pubkey)?,
+        1_000
+    );
+
+    Ok(())
+}
+
+/// Write the validation account of the transfer hook program, listing
+/// `(discriminator, address_config, is_signer, is_writable)` extra accounts
+fn set_extra_account_metas(
+    app: &mut App,
+    mint: &Pubkey,
+    extra_account_metas: &[(u8, [u8; 32], bool, bool)],
+) -> TestResult<Pubkey> {
+    let transfer_hook_program = app.program_id.transfer_hook;
+    let (validation_pubkey, _) = Pubkey::find_program_address(
+        &[EXTRA_ACCOUNT_METAS_SEED, mint.as_ref()],
+        &transfer_hook_program,
+    );
+
+    let value_len = 4 + extra_account_metas.len() * ExtraAccountMeta::LEN;
+    let mut data = Vec::with_capacity(12 + value_len);
+    data.extend_from_slice(&EXECUTE_DISCRIMINATOR);
+    data.extend_from_slice(&(value_len as u32).to_le_bytes());
+    data.extend_from_slice(&(extra_account_metas.len() as u32).to_le_bytes());
+
+    for (discriminator, address_config, is_signer, is_writable) in extra_account_metas {
+        data.push(*discriminator);
+        data.extend_from_slice(address_config);
+        data.push(*is_signer as u8);
+        data.push(*is_writable as u8);
+    }
+
+    let lamports = app.litesvm.minimum_balance_for_rent_exemption(data.len());
+    app.litesvm
+        .set_account(
+            validation_pubkey,
+            solana_account::Account {
+                lamports,
+                data,
+                owner: transfer_hook_program,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .map_err(TestError::from_raw_error)?;
+
+    Ok(validation_pubkey)
+}
+
+/// Build the address config of a PDA extra account from its encoded seeds
+fn pda_address_config(seeds: &[&[u8]]) -> [u8; 32] {
+    let mut address_config = [0; 32];
+    let mut offset = 0;
+
+    for seed in seeds {
+        address_config[offset..offset + seed.len()].copy_from_slice(seed);
+        offset += seed.len();
+    }
+
+    address_config
+}
+
+#[test]
+fn proxy_transfer_checked_with_hook() -> TestResult<()> {
+    let mut app = App::new(false);
+    let transfer_hook_program = app.program_id.transfer_hook;
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::TransferHook]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_transfer_hook(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&transfer_hook_program.to_bytes()),
+    )?;
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let (_, source_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::TransferHookAccount],
+    )?;
+    let source_pubkey = &source_keypair.pubkey().to_bytes();
+    let (_, destination_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::TransferHookAccount],
+    )?;
+    let destination_pubkey = &destination_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        source_pubkey,
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000,
+    )?;
+
+    // extra accounts:
+    //   - a fixed address
+    //   - a writable PDA seeded by a literal and the destination key
+    //   - a PDA seeded by the amount and the source owner
+    let fixed_pubkey = Pubkey::new_unique();
+    let extra_account_metas = [
+        (0, fixed_pubkey.to_bytes(), false, false),
+        (
+            1,
+            pda_address_config(&[&[1, 7], b"counter", &[3, 2]]),
+            false,
+            true,
+        ),
+        (
+            1,
+            pda_address_config(&[&[2, 8, 8], &[4, 0, 32, 32]]),
+            false,
+            false,
+        ),
+    ];
+    let validation_pubkey = set_extra_account_metas(
+        &mut app,
+        &pin_to_sol_pubkey(mint_pubkey),
+        &extra_account_metas,
+    )?;
+
+    let (counter_pubkey, _) =
+        Pubkey::find_program_address(&[b"counter", destination_pubkey], &transfer_hook_program);
+    let additional_accounts = |amount: u64| {
+        let (amount_pubkey, _) = Pubkey::find_program_address(
+            &[&amount.to_le_bytes(), &AppUser::Alice.pubkey()],
+            &transfer_hook_program,
+        );
+
+        vec![
+            AccountMeta::new_readonly(fixed_pubkey, false),
+            AccountMeta::new(counter_pubkey, false),
+            AccountMeta::new_readonly(amount_pubkey, false),
+            AccountMeta::new_readonly(validation_pubkey, false),
+            AccountMeta::new_readonly(transfer_hook_program, false),
+        ]
+    };
+
+    for target in [Target::Spl, Target::Proxy] {
+        app.token_2022_try_transfer_checked_with_hook(
+            target,
+            AppUser::Alice,
+            source_pubkey,
+            mint_pubkey,
+            destination_pubkey,
+            &AppUser::Alice.pubkey(),
+            &[],
+            &additional_accounts(200),
+            200,
+            6,
+        )?;
+    }
+
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, source_pubkey)?,
+        600
+    );
+    assert_eq!(
+        app.token_2022_query_token_account_amount(Target::Proxy, destination_pubkey)?,
+        400
+    );
+
+    // the accounts are only flagged while the hook program is invoked
+    for target in [Target::Spl, Target::Proxy] {
+        assert_eq!(
+            app.token_2022_query_transfer_hook_account(target, source_pubkey)?,
+            TransferHookAccount {
+                transferring: false.into(),
+            }
+        );
+    }
+
+    // the extra accounts resolved for another amount are rejected
+    let res = app
+        .token_2022_try_transfer_checked_with_hook(
+            Target::Proxy,
+            AppUser::Alice,
+            source_pubkey,
+            mint_pubkey,
+            destination_pubkey,
+            &AppUser::Alice.pubkey(),
+            &[],
+            &additional_accounts(100),
+            200,
+            6,
+        )
+        .unwrap_err();
+    assert_eq!(
+        res,
+        TestError {
+            info: "insufficient account keys for instruction".to_string(),
+            index: None,
+        },
+    );
+
+    // the hook program only accepts an `Execute` invoked during a transfer
+    let mut execute_data = EXECUTE_DISCRIMINATOR.to_vec();
+    execute_data.extend_from_slice(&200u64.to_le_bytes());
+    let execute_ix = Instruction {
+        program_id: transfer_hook_program,
+        accounts: [
+            source_pubkey,
+            mint_pubkey,
+            destination_pubkey,
+            &AppUser::Alice.pubkey(),
+            &validation_pubkey.to_bytes(),
+        ]
+        .into_iter()
+        .map(|x| AccountMeta::new_readonly(pin_to_sol_pubkey(x), false))
+        .collect(),
+        data: execute_data,
+    };
+    let res = send_tx(
+        &mut app.litesvm,
+        &[execute_ix],
+        &[AppUser::Alice.keypair()],
+        app.is_log_displayed,
+    )
+    .unwrap_err();
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x7dc8348f".to_string(),
+            index: None,
+        },
+    );
+    assert_eq!(
+        res.custom_program_error_message(),
+        Some("Program called outside of a token transfer")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,511 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension, mint_to::Token2022MintToExtension,
+            token_account::Token2022TokenAccountExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+        },
+        suite::{
+            core::App,
+            types::{
+                to_optional_non_zero_pubkey, AppUser, PinPubkey, Target, TestError, TestResult,
+            },
+        },
+    },
+    pinocchio::pubkey::Pubkey,
+    pinocchio_token_2022::extension::transfer_fee::state::TransferFee as PinocchioTransferFee,
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::{
+        transfer_fee::{TransferFee, TransferFeeConfig},
+        ExtensionType,
+    },
+};
+
+#[test]
+fn proxy_initialize_transfer_fee_config() -> TestResult<()> {
+    let mut app = App::new(false);
+    let transfer_fee_basis_points: u16 = 50;
+    let maximum_fee: u64 = 5_000;
+
+    let transfer_fee = TransferFee {
+        epoch: 0.into(),
+        maximum_fee: maximum_fee.into(),
+        transfer_fee_basis_points: transfer_fee_basis_points.into(),
+    };
+    let expected_config = TransferFeeConfig {
+        transfer_fee_config_authority: to_optional_non_zero_pubkey(Some(&AppUser::Admin.pubkey())),
+        withdraw_withheld_authority: to_optional_non_zero_pubkey(Some(&AppUser::Alice.pubkey())),
+        withheld_amount: 0.into(),
+        older_transfer_fee: transfer_fee,
+        newer_transfer_fee: transfer_fee,
+    };
+
+    for target in [Target::Spl, Target::Proxy] {
+        let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+            AppUser::Admin,
+            None,
+            Some(&[ExtensionType::TransferFeeConfig]),
+        )?;
+        let mint = &mint_keypair.pubkey().to_bytes();
+
+        app.token_2022_try_initialize_transfer_fee_config(
+            target,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+            Some(&AppUser::Alice.pubkey()),
+            transfer_fee_basis_points,
+            maximum_fee,
+        )?;
+
+        app.token_2022_try_initialize_mint(
+            Target::Spl,
+            AppUser::Admin,
+            mint,
+            6,
+            &AppUser::Admin.pubkey(),
+            None,
+        )?;
+
+        assert_eq!(
+            app.token_2022_query_transfer_fee_config(Target::Spl, mint)?,
+            expected_config
+        );
+        assert_eq!(
+            app.token_2022_query_transfer_fee_config(Target::Proxy, mint)?,
+            expected_config
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn proxy_set_transfer_fee() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::TransferFeeConfig]),
+    )?;
+    let mint = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_transfer_fee_config(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        Some(&AppUser::Admin.pubkey()),
+        None,
+        50,
+        5_000,
+    )?;
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.token_2022_try_set_transfer_fee(
+        Target::Proxy,
+        AppUser::Admin,
+        mint,
+        &AppUser::Admin.pubkey(),
+        100,
+        10_000,
+    )?;
+
+    let config = app.token_2022_query_transfer_fee_config(Target::Proxy, mint)?;
+    assert_eq!(
+        u16::from(config.newer_transfer_fee.transfer_fee_basis_points),
+        100
+    );
+    assert_eq!(u64::from(config.newer_transfer_fee.maximum_fee), 10_000);
+    assert_eq!(
+        u16::from(config.older_transfer_fee.transfer_fee_basis_points),
+        50
+    );
+    assert_eq!(
+        app.token_2022_query_transfer_fee_config(Target::Spl, mint)?,
+        config
+    );
+
+    Ok(())
+}
+
+const DECIMALS: u8 = 6;
+const TRANSFER_FEE_BASIS_POINTS: u16 = 100;
+const MAXIMUM_FEE: u64 = 5_000;
+
+struct TransferFeeAccounts {
+    mint: Pubkey,
+    source: Pubkey,
+    destination: Pubkey,
+    receiver: Pubkey,
+}
+
+/// Creates a mint with a transfer fee, a funded source account owned by Alice,
+/// a destination account owned by Bob and a fee receiver account owned by Admin
+fn init_transfer_fee_accounts(app: &mut App) -> TestResult<TransferFeeAccounts> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::TransferFeeConfig]),
+    )?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_transfer_fee_config(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&AppUser::Admin.pubkey()),
+        TRANSFER_FEE_BASIS_POINTS,
+        MAXIMUM_FEE,
+    )?;
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let mut create_account = |owner: AppUser| -> TestResult<Pubkey> {
+        let (_, keypair) = app.token_2022_try_create_and_init_token_account(
+            AppUser::Admin,
+            &owner.pubkey(),
+            &mint,
+            &[ExtensionType::TransferFeeAmount],
+        )?;
+
+        Ok(keypair.pubkey().to_bytes())
+    };
+    let source = create_account(AppUser::Alice)?;
+    let destination = create_account(AppUser::Bob)?;
+    let receiver = create_account(AppUser::Admin)?;
+
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        &source,
+        &AppUser::Admin.pubkey(),
+        &[],
+        1_000_000,
+    )?;
+
+    Ok(TransferFeeAccounts {
+        mint,
+        source,
+        destination,
+        receiver,
+    })
+}
+
+#[test]
+fn proxy_transfer_checked_with_fee() -> TestResult<()> {
+    for target in [Target::Spl, Target::Proxy] {
+        let mut app = App::new(false);
+        let TransferFeeAccounts {
+            mint,
+            source,
+            destination,
+            ..
+        } = init_transfer_fee_accounts(&mut app)?;
+
+        // 1% of 10_000
+        app.token_2022_try_transfer_checked_with_fee(
+            target,
+            AppUser::Alice,
+            &source,
+            &mint,
+            &destination,
+            10_000,
+            DECIMALS,
+            100,
+        )?;
+
+        assert_eq!(
+            app.token_2022_query_token_account_amount(target, &source)?,
+            990_000
+        );
+        assert_eq!(
+            app.token_2022_query_token_account_amount(target, &destination)?,
+            9_900
+        );
+        assert_eq!(
+            app.token_2022_query_transfer_fee_amount(target, &destination)?,
+            100
+        );
+
+        let res = app
+            .token_2022_try_transfer_checked_with_fee(
+                target,
+                AppUser::Alice,
+                &source,
+                &mint,
+                &destination,
+                10_000,
+                DECIMALS,
+                99,
+            )
+            .unwrap_err();
+        assert_eq!(
+            res,
+            TestError {
+                info: "custom program error: 0x20".to_string(),
+                index: None,
+            }
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn proxy_harvest_withheld_tokens_to_mint() -> TestResult<()> {
+    for target in [Target::Spl, Target::Proxy] {
+        let mut app = App::new(false);
+        let TransferFeeAccounts {
+            mint,
+            source,
+            destination,
+            ..
+        } = init_transfer_fee_accounts(&mut app)?;
+
+        app.token_2022_try_transfer_checked_with_fee(
+            Target::Spl,
+            AppUser::Alice,
+            &source,
+            &mint,
+            &destination,
+            10_000,
+            DECIMALS,
+            100,
+        )?;
+
+        app.token_2022_try_harvest_withheld_tokens_to_mint(
+            target,
+            AppUser::Bob,
+            &mint,
+            &[destination],
+        )?;
+
+        assert_eq!(
+            app.token_2022_query_transfer_fee_amount(target, &destination)?,
+            0
+        );
+        assert_eq!(
+            u64::from(
+                app.token_2022_query_transfer_fee_config(target, &mint)?
+                    .withheld_amount
+            ),
+            100
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn proxy_withdraw_withheld_tokens_from_mint() -> TestResult<()> {
+    for target in [Target::Spl, Target::Proxy] {
+        let mut app = App::new(false);
+        let TransferFeeAccounts {
+            mint,
+            source,
+            destination,
+            receiver,
+        } = init_transfer_fee_accounts(&mut app)?;
+
+        app.token_2022_try_transfer_checked_with_fee(
+            Target::Spl,
+            AppUser::Alice,
+            &source,
+            &mint,
+            &destination,
+            10_000,
+            DECIMALS,
+            100,
+        )?;
+        app.token_2022_try_harvest_withheld_tokens_to_mint(
+            Target::Spl,
+            AppUser::Bob,
+            &mint,
+            &[destination],
+        )?;
+
+        app.token_2022_try_withdraw_withheld_tokens_from_mint(
+            target,
+            AppUser::Admin,
+            &mint,
+            &receiver,
+        )?;
+
+        assert_eq!(
+            app.token_2022_query_token_account_amount(target, &receiver)?,
+            100
+        );
+        assert_eq!(
+            u64::from(
+                app.token_2022_query_transfer_fee_config(target, &mint)?
+                    .withheld_amount
+            ),
+            0
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn proxy_withdraw_withheld_tokens_from_accounts() -> TestResult<()> {
+    for target in [Target::Spl, Target::Proxy] {
+        let mut app = App::new(false);
+        let TransferFeeAccounts {
+            mint,
+            source,
+            destination,
+            receiver,
+        } = init_transfer_fee_accounts(&mut app)?;
+
+        app.token_2022_try_transfer_checked_with_fee(
+            Target::Spl,
+            AppUser::Alice,
+            &source,
+            &mint,
+            &destination,
+            10_000,
+            DECIMALS,
+            100,
+        )?;
+
+        app.token_2022_try_withdraw_withheld_tokens_from_accounts(
+            target,
+            AppUser::Admin,
+            &mint,
+            &receiver,
+            &[destination],
+        )?;
+
+        assert_eq!(
+            app.token_2022_query_token_account_amount(target, &receiver)?,
+            100
+        );
+        assert_eq!(
+            app.token_2022_query_transfer_fee_amount(target, &destination)?,
+            0
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn transfer_fee_zero_basis_points() {
+    let fee = PinocchioTransferFee::new(0, u64::MAX, 0);
+
+    for amount in [0, 1, 100, 1_000_000, u64::MAX] {
+        assert_eq!(fee.calculate_fee(amount), Some(0));
+        assert_eq!(fee.calculate_post_fee_amount(amount), Some(amount));
+        assert_eq!(fee.calculate_pre_fee_amount(amount), Some(amount));
+        assert_eq!(fee.calculate_inverse_fee(amount), Some(0));
+    }
+}
+
+#[test]
+fn transfer_fee_max_basis_points() {
+    let fee = PinocchioTransferFee::new(0, 5_000, 10_000);
+
+    assert_eq!(fee.calculate_fee(0), Some(0));
+    assert_eq!(fee.calculate_fee(1_000), Some(1_000));
+    assert_eq!(fee.calculate_fee(10_000), Some(5_000));
+    assert_eq!(fee.calculate_post_fee_amount(10_000), Some(5_000));
+
+    // the whole transfer is taken as fee, so only the maximum fee can be inverted
+    assert_eq!(fee.calculate_pre_fee_amount(0), Some(0));
+    assert_eq!(fee.calculate_pre_fee_amount(100), Some(5_100));
+    assert_eq!(fee.calculate_inverse_fee(100), Some(5_000));
+}
+
+#[test]
+fn transfer_fee_maximum_fee_cap() {
+    let fee = PinocchioTransferFee::new(0, 50, 100);
+
+    assert_eq!(fee.calculate_fee(1_000), Some(10));
+    assert_eq!(fee.calculate_fee(5_000), Some(50));
+    assert_eq!(fee.calculate_fee(10_000), Some(50));
+    assert_eq!(fee.calculate_fee(u64::MAX), Some(50));
+    assert_eq!(fee.calculate_pre_fee_amount(10_000), Some(10_050));
+    assert_eq!(fee.calculate_inverse_fee(10_000), Some(50));
+}
+
+#[test]
+fn transfer_fee_rounding() {
+    let fee = PinocchioTransferFee::new(0, u64::MAX, 100);
+
+    // fees are rounded up
+    assert_eq!(fee.calculate_fee(1), Some(1));
+    assert_eq!(fee.calculate_fee(100), Some(1));
+    assert_eq!(fee.calculate_fee(101), Some(2));
+    assert_eq!(fee.calculate_pre_fee_amount(u64::MAX), None);
+
+    let fee = PinocchioTransferFee::new(0, u64::MAX, 1_000);
+
+    assert_eq!(fee.calculate_pre_fee_amount(9), Some(10));
+    assert_eq!(fee.calculate_post_fee_amount(10), Some(9));
+    assert_eq!(fee.calculate_post_fee_amount(11), Some(9));
+}
+
+#[test]
+fn transfer_fee_matches_spl() {
+    for (basis_points, maximum_fee) in [
+        (0, 0),
+        (1, 1),
+        (50, 5_000),
+        (100, u64::MAX),
+        (9_999, 1_000_000),
+        (10_000, 5_000),
+        (10_000, u64::MAX),
+    ] {
+        let fee = PinocchioTransferFee::new(0, maximum_fee, basis_points);
+        let spl_fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        };
+
+        for amount in [
+            0,
+            1,
+            9,
+            10,
+            99,
+            100,
+            101,
+            9_999,
+            1_000_000,
+            u64::MAX / 2,
+            u64::MAX,
+        ] {
+            assert_eq!(fee.calculate_fee(amount), spl_fee.calculate_fee(amount));
+            assert_eq!(
+                fee.calculate_post_fee_amount(amount),
+                spl_fee.calculate_post_fee_amount(amount)
+            );
+            assert_eq!(
+                fee.calculate_pre_fee_amount(amount),
+                spl_fee.calculate_pre_fee_amount(amount)
+            );
+            assert_eq!(
+                fee.calculate_inverse_fee(amount),
+                spl_fee.calculate_inverse_fee(amount)
+            );
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_token_account::Token2022InitializeAccountExtension,
+            ui_amount::Token2022UiAmountExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+};
+
+#[test]
+fn proxy_amount_to_ui_amount() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    for target in [Target::Spl, Target::Proxy] {
+        assert_eq!(
+            app.token_2022_try_amount_to_ui_amount(
+                target,
+                AppUser::Admin,
+                &mint_pubkey,
+                1_500_000
+            )?,
+            "1.5"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn proxy_ui_amount_to_amount() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_pubkey) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    for target in [Target::Spl, Target::Proxy] {
+        assert_eq!(
+            app.token_2022_try_ui_amount_to_amount(target, AppUser::Admin, &mint_pubkey, "1.5")?,
+            1_500_000
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use pinocchio::pubkey::{find_program_address, Pubkey};
+use pinocchio_pubkey::derive_address;
+
+/// Return the address and bump seed of the associated token account of the given
+/// wallet and mint.
+///
+/// The `token_program` is the program owning the mint, either the Token or the
+/// Token-2022 program.
+#[inline]
+pub fn find_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> (Pubkey, u8) {
+    find_program_address(&[wallet, token_program, mint], &crate::ID)
+}
+
+/// Return the address of the associated token account of the given wallet and mint.
+///
+/// When the bump seed is known, [`derive_associated_token_address`] derives the
+/// address without searching for it.
+#[inline]
+pub fn get_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    find_associated_token_address(wallet, mint, token_program).0
+}
+
+/// Derive the address of the associated token account of the given wallet and mint
+/// from a known bump seed.
+///
+/// This is cheaper than [`get_associated_token_address`], but does not check that
+/// the address is a valid program derived address: the bump seed must be the one
+/// returned by [`find_associated_token_address`], e.g. stored by the program.
+#[inline]
+pub fn derive_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    bump: u8,
+) -> Pubkey {
+    derive_address(&[wallet, token_program, mint], Some(bump), &crate::ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::pubkey::Pubkey;
+    use pinocchio_pubkey::pubkey;
+
+    use crate::address::{find_associated_token_address, get_associated_token_address};
+
+    const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+    const WALLET: Pubkey = pubkey!("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+    const USDC_MINT: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+
+    #[test]
+    fn test_associated_token_address() {
+        let expected = [
+            (
+                &WALLET,
+                &USDC_MINT,
+                &TOKEN_PROGRAM_ID,
+                pubkey!("FGETo8T8wMcN2wCjav8VK6eh3dLk63evNDPxzLSJra8B"),
+                254,
+            ),
+            (
+                &WALLET,
+                &USDC_MINT,
+                &TOKEN_2022_PROGRAM_ID,
+                pubkey!("GdjpegrtGwU3pgtzPivYVViSA8rmGL248qBVKzsrU3DD"),
+                254,
+            ),
+            (
+                &[2; 32],
+                &[3; 32],
+                &TOKEN_PROGRAM_ID,
+                pubkey!("BKbxqhBJfLZNgac5dEUesF1V5xRZSzxDkcpQBAy4c8sw"),
+                254,
+            ),
+            (
+                &[2; 32],
+                &[3; 32],
+                &TOKEN_2022_PROGRAM_ID,
+                pubkey!("DB2rSUqWCtYs8BwzuLqJZK1DfqQ22eeudg4LBS1X6Urh"),
+                255,
+            ),
+        ];
+
+        for (wallet, mint, token_program, address, bump) in expected {
+            assert_eq!(
+                find_associated_token_address(wallet, mint, token_program),
+                (address, bump)
+            );
+            assert_eq!(
+                get_associated_token_address(wallet, mint, token_program),
+                address
+            );
+        }
+    }
+}
@@ -1,5 +1,7 @@
 #![no_std]
 
+pub mod address;
 pub mod instructions;
+pub mod validation;
 
 pinocchio_pubkey::declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
@@ -0,0 +1,275 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{create_program_address, Pubkey},
+};
+use pinocchio_token_2022::interface::{InterfaceTokenAccount, TokenProgram};
+
+use crate::address::get_associated_token_address;
+
+/// Check that the given account is the initialized associated token account of the
+/// given wallet and mint.
+///
+/// The account may belong to either the Token or the Token-2022 program:
+///   - the account must be owned by a token program, otherwise fails with
+///     `InvalidAccountOwner`;
+///   - its address must be the associated token address for that token program,
+///     otherwise fails with `InvalidSeeds`;
+///   - it must be an initialized token account of the mint, otherwise fails with
+///     `InvalidAccountData` or `UninitializedAccount`;
+///   - it must be owned by the wallet, otherwise fails with `IllegalOwner`.
+#[inline]
+pub fn validate_ata(
+    account: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Result<(), ProgramError> {
+    let token_program = TokenProgram::from_account_info(account)?;
+
+    if account.key() != &get_associated_token_address(wallet, mint, token_program.id()) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    validate_token_account(account, wallet, mint)
+}
+
+/// Check that the given account is the initialized associated token account of the
+/// given wallet and mint, derived with the given bump seed.
+///
+/// This is cheaper than [`validate_ata`] since the address is derived without
+/// searching for the bump seed, e.g. when the bump seed is stored by the program.
+/// Fails as [`validate_ata`] does; the address check also fails with `InvalidSeeds`
+/// if the bump seed does not derive a valid program derived address.
+#[inline]
+pub fn validate_ata_with_bump(
+    account: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    bump: u8,
+) -> Result<(), ProgramError> {
+    let token_program = TokenProgram::from_account_info(account)?;
+
+    let address = create_program_address(&[wallet, token_program.id(), mint, &[bump]], &crate::ID)
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+
+    if account.key() != &address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    validate_token_account(account, wallet, mint)
+}
+
+/// Check that the given account is an initialized token account of the given wallet
+/// and mint.
+#[inline(always)]
+fn validate_token_account(
+    account: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Result<(), ProgramError> {
+    let token_account = InterfaceTokenAccount::from_account_info(account)?;
+
+    if !token_account.is_initialized() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    if token_account.mint() != mint {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if token_account.owner() != wallet {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::{vec, vec::Vec};
+
+    use pinocchio::{
+        entrypoint::input::{InputAccount, InputBuilder},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    use crate::{
+        address::find_associated_token_address,
+        validation::{validate_ata, validate_ata_with_bump},
+    };
+
+    const TOKEN_PROGRAM_ID: Pubkey =
+        pinocchio_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    const WALLET: Pubkey = [2; 32];
+    const MINT: Pubkey = [3; 32];
+
+    /// Return the data of an initialized token account.
+    fn token_account_data(mint: &Pubkey, owner: &Pubkey) -> Vec<u8> {
+        let mut data = vec![0; 165];
+        data[..32].copy_from_slice(mint);
+        data[32..64].copy_from_slice(owner);
+        // account state
+        data[108] = 1;
+        data
+    }
+
+    /// Run `validate` against an account with the given key, owner and data.
+    fn validate(
+        key: Pubkey,
+        owner: Pubkey,
+        data: Vec<u8>,
+        validate: impl FnOnce(&pinocchio::account_info::AccountInfo) -> Result<(), ProgramError>,
+    ) -> Result<(), ProgramError> {
+        InputBuilder::new(&crate::ID)
+            .account(InputAccount::new(key, owner, 1_000_000, data))
+            .build()
+            .process(|_, accounts, _| validate(&accounts[0]))
+    }
+
+    #[test]
+    fn test_validate_ata() {
+        for token_program in [TOKEN_PROGRAM_ID, pinocchio_token_2022::ID] {
+            let (address, bump) = find_associated_token_address(&WALLET, &MINT, &token_program);
+            let data = token_account_data(&MINT, &WALLET);
+
+            assert_eq!(
+                validate(address, token_program, data.clone(), |account| {
+                    validate_ata(account, &WALLET, &MINT)
+                }),
+                Ok(())
+            );
+            assert_eq!(
+                validate(address, token_program, data, |account| {
+                    validate_ata_with_bump(account, &WALLET, &MINT, bump)
+                }),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_ata_wrong_mint() {
+        let (address, bump) = find_associated_token_address(&WALLET, &MINT, &TOKEN_PROGRAM_ID);
+
+        // the address is derived from another mint
+        let data = token_account_data(&MINT, &WALLET);
+        assert_eq!(
+            validate(address, TOKEN_PROGRAM_ID, data.clone(), |account| {
+                validate_ata(account, &WALLET, &[4; 32])
+            }),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            validate(address, TOKEN_PROGRAM_ID, data, |account| {
+                validate_ata_with_bump(account, &WALLET, &[4; 32], bump)
+            }),
+            Err(ProgramError::InvalidSeeds)
+        );
+
+        // the token account holds another mint
+        let data = token_account_data(&[4; 32], &WALLET);
+        assert_eq!(
+            validate(address, TOKEN_PROGRAM_ID, data.clone(), |account| {
+                validate_ata(account, &WALLET, &MINT)
+            }),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            validate(address, TOKEN_PROGRAM_ID, data, |account| {
+                validate_ata_with_bump(account, &WALLET, &MINT, bump)
+            }),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_validate_ata_wrong_owner() {
+        let (address, bump) = find_associated_token_address(&WALLET, &MINT, &TOKEN_PROGRAM_ID);
+
+        // the address is derived from another wallet
+        let data = token_account_data(&MINT, &WALLET);
+        assert_eq!(
+            validate(address, TOKEN_PROGRAM_ID, data, |account| {
+                validate_ata(account, &[5; 32], &MINT)
+            }),
+            Err(ProgramError::InvalidSeeds)
+        );
+
+        // the token account is owned by another wallet
+        let data = token_account_data(&MINT, &[5; 32]);
+        assert_eq!(
+            validate(address, TOKEN_PROGRAM_ID, data.clone(), |account| {
+                validate_ata(account, &WALLET, &MINT)
+            }),
+            Err(ProgramError::IllegalOwner)
+        );
+        assert_eq!(
+            validate(address, TOKEN_PROGRAM_ID, data, |account| {
+                validate_ata_with_bump(account, &WALLET, &MINT, bump)
+            }),
+            Err(ProgramError::IllegalOwner)
+        );
+    }
+
+    #[test]
+    fn test_validate_ata_wrong_program() {
+        let (address, bump) = find_associated_token_address(&WALLET, &MINT, &TOKEN_PROGRAM_ID);
+        let data = token_account_data(&MINT, &WALLET);
+
+        // the account is not owned by a token program
+        assert_eq!(
+            validate(address, [6; 32], data.clone(), |account| {
+                validate_ata(account, &WALLET, &MINT)
+            }),
+            Err(ProgramError::InvalidAccountOwner)
+        );
+
+        // the address is derived for the Token program, but the account is owned
+        // by the Token-2022 program
+        assert_eq!(
+            validate(address, pinocchio_token_2022::ID, data.clone(), |account| {
+                validate_ata(account, &WALLET, &MINT)
+            }),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            validate(address, pinocchio_token_2022::ID, data, |account| {
+                validate_ata_with_bump(account, &WALLET, &MINT, bump)
+            }),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn test_validate_ata_wrong_bump() {
+        let (address, bump) = find_associated_token_address(&WALLET, &MINT, &TOKEN_PROGRAM_ID);
+        let data = token_account_data(&MINT, &WALLET);
+
+        // a bump seed deriving another address, and one deriving a point on the curve
+        for bump in [bump - 1, bump + 1] {
+            assert_eq!(
+                validate(address, TOKEN_PROGRAM_ID, data.clone(), |account| {
+                    validate_ata_with_bump(account, &WALLET, &MINT, bump)
+                }),
+                Err(ProgramError::InvalidSeeds)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_ata_uninitialized() {
+        let (address, _) = find_associated_token_address(&WALLET, &MINT, &TOKEN_PROGRAM_ID);
+        let mut data = token_account_data(&MINT, &WALLET);
+        data[108] = 0;
+
+        assert_eq!(
+            validate(address, TOKEN_PROGRAM_ID, data, |account| {
+                validate_ata(account, &WALLET, &MINT)
+            }),
+            Err(ProgramError::UninitializedAccount)
+        );
+    }
+}
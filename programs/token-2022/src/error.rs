@@ -0,0 +1,336 @@
+use pinocchio::program_error::{ProgramError, ToStr};
+
+/// Errors that may be returned by the Token-2022 program.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenError {
+    // 0
+    /// Lamport balance below rent-exempt threshold.
+    NotRentExempt,
+    /// Insufficient funds for the operation requested.
+    InsufficientFunds,
+    /// Invalid Mint.
+    InvalidMint,
+    /// Account not associated with this Mint.
+    MintMismatch,
+    /// Owner does not match.
+    OwnerMismatch,
+    // 5
+    /// This token's supply is fixed and new tokens cannot be minted.
+    FixedSupply,
+    /// The account cannot be initialized because it is already being used.
+    AlreadyInUse,
+    /// Invalid number of provided signers.
+    InvalidNumberOfProvidedSigners,
+    /// Invalid number of required signers.
+    InvalidNumberOfRequiredSigners,
+    /// State is uninitialized.
+    UninitializedState,
+    // 10
+    /// Instruction does not support native tokens
+    NativeNotSupported,
+    /// Non-native account can only be closed if its balance is zero
+    NonNativeHasBalance,
+    /// Invalid instruction
+    InvalidInstruction,
+    /// State is invalid for requested operation.
+    InvalidState,
+    /// Operation overflowed
+    Overflow,
+    // 15
+    /// Account does not support specified authority type.
+    AuthorityTypeNotSupported,
+    /// This token mint cannot freeze accounts.
+    MintCannotFreeze,
+    /// Account is frozen; all account operations will fail
+    AccountFrozen,
+    /// Mint decimals mismatch between the client and mint
+    MintDecimalsMismatch,
+    /// Instruction does not support non-native tokens
+    NonNativeNotSupported,
+    // 20
+    /// Extension type does not match already existing extensions
+    ExtensionTypeMismatch,
+    /// Extension does not match the base type provided
+    ExtensionBaseMismatch,
+    /// Extension already initialized on this account
+    ExtensionAlreadyInitialized,
+    /// An account can only be closed if its confidential balance is zero
+    ConfidentialTransferAccountHasBalance,
+    /// Account not approved for confidential transfers
+    ConfidentialTransferAccountNotApproved,
+    // 25
+    /// Account not accepting deposits or transfers
+    ConfidentialTransferDepositsAndTransfersDisabled,
+    /// ElGamal public key mismatch
+    ConfidentialTransferElGamalPubkeyMismatch,
+    /// Balance mismatch
+    ConfidentialTransferBalanceMismatch,
+    /// Mint has non-zero supply. Burn all tokens before closing the mint.
+    MintHasSupply,
+    /// No authority exists to perform the desired operation
+    NoAuthorityExists,
+    // 30
+    /// Transfer fee exceeds maximum of 10,000 basis points
+    TransferFeeExceedsMaximum,
+    /// Mint required for this account to transfer tokens, use
+    /// `transfer_checked` or `transfer_checked_with_fee`
+    MintRequiredForTransfer,
+    /// Calculated fee does not match expected fee
+    FeeMismatch,
+    /// Fee parameters associated with confidential transfer zero-knowledge
+    /// proofs do not match fee parameters in mint
+    FeeParametersMismatch,
+    /// The owner authority cannot be changed
+    ImmutableOwner,
+    // 35
+    /// An account can only be closed if its withheld fee balance is zero,
+    /// harvest fees to the mint and try again
+    AccountHasWithheldTransferFees,
+    /// No memo in previous instruction; required for recipient to receive a
+    /// transfer
+    NoMemo,
+    /// Transfer is disabled for this mint
+    NonTransferable,
+    /// Non-transferable tokens can't be minted to an account without immutable
+    /// ownership
+    NonTransferableNeedsImmutableOwnership,
+    /// The total number of `Deposit` and `Transfer` instructions to an account
+    /// cannot exceed the associated
+    /// `maximum_pending_balance_credit_counter`
+    MaximumPendingBalanceCreditCounterExceeded,
+    // 40
+    /// The deposit amount for the confidential extension exceeds the maximum
+    /// limit
+    MaximumDepositAmountExceeded,
+    /// CPI Guard cannot be enabled or disabled in CPI
+    CpiGuardSettingsLocked,
+    /// CPI Guard is enabled, and a program attempted to transfer user funds
+    /// without using a delegate
+    CpiGuardTransferBlocked,
+    /// CPI Guard is enabled, and a program attempted to burn user funds without
+    /// using a delegate
+    CpiGuardBurnBlocked,
+    /// CPI Guard is enabled, and a program attempted to close an account
+    /// without returning lamports to owner
+    CpiGuardCloseAccountBlocked,
+    // 45
+    /// CPI Guard is enabled, and a program attempted to approve a delegate
+    CpiGuardApproveBlocked,
+    /// CPI Guard is enabled, and a program attempted to add or replace an
+    /// authority
+    CpiGuardSetAuthorityBlocked,
+    /// Account ownership cannot be changed while CPI Guard is enabled
+    CpiGuardOwnerChangeBlocked,
+    /// Extension not found in account data
+    ExtensionNotFound,
+    /// Account does not accept non-confidential transfers
+    NonConfidentialTransfersDisabled,
+    // 50
+    /// An account can only be closed if the confidential withheld fee is zero
+    ConfidentialTransferFeeAccountHasWithheldFee,
+    /// A mint or an account is initialized to an invalid combination of
+    /// extensions
+    InvalidExtensionCombination,
+    /// Extension allocation with overwrite must use the same length
+    InvalidLengthForAlloc,
+    /// Failed to decrypt a confidential transfer account
+    AccountDecryption,
+    /// Failed to generate a zero-knowledge proof needed for a token instruction
+    ProofGeneration,
+    // 55
+    /// An invalid proof instruction offset was provided
+    InvalidProofInstructionOffset,
+    /// Harvest of withheld tokens to mint is disabled
+    HarvestToMintDisabled,
+    /// Split proof context state accounts not supported for instruction
+    SplitProofContextStateAccountsNotSupported,
+    /// Not enough proof context state accounts provided
+    NotEnoughProofContextStateAccounts,
+    /// Ciphertext is malformed
+    MalformedCiphertext,
+    // 60
+    /// Ciphertext arithmetic failed
+    CiphertextArithmeticFailed,
+    /// Pedersen commitments did not match
+    PedersenCommitmentMismatch,
+    /// Range proof length did not match
+    RangeProofLengthMismatch,
+    /// Illegal transfer amount bit length
+    IllegalBitLength,
+    /// Fee calculation failed
+    FeeCalculation,
+    // 65
+    /// Withdraw / Deposit not allowed for confidential-mint-burn
+    IllegalMintBurnConversion,
+    /// Invalid scale for scaled ui amount
+    InvalidScale,
+    /// Transferring, minting, and burning is paused on this mint
+    MintPaused,
+    /// Pending supply is not zero
+    PendingBalanceNonZero,
+}
+
+impl From<TokenError> for ProgramError {
+    fn from(e: TokenError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+impl TryFrom<u32> for TokenError {
+    type Error = ProgramError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TokenError::NotRentExempt),
+            1 => Ok(TokenError::InsufficientFunds),
+            2 => Ok(TokenError::InvalidMint),
+            3 => Ok(TokenError::MintMismatch),
+            4 => Ok(TokenError::OwnerMismatch),
+            5 => Ok(TokenError::FixedSupply),
+            6 => Ok(TokenError::AlreadyInUse),
+            7 => Ok(TokenError::InvalidNumberOfProvidedSigners),
+            8 => Ok(TokenError::InvalidNumberOfRequiredSigners),
+            9 => Ok(TokenError::UninitializedState),
+            10 => Ok(TokenError::NativeNotSupported),
+            11 => Ok(TokenError::NonNativeHasBalance),
+            12 => Ok(TokenError::InvalidInstruction),
+            13 => Ok(TokenError::InvalidState),
+            14 => Ok(TokenError::Overflow),
+            15 => Ok(TokenError::AuthorityTypeNotSupported),
+            16 => Ok(TokenError::MintCannotFreeze),
+            17 => Ok(TokenError::AccountFrozen),
+            18 => Ok(TokenError::MintDecimalsMismatch),
+            19 => Ok(TokenError::NonNativeNotSupported),
+            20 => Ok(TokenError::ExtensionTypeMismatch),
+            21 => Ok(TokenError::ExtensionBaseMismatch),
+            22 => Ok(TokenError::ExtensionAlreadyInitialized),
+            23 => Ok(TokenError::ConfidentialTransferAccountHasBalance),
+            24 => Ok(TokenError::ConfidentialTransferAccountNotApproved),
+            25 => Ok(TokenError::ConfidentialTransferDepositsAndTransfersDisabled),
+            26 => Ok(TokenError::ConfidentialTransferElGamalPubkeyMismatch),
+            27 => Ok(TokenError::ConfidentialTransferBalanceMismatch),
+            28 => Ok(TokenError::MintHasSupply),
+            29 => Ok(TokenError::NoAuthorityExists),
+            30 => Ok(TokenError::TransferFeeExceedsMaximum),
+            31 => Ok(TokenError::MintRequiredForTransfer),
+            32 => Ok(TokenError::FeeMismatch),
+            33 => Ok(TokenError::FeeParametersMismatch),
+            34 => Ok(TokenError::ImmutableOwner),
+            35 => Ok(TokenError::AccountHasWithheldTransferFees),
+            36 => Ok(TokenError::NoMemo),
+            37 => Ok(TokenError::NonTransferable),
+            38 => Ok(TokenError::NonTransferableNeedsImmutableOwnership),
+            39 => Ok(TokenError::MaximumPendingBalanceCreditCounterExceeded),
+            40 => Ok(TokenError::MaximumDepositAmountExceeded),
+            41 => Ok(TokenError::CpiGuardSettingsLocked),
+            42 => Ok(TokenError::CpiGuardTransferBlocked),
+            43 => Ok(TokenError::CpiGuardBurnBlocked),
+            44 => Ok(TokenError::CpiGuardCloseAccountBlocked),
+            45 => Ok(TokenError::CpiGuardApproveBlocked),
+            46 => Ok(TokenError::CpiGuardSetAuthorityBlocked),
+            47 => Ok(TokenError::CpiGuardOwnerChangeBlocked),
+            48 => Ok(TokenError::ExtensionNotFound),
+            49 => Ok(TokenError::NonConfidentialTransfersDisabled),
+            50 => Ok(TokenError::ConfidentialTransferFeeAccountHasWithheldFee),
+            51 => Ok(TokenError::InvalidExtensionCombination),
+            52 => Ok(TokenError::InvalidLengthForAlloc),
+            53 => Ok(TokenError::AccountDecryption),
+            54 => Ok(TokenError::ProofGeneration),
+            55 => Ok(TokenError::InvalidProofInstructionOffset),
+            56 => Ok(TokenError::HarvestToMintDisabled),
+            57 => Ok(TokenError::SplitProofContextStateAccountsNotSupported),
+            58 => Ok(TokenError::NotEnoughProofContextStateAccounts),
+            59 => Ok(TokenError::MalformedCiphertext),
+            60 => Ok(TokenError::CiphertextArithmeticFailed),
+            61 => Ok(TokenError::PedersenCommitmentMismatch),
+            62 => Ok(TokenError::RangeProofLengthMismatch),
+            63 => Ok(TokenError::IllegalBitLength),
+            64 => Ok(TokenError::FeeCalculation),
+            65 => Ok(TokenError::IllegalMintBurnConversion),
+            66 => Ok(TokenError::InvalidScale),
+            67 => Ok(TokenError::MintPaused),
+            68 => Ok(TokenError::PendingBalanceNonZero),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+impl ToStr for TokenError {
+    fn to_str<E>(&self) -> &'static str
+    where
+        E: 'static + ToStr + TryFrom<u32>,
+    {
+        match self {
+            TokenError::NotRentExempt => "Lamport balance below rent-exempt threshold",
+            TokenError::InsufficientFunds => "Insufficient funds",
+            TokenError::InvalidMint => "Invalid Mint",
+            TokenError::MintMismatch => "Account not associated with this Mint",
+            TokenError::OwnerMismatch => "Owner does not match",
+            TokenError::FixedSupply => "Fixed supply",
+            TokenError::AlreadyInUse => "Already in use",
+            TokenError::InvalidNumberOfProvidedSigners => "Invalid number of provided signers",
+            TokenError::InvalidNumberOfRequiredSigners => "Invalid number of required signers",
+            TokenError::UninitializedState => "State is uninitialized",
+            TokenError::NativeNotSupported => "Instruction does not support native tokens",
+            TokenError::NonNativeHasBalance => "Non-native account can only be closed if its balance is zero",
+            TokenError::InvalidInstruction => "Invalid instruction",
+            TokenError::InvalidState => "State is invalid for requested operation",
+            TokenError::Overflow => "Operation overflowed",
+            TokenError::AuthorityTypeNotSupported => "Account does not support specified authority type",
+            TokenError::MintCannotFreeze => "This token mint cannot freeze accounts",
+            TokenError::AccountFrozen => "Account is frozen",
+            TokenError::MintDecimalsMismatch => "The provided decimals value different from the Mint decimals",
+            TokenError::NonNativeNotSupported => "Instruction does not support non-native tokens",
+            TokenError::ExtensionTypeMismatch => "Extension type does not match already existing extensions",
+            TokenError::ExtensionBaseMismatch => "Extension does not match the base type provided",
+            TokenError::ExtensionAlreadyInitialized => "Extension already initialized on this account",
+            TokenError::ConfidentialTransferAccountHasBalance => "An account can only be closed if its confidential balance is zero",
+            TokenError::ConfidentialTransferAccountNotApproved => "Account not approved for confidential transfers",
+            TokenError::ConfidentialTransferDepositsAndTransfersDisabled => "Account not accepting deposits or transfers",
+            TokenError::ConfidentialTransferElGamalPubkeyMismatch => "ElGamal public key mismatch",
+            TokenError::ConfidentialTransferBalanceMismatch => "Balance mismatch",
+            TokenError::MintHasSupply => "Mint has non-zero supply. Burn all tokens before closing the mint",
+            TokenError::NoAuthorityExists => "No authority exists to perform the desired operation",
+            TokenError::TransferFeeExceedsMaximum => "Transfer fee exceeds maximum of 10,000 basis points",
+            TokenError::MintRequiredForTransfer => "Mint required for this account to transfer tokens, use `transfer_checked` or `transfer_checked_with_fee`",
+            TokenError::FeeMismatch => "Calculated fee does not match expected fee",
+            TokenError::FeeParametersMismatch => "Fee parameters associated with zero-knowledge proofs do not match fee parameters in mint",
+            TokenError::ImmutableOwner => "The owner authority cannot be changed",
+            TokenError::AccountHasWithheldTransferFees => "An account can only be closed if its withheld fee balance is zero, harvest fees to the mint and try again",
+            TokenError::NoMemo => "No memo in previous instruction; required for recipient to receive a transfer",
+            TokenError::NonTransferable => "Transfer is disabled for this mint",
+            TokenError::NonTransferableNeedsImmutableOwnership => "Non-transferable tokens can't be minted to an account without immutable ownership",
+            TokenError::MaximumPendingBalanceCreditCounterExceeded => "The total number of `Deposit` and `Transfer` instructions to an account cannot exceed the associated `maximum_pending_balance_credit_counter`",
+            TokenError::MaximumDepositAmountExceeded => "Deposit amount exceeds maximum limit",
+            TokenError::CpiGuardSettingsLocked => "CPI Guard cannot be enabled or disabled in CPI",
+            TokenError::CpiGuardTransferBlocked => "CPI Guard is enabled, and a program attempted to transfer user funds via CPI without using a delegate",
+            TokenError::CpiGuardBurnBlocked => "CPI Guard is enabled, and a program attempted to burn user funds via CPI without using a delegate",
+            TokenError::CpiGuardCloseAccountBlocked => "CPI Guard is enabled, and a program attempted to close an account via CPI without returning lamports to owner",
+            TokenError::CpiGuardApproveBlocked => "CPI Guard is enabled, and a program attempted to approve a delegate via CPI",
+            TokenError::CpiGuardSetAuthorityBlocked => "CPI Guard is enabled, and a program attempted to add or replace an authority via CPI",
+            TokenError::CpiGuardOwnerChangeBlocked => "Account ownership cannot be changed while CPI Guard is enabled",
+            TokenError::ExtensionNotFound => "Extension not found in account data",
+            TokenError::NonConfidentialTransfersDisabled => "Non-confidential transfers disabled",
+            TokenError::ConfidentialTransferFeeAccountHasWithheldFee => "An account can only be closed if the confidential withheld fee is zero",
+            TokenError::InvalidExtensionCombination => "A mint or an account is initialized to an invalid combination of extensions",
+            TokenError::InvalidLengthForAlloc => "Extension allocation with overwrite must use the same length",
+            TokenError::AccountDecryption => "Failed to decrypt a confidential transfer account",
+            TokenError::ProofGeneration => "Failed to generate proof",
+            TokenError::InvalidProofInstructionOffset => "An invalid proof instruction offset was provided",
+            TokenError::HarvestToMintDisabled => "Harvest of withheld tokens to mint is disabled",
+            TokenError::SplitProofContextStateAccountsNotSupported => "Split proof context state accounts not supported for instruction",
+            TokenError::NotEnoughProofContextStateAccounts => "Not enough proof context state accounts provided",
+            TokenError::MalformedCiphertext => "Ciphertext is malformed",
+            TokenError::CiphertextArithmeticFailed => "Ciphertext arithmetic failed",
+            TokenError::PedersenCommitmentMismatch => "Pedersen commitment mismatch",
+            TokenError::RangeProofLengthMismatch => "Range proof length mismatch",
+            TokenError::IllegalBitLength => "Illegal transfer amount bit length",
+            TokenError::FeeCalculation => "Fee calculation failed",
+            TokenError::IllegalMintBurnConversion => "Withdraw / Deposit not allowed for confidential-mint-burn",
+            TokenError::InvalidScale => "Invalid scale for scaled ui amount",
+            TokenError::MintPaused => "Transferring, minting, and burning is paused on this mint",
+            TokenError::PendingBalanceNonZero => "Key rotation attempted while pending balance is not zero",
+        }
+    }
+}
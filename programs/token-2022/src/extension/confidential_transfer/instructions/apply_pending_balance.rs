@@ -0,0 +1,91 @@
+use {
+    crate::{
+        extension::{
+            confidential_transfer::{
+                instructions::invoke_with_authority,
+                state::{ConfidentialTransferInstruction, DecryptableBalance},
+            },
+            consts::ExtensionDiscriminator,
+        },
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Applies the pending balance to the available balance, based on the history
+/// of `Deposit` and/or `Transfer` instructions.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[signer]` The single account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The multisig account owner.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct ApplyPendingBalance<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// The expected number of pending balance credits since the last successful
+    /// `ApplyPendingBalance` instruction
+    pub expected_pending_balance_credit_counter: u64,
+    /// The new decryptable balance if the pending balance is applied
+    /// successfully
+    pub new_decryptable_available_balance: &'a DecryptableBalance,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl ApplyPendingBalance<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2..10]: expected_pending_balance_credit_counter (8 bytes, u64)
+        // -  [10..46]: new_decryptable_available_balance (36 bytes, AeCiphertext)
+        let mut instruction_data = [UNINIT_BYTE; 46];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::ConfidentialTransfer as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[ConfidentialTransferInstruction::ApplyPendingBalance as u8],
+        );
+        // Set expected_pending_balance_credit_counter as u64 at offset [2..10]
+        write_bytes(
+            &mut instruction_data[2..10],
+            &self.expected_pending_balance_credit_counter.to_le_bytes(),
+        );
+        // Set new_decryptable_available_balance at offset [10..46]
+        write_bytes(
+            &mut instruction_data[10..46],
+            self.new_decryptable_available_balance,
+        );
+
+        invoke_with_authority(
+            &[(self.token_account, true)],
+            self.authority,
+            self.signers,
+            unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 46) },
+            self.token_program,
+            signers,
+        )
+    }
+}
@@ -0,0 +1,65 @@
+use {
+    crate::extension::{
+        confidential_transfer::{
+            instructions::invoke_with_authority, state::ConfidentialTransferInstruction,
+        },
+        consts::ExtensionDiscriminator,
+    },
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Approves a token account for confidential transfers.
+///
+/// Approval is only required when the mint does not auto-approve new accounts.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The token account to approve.
+///   1. `[]` The token mint.
+///   2. `[signer]` The confidential transfer authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` The token account to approve.
+///   1. `[]` The token mint.
+///   2. `[]` The multisig confidential transfer authority.
+///   3. `..3+M` `[signer]` M signer accounts.
+pub struct ApproveConfidentialTransferAccount<'a> {
+    /// Token Account to approve
+    pub token_account: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Confidential Transfer Authority Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl ApproveConfidentialTransferAccount<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        let instruction_data = [
+            ExtensionDiscriminator::ConfidentialTransfer as u8,
+            ConfidentialTransferInstruction::ApproveAccount as u8,
+        ];
+
+        invoke_with_authority(
+            &[(self.token_account, true), (self.mint, false)],
+            self.authority,
+            self.signers,
+            &instruction_data,
+            self.token_program,
+            signers,
+        )
+    }
+}
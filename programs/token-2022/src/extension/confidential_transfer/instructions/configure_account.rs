@@ -0,0 +1,110 @@
+use {
+    crate::{
+        extension::{
+            confidential_transfer::{
+                instructions::{invoke_with_authority, ProofLocation},
+                state::{ConfidentialTransferInstruction, DecryptableBalance},
+            },
+            consts::ExtensionDiscriminator,
+        },
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Configures confidential transfers for a token account.
+///
+/// The ElGamal public key of the account is validated against a
+/// `VerifyPubkeyValidity` proof, either included in the same transaction or
+/// pre-verified into a context state account.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The token mint.
+///   2. `[]` The instructions sysvar or the proof context state account.
+///   3. `[signer]` The single account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The token mint.
+///   2. `[]` The instructions sysvar or the proof context state account.
+///   3. `[]` The multisig account owner.
+///   4. `..4+M` `[signer]` M signer accounts.
+pub struct ConfigureConfidentialTransferAccount<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Location of the `VerifyPubkeyValidity` proof
+    pub proof: ProofLocation<'a>,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// The decryptable balance (always 0) once the configure account succeeds
+    pub decryptable_zero_balance: &'a DecryptableBalance,
+    /// The maximum number of deposits and transfers that an account can
+    /// receive before the `ApplyPendingBalance` is executed
+    pub maximum_pending_balance_credit_counter: u64,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl ConfigureConfidentialTransferAccount<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let (proof_account, proof_instruction_offset) = self.proof.account_and_offset();
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2..38]: decryptable_zero_balance (36 bytes, AeCiphertext)
+        // -  [38..46]: maximum_pending_balance_credit_counter (8 bytes, u64)
+        // -  [46]: proof_instruction_offset (1 byte, i8)
+        let mut instruction_data = [UNINIT_BYTE; 47];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::ConfidentialTransfer as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[ConfidentialTransferInstruction::ConfigureAccount as u8],
+        );
+        // Set decryptable_zero_balance at offset [2..38]
+        write_bytes(&mut instruction_data[2..38], self.decryptable_zero_balance);
+        // Set maximum_pending_balance_credit_counter as u64 at offset [38..46]
+        write_bytes(
+            &mut instruction_data[38..46],
+            &self.maximum_pending_balance_credit_counter.to_le_bytes(),
+        );
+        // Set proof_instruction_offset as i8 at offset [46]
+        write_bytes(
+            &mut instruction_data[46..47],
+            &proof_instruction_offset.to_le_bytes(),
+        );
+
+        invoke_with_authority(
+            &[
+                (self.token_account, true),
+                (self.mint, false),
+                (proof_account, false),
+            ],
+            self.authority,
+            self.signers,
+            unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 47) },
+            self.token_program,
+            signers,
+        )
+    }
+}
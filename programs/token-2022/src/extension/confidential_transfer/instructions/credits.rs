@@ -0,0 +1,208 @@
+use {
+    crate::extension::{
+        confidential_transfer::{
+            instructions::invoke_with_authority, state::ConfidentialTransferInstruction,
+        },
+        consts::ExtensionDiscriminator,
+    },
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Configure a token account to accept incoming confidential transfers.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[signer]` The single account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The multisig account owner.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct EnableConfidentialCredits<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl EnableConfidentialCredits<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_credits_instruction(
+            ConfidentialTransferInstruction::EnableConfidentialCredits,
+            self.token_account,
+            self.authority,
+            self.signers,
+            self.token_program,
+            signers,
+        )
+    }
+}
+
+/// Configure a token account to reject any incoming confidential transfers.
+///
+/// Confidential transfers already in the pending balance can still be applied.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[signer]` The single account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The multisig account owner.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct DisableConfidentialCredits<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl DisableConfidentialCredits<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_credits_instruction(
+            ConfidentialTransferInstruction::DisableConfidentialCredits,
+            self.token_account,
+            self.authority,
+            self.signers,
+            self.token_program,
+            signers,
+        )
+    }
+}
+
+/// Configure a token account to accept incoming non-confidential transfers.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[signer]` The single account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The multisig account owner.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct EnableNonConfidentialCredits<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl EnableNonConfidentialCredits<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_credits_instruction(
+            ConfidentialTransferInstruction::EnableNonConfidentialCredits,
+            self.token_account,
+            self.authority,
+            self.signers,
+            self.token_program,
+            signers,
+        )
+    }
+}
+
+/// Configure a token account to reject any incoming non-confidential transfers.
+///
+/// The account can still receive confidential transfers.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[signer]` The single account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The multisig account owner.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct DisableNonConfidentialCredits<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl DisableNonConfidentialCredits<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_credits_instruction(
+            ConfidentialTransferInstruction::DisableNonConfidentialCredits,
+            self.token_account,
+            self.authority,
+            self.signers,
+            self.token_program,
+            signers,
+        )
+    }
+}
+
+#[inline(always)]
+fn invoke_credits_instruction(
+    instruction_type: ConfidentialTransferInstruction,
+    token_account: &AccountInfo,
+    authority: &AccountInfo,
+    multisig_signers: &[AccountInfo],
+    token_program: &Pubkey,
+    signers: &[Signer],
+) -> ProgramResult {
+    // Instruction data layout:
+    // -  [0]: extension discriminator (1 byte, u8)
+    // -  [1]: instruction_type (1 byte, u8)
+    let instruction_data = [
+        ExtensionDiscriminator::ConfidentialTransfer as u8,
+        instruction_type as u8,
+    ];
+
+    invoke_with_authority(
+        &[(token_account, true)],
+        authority,
+        multisig_signers,
+        &instruction_data,
+        token_program,
+        signers,
+    )
+}
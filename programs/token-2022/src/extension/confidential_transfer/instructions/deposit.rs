@@ -0,0 +1,87 @@
+use {
+    crate::{
+        extension::{
+            confidential_transfer::{
+                instructions::invoke_with_authority, state::ConfidentialTransferInstruction,
+            },
+            consts::ExtensionDiscriminator,
+        },
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Deposit tokens into the pending balance of a confidential token account.
+///
+/// The deposit is moved to the available balance by `ApplyPendingBalance`.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The token mint.
+///   2. `[signer]` The single account owner or delegate.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The token mint.
+///   2. `[]` The multisig account owner or delegate.
+///   3. `..3+M` `[signer]` M signer accounts.
+pub struct ConfidentialDeposit<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Owner or Delegate Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Amount of micro-tokens to deposit.
+    pub amount: u64,
+    /// Decimal for the Token
+    pub decimals: u8,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl ConfidentialDeposit<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2..10]: amount (8 bytes, u64)
+        // -  [10]: decimals (1 byte, u8)
+        let mut instruction_data = [UNINIT_BYTE; 11];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::ConfidentialTransfer as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[ConfidentialTransferInstruction::Deposit as u8],
+        );
+        // Set amount as u64 at offset [2..10]
+        write_bytes(&mut instruction_data[2..10], &self.amount.to_le_bytes());
+        // Set decimals as u8 at offset [10]
+        write_bytes(&mut instruction_data[10..11], &[self.decimals]);
+
+        invoke_with_authority(
+            &[(self.token_account, true), (self.mint, false)],
+            self.authority,
+            self.signers,
+            unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 11) },
+            self.token_program,
+            signers,
+        )
+    }
+}
@@ -0,0 +1,72 @@
+use {
+    crate::extension::{
+        confidential_transfer::{
+            instructions::invoke_with_authority, state::ConfidentialTransferInstruction,
+        },
+        consts::ExtensionDiscriminator,
+    },
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Empty the available balance of a confidential token account, so that it
+/// can be closed.
+///
+/// The zero balance is validated against a `VerifyZeroCiphertext` proof
+/// pre-verified into a context state account.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The proof context state account.
+///   2. `[signer]` The single account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The proof context state account.
+///   2. `[]` The multisig account owner.
+///   3. `..3+M` `[signer]` M signer accounts.
+pub struct EmptyConfidentialTransferAccount<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Context state account of the `VerifyZeroCiphertext` proof
+    pub proof_context_state_account: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl EmptyConfidentialTransferAccount<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2]: proof_instruction_offset (1 byte, i8), 0 for a context state account
+        let instruction_data = [
+            ExtensionDiscriminator::ConfidentialTransfer as u8,
+            ConfidentialTransferInstruction::EmptyAccount as u8,
+            0,
+        ];
+
+        invoke_with_authority(
+            &[
+                (self.token_account, true),
+                (self.proof_context_state_account, false),
+            ],
+            self.authority,
+            self.signers,
+            &instruction_data,
+            self.token_program,
+            signers,
+        )
+    }
+}
@@ -0,0 +1,93 @@
+use {
+    crate::{
+        extension::{
+            confidential_transfer::state::{ConfidentialTransferInstruction, ElGamalPubkey},
+            consts::ExtensionDiscriminator,
+        },
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Initializes confidential transfers for a mint.
+///
+/// Fails if the mint has already been initialized, so must be called before
+/// `InitializeMint`.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint to initialize.
+pub struct InitializeConfidentialTransferMint<'a> {
+    /// The mint to initialize
+    pub mint: &'a AccountInfo,
+    /// Authority to modify the confidential transfer configuration and to
+    /// approve new accounts
+    pub authority: Option<&'a Pubkey>,
+    /// Whether newly configured accounts are approved without the authority
+    pub auto_approve_new_accounts: bool,
+    /// Authority to decode any transfer amount in a confidential transfer
+    pub auditor_elgamal_pubkey: Option<&'a ElGamalPubkey>,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl InitializeConfidentialTransferMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [AccountMeta::writable(self.mint.key())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2..34]: authority (32 bytes, Pubkey)
+        // -  [34]: auto_approve_new_accounts (1 byte, bool)
+        // -  [35..67]: auditor_elgamal_pubkey (32 bytes, ElGamalPubkey)
+        let mut instruction_data = [UNINIT_BYTE; 67];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::ConfidentialTransfer as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[ConfidentialTransferInstruction::InitializeMint as u8],
+        );
+        // Set authority at offset [2..34]
+        write_bytes(
+            &mut instruction_data[2..34],
+            self.authority.unwrap_or(&Pubkey::default()),
+        );
+        // Set auto_approve_new_accounts at offset [34]
+        write_bytes(
+            &mut instruction_data[34..35],
+            &[self.auto_approve_new_accounts as u8],
+        );
+        // Set auditor_elgamal_pubkey at offset [35..67]
+        write_bytes(
+            &mut instruction_data[35..67],
+            self.auditor_elgamal_pubkey.unwrap_or(&[0; 32]),
+        );
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 67) },
+        };
+
+        invoke_signed(&instruction, &[self.mint], signers)
+    }
+}
@@ -0,0 +1,128 @@
+pub mod apply_pending_balance;
+pub mod approve_account;
+pub mod configure_account;
+pub mod credits;
+pub mod deposit;
+pub mod empty_account;
+pub mod initialize_mint;
+pub mod transfer;
+pub mod update_mint;
+pub mod withdraw;
+
+pub use apply_pending_balance::*;
+pub use approve_account::*;
+pub use configure_account::*;
+pub use credits::*;
+pub use deposit::*;
+pub use empty_account::*;
+pub use initialize_mint::*;
+pub use transfer::*;
+pub use update_mint::*;
+pub use withdraw::*;
+
+use {
+    crate::instructions::MAX_MULTISIG_SIGNERS,
+    core::{iter, mem::MaybeUninit, num::NonZeroI8, slice},
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed_with_bounds,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Location of the zero-knowledge proof an instruction is verified against.
+#[derive(Clone, Copy)]
+pub enum ProofLocation<'a> {
+    /// The proof instruction is included in the same transaction, at the given
+    /// offset from the instruction being processed.
+    InstructionOffset {
+        /// Offset of the proof instruction
+        offset: NonZeroI8,
+        /// Instructions sysvar account
+        instructions_sysvar: &'a AccountInfo,
+    },
+    /// The proof was pre-verified into a context state account.
+    ContextStateAccount(&'a AccountInfo),
+}
+
+impl<'a> ProofLocation<'a> {
+    /// Return the account passed for the proof and the proof instruction offset
+    /// written in the instruction data.
+    #[inline(always)]
+    pub(crate) fn account_and_offset(&self) -> (&'a AccountInfo, i8) {
+        match *self {
+            ProofLocation::InstructionOffset {
+                offset,
+                instructions_sysvar,
+            } => (instructions_sysvar, offset.get()),
+            ProofLocation::ContextStateAccount(context_state_account) => (context_state_account, 0),
+        }
+    }
+}
+
+/// Maximum number of accounts preceding the authority in a confidential transfer
+/// instruction.
+const MAX_BASE_ACCOUNTS: usize = 6;
+
+/// Maximum number of accounts of a confidential transfer instruction signed by an
+/// authority.
+const MAX_ACCOUNTS: usize = MAX_BASE_ACCOUNTS + 1 + MAX_MULTISIG_SIGNERS;
+
+/// Invoke a confidential transfer instruction whose `(account, is_writable)`
+/// accounts are followed by the authority and, if the authority is a multisig,
+/// its signer accounts.
+#[inline(always)]
+fn invoke_with_authority(
+    accounts: &[(&AccountInfo, bool)],
+    authority: &AccountInfo,
+    multisig_signers: &[AccountInfo],
+    instruction_data: &[u8],
+    token_program: &Pubkey,
+    signers: &[Signer],
+) -> ProgramResult {
+    if accounts.len() > MAX_BASE_ACCOUNTS || multisig_signers.len() > MAX_MULTISIG_SIGNERS {
+        Err(ProgramError::InvalidArgument)?;
+    }
+
+    // The authority only signs when it is not a multisig
+    let all_accounts = accounts
+        .iter()
+        .map(|&(account, is_writable)| (account, is_writable, false))
+        .chain(iter::once((authority, false, multisig_signers.is_empty())))
+        .chain(multisig_signers.iter().map(|signer| (signer, false, true)));
+
+    const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+    let mut acc_metas = [UNINIT_META; MAX_ACCOUNTS];
+
+    const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+    let mut acc_infos = [UNINIT_INFO; MAX_ACCOUNTS];
+
+    let mut num_accounts = 0;
+
+    for ((account_meta, account_info), (account, is_writable, is_signer)) in acc_metas
+        .iter_mut()
+        .zip(acc_infos.iter_mut())
+        .zip(all_accounts)
+    {
+        account_meta.write(AccountMeta::new(account.key(), is_writable, is_signer));
+        account_info.write(account);
+        num_accounts += 1;
+    }
+
+    let instruction = Instruction {
+        program_id: token_program,
+        // SAFETY: the first `num_accounts` metas were written above
+        accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+        data: instruction_data,
+    };
+
+    invoke_signed_with_bounds::<MAX_ACCOUNTS>(
+        &instruction,
+        // SAFETY: the first `num_accounts` infos were written above
+        unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+        signers,
+    )
+}
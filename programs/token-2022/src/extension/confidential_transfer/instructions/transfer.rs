@@ -0,0 +1,137 @@
+use {
+    crate::{
+        extension::{
+            confidential_transfer::{
+                instructions::invoke_with_authority,
+                state::{ConfidentialTransferInstruction, DecryptableBalance, ElGamalCiphertext},
+            },
+            consts::ExtensionDiscriminator,
+        },
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Transfer tokens confidentially.
+///
+/// The transfer is validated against the `VerifyCiphertextCommitmentEquality`,
+/// `VerifyBatchedGroupedCiphertext3HandlesValidity` and
+/// `VerifyBatchedRangeProofU128` proofs pre-verified into context state
+/// accounts.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The source token account.
+///   1. `[]` The token mint.
+///   2. `[writable]` The destination token account.
+///   3. `[]` The equality proof context state account.
+///   4. `[]` The ciphertext validity proof context state account.
+///   5. `[]` The range proof context state account.
+///   6. `[signer]` The single source account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The source token account.
+///   1. `[]` The token mint.
+///   2. `[writable]` The destination token account.
+///   3. `[]` The equality proof context state account.
+///   4. `[]` The ciphertext validity proof context state account.
+///   5. `[]` The range proof context state account.
+///   6. `[]` The multisig source account owner.
+///   7. `..7+M` `[signer]` M signer accounts.
+pub struct ConfidentialTransfer<'a> {
+    /// Sender account.
+    pub from: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Recipient account.
+    pub to: &'a AccountInfo,
+    /// Context state account of the `VerifyCiphertextCommitmentEquality` proof
+    pub equality_proof_context_state_account: &'a AccountInfo,
+    /// Context state account of the `VerifyBatchedGroupedCiphertext3HandlesValidity`
+    /// proof
+    pub ciphertext_validity_proof_context_state_account: &'a AccountInfo,
+    /// Context state account of the `VerifyBatchedRangeProofU128` proof
+    pub range_proof_context_state_account: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// The new source decryptable balance if the transfer succeeds
+    pub new_source_decryptable_available_balance: &'a DecryptableBalance,
+    /// The transfer amount encrypted under the auditor ElGamal public key (low
+    /// 16 bits)
+    pub transfer_amount_auditor_ciphertext_lo: &'a ElGamalCiphertext,
+    /// The transfer amount encrypted under the auditor ElGamal public key (high
+    /// 32 bits)
+    pub transfer_amount_auditor_ciphertext_hi: &'a ElGamalCiphertext,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl ConfidentialTransfer<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2..38]: new_source_decryptable_available_balance (36 bytes, AeCiphertext)
+        // -  [38..102]: transfer_amount_auditor_ciphertext_lo (64 bytes, ElGamalCiphertext)
+        // -  [102..166]: transfer_amount_auditor_ciphertext_hi (64 bytes, ElGamalCiphertext)
+        // -  [166]: equality_proof_instruction_offset (1 byte, i8)
+        // -  [167]: ciphertext_validity_proof_instruction_offset (1 byte, i8)
+        // -  [168]: range_proof_instruction_offset (1 byte, i8)
+        let mut instruction_data = [UNINIT_BYTE; 169];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::ConfidentialTransfer as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[ConfidentialTransferInstruction::Transfer as u8],
+        );
+        // Set new_source_decryptable_available_balance at offset [2..38]
+        write_bytes(
+            &mut instruction_data[2..38],
+            self.new_source_decryptable_available_balance,
+        );
+        // Set transfer_amount_auditor_ciphertext_lo at offset [38..102]
+        write_bytes(
+            &mut instruction_data[38..102],
+            self.transfer_amount_auditor_ciphertext_lo,
+        );
+        // Set transfer_amount_auditor_ciphertext_hi at offset [102..166]
+        write_bytes(
+            &mut instruction_data[102..166],
+            self.transfer_amount_auditor_ciphertext_hi,
+        );
+        // Set the proof instruction offsets at offset [166..169], 0 for context
+        // state accounts
+        write_bytes(&mut instruction_data[166..169], &[0, 0, 0]);
+
+        invoke_with_authority(
+            &[
+                (self.from, true),
+                (self.mint, false),
+                (self.to, true),
+                (self.equality_proof_context_state_account, false),
+                (self.ciphertext_validity_proof_context_state_account, false),
+                (self.range_proof_context_state_account, false),
+            ],
+            self.authority,
+            self.signers,
+            unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 169) },
+            self.token_program,
+            signers,
+        )
+    }
+}
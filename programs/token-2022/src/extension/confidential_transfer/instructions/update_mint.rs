@@ -0,0 +1,88 @@
+use {
+    crate::{
+        extension::{
+            confidential_transfer::{
+                instructions::invoke_with_authority,
+                state::{ConfidentialTransferInstruction, ElGamalPubkey},
+            },
+            consts::ExtensionDiscriminator,
+        },
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Updates the confidential transfer configuration of a mint.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The mint.
+///   1. `[signer]` The confidential transfer authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` The mint.
+///   1. `[]` The multisig confidential transfer authority.
+///   2. `..2+M` `[signer]` M signer accounts.
+pub struct UpdateConfidentialTransferMint<'a> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Confidential Transfer Authority Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Whether newly configured accounts are approved without the authority
+    pub auto_approve_new_accounts: bool,
+    /// New authority to decode any transfer amount in a confidential transfer
+    pub auditor_elgamal_pubkey: Option<&'a ElGamalPubkey>,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl UpdateConfidentialTransferMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2]: auto_approve_new_accounts (1 byte, bool)
+        // -  [3..35]: auditor_elgamal_pubkey (32 bytes, ElGamalPubkey)
+        let mut instruction_data = [UNINIT_BYTE; 35];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::ConfidentialTransfer as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[ConfidentialTransferInstruction::UpdateMint as u8],
+        );
+        // Set auto_approve_new_accounts at offset [2]
+        write_bytes(
+            &mut instruction_data[2..3],
+            &[self.auto_approve_new_accounts as u8],
+        );
+        // Set auditor_elgamal_pubkey at offset [3..35]
+        write_bytes(
+            &mut instruction_data[3..35],
+            self.auditor_elgamal_pubkey.unwrap_or(&[0; 32]),
+        );
+
+        invoke_with_authority(
+            &[(self.mint, true)],
+            self.authority,
+            self.signers,
+            unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 35) },
+            self.token_program,
+            signers,
+        )
+    }
+}
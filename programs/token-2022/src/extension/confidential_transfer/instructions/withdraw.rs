@@ -0,0 +1,116 @@
+use {
+    crate::{
+        extension::{
+            confidential_transfer::{
+                instructions::invoke_with_authority,
+                state::{ConfidentialTransferInstruction, DecryptableBalance},
+            },
+            consts::ExtensionDiscriminator,
+        },
+        write_bytes, UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Withdraw tokens from the available balance of a confidential token account.
+///
+/// The withdrawal is validated against the `VerifyCiphertextCommitmentEquality`
+/// and `VerifyBatchedRangeProofU64` proofs pre-verified into context state
+/// accounts.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The token mint.
+///   2. `[]` The equality proof context state account.
+///   3. `[]` The range proof context state account.
+///   4. `[signer]` The single account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token account.
+///   1. `[]` The token mint.
+///   2. `[]` The equality proof context state account.
+///   3. `[]` The range proof context state account.
+///   4. `[]` The multisig account owner.
+///   5. `..5+M` `[signer]` M signer accounts.
+pub struct ConfidentialWithdraw<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Context state account of the `VerifyCiphertextCommitmentEquality` proof
+    pub equality_proof_context_state_account: &'a AccountInfo,
+    /// Context state account of the `VerifyBatchedRangeProofU64` proof
+    pub range_proof_context_state_account: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Amount of micro-tokens to withdraw.
+    pub amount: u64,
+    /// Decimal for the Token
+    pub decimals: u8,
+    /// The new decryptable balance if the withdrawal succeeds
+    pub new_decryptable_available_balance: &'a DecryptableBalance,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl ConfidentialWithdraw<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2..10]: amount (8 bytes, u64)
+        // -  [10]: decimals (1 byte, u8)
+        // -  [11..47]: new_decryptable_available_balance (36 bytes, AeCiphertext)
+        // -  [47]: equality_proof_instruction_offset (1 byte, i8)
+        // -  [48]: range_proof_instruction_offset (1 byte, i8)
+        let mut instruction_data = [UNINIT_BYTE; 49];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::ConfidentialTransfer as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[ConfidentialTransferInstruction::Withdraw as u8],
+        );
+        // Set amount as u64 at offset [2..10]
+        write_bytes(&mut instruction_data[2..10], &self.amount.to_le_bytes());
+        // Set decimals as u8 at offset [10]
+        write_bytes(&mut instruction_data[10..11], &[self.decimals]);
+        // Set new_decryptable_available_balance at offset [11..47]
+        write_bytes(
+            &mut instruction_data[11..47],
+            self.new_decryptable_available_balance,
+        );
+        // Set the proof instruction offsets at offset [47..49], 0 for context
+        // state accounts
+        write_bytes(&mut instruction_data[47..49], &[0, 0]);
+
+        invoke_with_authority(
+            &[
+                (self.token_account, true),
+                (self.mint, false),
+                (self.equality_proof_context_state_account, false),
+                (self.range_proof_context_state_account, false),
+            ],
+            self.authority,
+            self.signers,
+            unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 49) },
+            self.token_program,
+            signers,
+        )
+    }
+}
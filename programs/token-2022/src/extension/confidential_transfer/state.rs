@@ -0,0 +1,334 @@
+use {
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{
+            get_extension, get_extension_from_account_info,
+            get_extension_from_account_info_unchecked, Extension,
+        },
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Maximum bit length of any deposit or transfer amount
+///
+/// Any deposit or transfer amount must be less than `2^48`
+pub const MAXIMUM_DEPOSIT_TRANSFER_AMOUNT: u64 = (u16::MAX as u64) + (1 << 16) * (u32::MAX as u64);
+
+/// Bit length of the low bits of pending balance plaintext
+pub const PENDING_BALANCE_LO_BIT_LENGTH: u32 = 16;
+
+/// The default maximum pending balance credit counter.
+pub const DEFAULT_MAXIMUM_PENDING_BALANCE_CREDIT_COUNTER: u64 = 65536;
+
+/// Length of an ElGamal public key.
+pub const ELGAMAL_PUBKEY_LEN: usize = 32;
+
+/// Length of an ElGamal ciphertext.
+pub const ELGAMAL_CIPHERTEXT_LEN: usize = 64;
+
+/// Length of an authenticated encryption ciphertext.
+pub const AE_CIPHERTEXT_LEN: usize = 36;
+
+/// ElGamal public key, as raw bytes.
+pub type ElGamalPubkey = [u8; ELGAMAL_PUBKEY_LEN];
+
+/// ElGamal ciphertext, as raw bytes.
+pub type ElGamalCiphertext = [u8; ELGAMAL_CIPHERTEXT_LEN];
+
+/// Authenticated encryption ciphertext, as raw bytes.
+pub type AeCiphertext = [u8; AE_CIPHERTEXT_LEN];
+
+/// ElGamal ciphertext containing an account balance
+pub type EncryptedBalance = ElGamalCiphertext;
+
+/// Authenticated encryption containing an account balance
+pub type DecryptableBalance = AeCiphertext;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfidentialTransferInstruction {
+    InitializeMint = 0,
+    UpdateMint = 1,
+    ConfigureAccount = 2,
+    ApproveAccount = 3,
+    EmptyAccount = 4,
+    Deposit = 5,
+    Withdraw = 6,
+    Transfer = 7,
+    ApplyPendingBalance = 8,
+    EnableConfidentialCredits = 9,
+    DisableConfidentialCredits = 10,
+    EnableNonConfidentialCredits = 11,
+    DisableNonConfidentialCredits = 12,
+    TransferWithFee = 13,
+    ConfigureAccountWithRegistry = 14,
+}
+
+/// Confidential transfer mint configuration
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConfidentialTransferMint {
+    /// Authority to modify the `ConfidentialTransferMint` configuration and to
+    /// approve new accounts (if `auto_approve_new_accounts` is false)
+    authority: Pubkey,
+    /// Indicate if newly configured accounts must be approved by the
+    /// `authority` before they may be used by the user
+    auto_approve_new_accounts: u8,
+    /// Authority to decode any transfer amount in a confidential transfer
+    auditor_elgamal_pubkey: ElGamalPubkey,
+}
+
+const _: () = assert!(core::mem::size_of::<ConfidentialTransferMint>() == 65);
+const _: () = assert!(core::mem::align_of::<ConfidentialTransferMint>() == 1);
+
+unsafe impl Extension for ConfidentialTransferMint {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialTransferMint;
+}
+
+impl ConfidentialTransferMint {
+    /// The length of the `ConfidentialTransferMint` data.
+    pub const BASE_LEN: usize = core::mem::size_of::<ConfidentialTransferMint>();
+
+    /// Return a `ConfidentialTransferMint` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, ConfidentialTransferMint>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return a `ConfidentialTransferMint` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        get_extension_from_account_info_unchecked::<Self>(account_info)
+    }
+
+    /// Return a `ConfidentialTransferMint` from the given extension bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
+    /// 2. `bytes` contains a valid representation of `ConfidentialTransferMint`
+    #[inline(always)]
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const ConfidentialTransferMint)
+    }
+
+    /// Return a `ConfidentialTransferMint` from the given account data, locating it through
+    /// the account TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    #[inline(always)]
+    pub fn has_authority(&self) -> bool {
+        self.authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn authority(&self) -> Option<&Pubkey> {
+        if self.has_authority() {
+            Some(&self.authority)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn auto_approve_new_accounts(&self) -> bool {
+        self.auto_approve_new_accounts != 0
+    }
+
+    #[inline]
+    pub fn auditor_elgamal_pubkey(&self) -> Option<&ElGamalPubkey> {
+        if self.auditor_elgamal_pubkey != [0; ELGAMAL_PUBKEY_LEN] {
+            Some(&self.auditor_elgamal_pubkey)
+        } else {
+            None
+        }
+    }
+}
+
+/// Confidential account state
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConfidentialTransferAccount {
+    /// `true` if this account has been approved for use. All confidential
+    /// transfer operations for the account will fail until approval is
+    /// granted.
+    approved: u8,
+    /// The public key associated with ElGamal encryption
+    elgamal_pubkey: ElGamalPubkey,
+    /// The low 16 bits of the pending balance (encrypted by `elgamal_pubkey`)
+    pending_balance_lo: EncryptedBalance,
+    /// The high 48 bits of the pending balance (encrypted by `elgamal_pubkey`)
+    pending_balance_hi: EncryptedBalance,
+    /// The available balance (encrypted by `encryption_pubkey`)
+    available_balance: EncryptedBalance,
+    /// The decryptable available balance
+    decryptable_available_balance: DecryptableBalance,
+    /// If `false`, the extended account rejects any incoming confidential
+    /// transfers
+    allow_confidential_credits: u8,
+    /// If `false`, the base account rejects any incoming transfers
+    allow_non_confidential_credits: u8,
+    /// The total number of `Deposit` and `Transfer` instructions that have
+    /// credited `pending_balance`
+    pending_balance_credit_counter: [u8; 8],
+    /// The maximum number of `Deposit` and `Transfer` instructions that can
+    /// credit `pending_balance` before the `ApplyPendingBalance`
+    /// instruction is executed
+    maximum_pending_balance_credit_counter: [u8; 8],
+    /// The `expected_pending_balance_credit_counter` value that was included in
+    /// the last `ApplyPendingBalance` instruction
+    expected_pending_balance_credit_counter: [u8; 8],
+    /// The actual `pending_balance_credit_counter` when the last
+    /// `ApplyPendingBalance` instruction was executed
+    actual_pending_balance_credit_counter: [u8; 8],
+}
+
+const _: () = assert!(core::mem::size_of::<ConfidentialTransferAccount>() == 295);
+const _: () = assert!(core::mem::align_of::<ConfidentialTransferAccount>() == 1);
+
+unsafe impl Extension for ConfidentialTransferAccount {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialTransferAccount;
+}
+
+impl ConfidentialTransferAccount {
+    /// The length of the `ConfidentialTransferAccount` data.
+    pub const BASE_LEN: usize = core::mem::size_of::<ConfidentialTransferAccount>();
+
+    /// Return a `ConfidentialTransferAccount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, ConfidentialTransferAccount>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return a `ConfidentialTransferAccount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        get_extension_from_account_info_unchecked::<Self>(account_info)
+    }
+
+    /// Return a `ConfidentialTransferAccount` from the given extension bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
+    /// 2. `bytes` contains a valid representation of `ConfidentialTransferAccount`
+    #[inline(always)]
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const ConfidentialTransferAccount)
+    }
+
+    /// Return a `ConfidentialTransferAccount` from the given account data, locating it
+    /// through the account TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    #[inline(always)]
+    pub fn approved(&self) -> bool {
+        self.approved != 0
+    }
+
+    #[inline(always)]
+    pub fn elgamal_pubkey(&self) -> &ElGamalPubkey {
+        &self.elgamal_pubkey
+    }
+
+    #[inline(always)]
+    pub fn pending_balance_lo(&self) -> &EncryptedBalance {
+        &self.pending_balance_lo
+    }
+
+    #[inline(always)]
+    pub fn pending_balance_hi(&self) -> &EncryptedBalance {
+        &self.pending_balance_hi
+    }
+
+    #[inline(always)]
+    pub fn available_balance(&self) -> &EncryptedBalance {
+        &self.available_balance
+    }
+
+    #[inline(always)]
+    pub fn decryptable_available_balance(&self) -> &DecryptableBalance {
+        &self.decryptable_available_balance
+    }
+
+    #[inline(always)]
+    pub fn allow_confidential_credits(&self) -> bool {
+        self.allow_confidential_credits != 0
+    }
+
+    #[inline(always)]
+    pub fn allow_non_confidential_credits(&self) -> bool {
+        self.allow_non_confidential_credits != 0
+    }
+
+    #[inline(always)]
+    pub fn pending_balance_credit_counter(&self) -> u64 {
+        u64::from_le_bytes(self.pending_balance_credit_counter)
+    }
+
+    #[inline(always)]
+    pub fn maximum_pending_balance_credit_counter(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_pending_balance_credit_counter)
+    }
+
+    #[inline(always)]
+    pub fn expected_pending_balance_credit_counter(&self) -> u64 {
+        u64::from_le_bytes(self.expected_pending_balance_credit_counter)
+    }
+
+    #[inline(always)]
+    pub fn actual_pending_balance_credit_counter(&self) -> u64 {
+        u64::from_le_bytes(self.actual_pending_balance_credit_counter)
+    }
+
+    /// Return `true` if the pending and available balances are empty, as required
+    /// to close the account.
+    #[inline]
+    pub fn is_closable(&self) -> bool {
+        self.pending_balance_lo == [0; ELGAMAL_CIPHERTEXT_LEN]
+            && self.pending_balance_hi == [0; ELGAMAL_CIPHERTEXT_LEN]
+            && self.available_balance == [0; ELGAMAL_CIPHERTEXT_LEN]
+    }
+}
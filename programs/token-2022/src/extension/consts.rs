@@ -1,3 +1,5 @@
+use {crate::extension::tlv::TLV_HEADER_LEN, pinocchio::program_error::ProgramError};
+
 #[repr(u8)]
 pub enum ExtensionDiscriminator {
     CpiGuard = 34,
@@ -11,4 +13,223 @@ pub enum ExtensionDiscriminator {
     TransferHook = 36,
     InterestBearingMint = 33,
     MetadataPointer = 39,
+    TransferFee = 26,
+    ConfidentialTransfer = 27,
+    MintCloseAuthority = 25,
+    ImmutableOwner = 22,
+    NonTransferable = 32,
+}
+
+/// Extension types, as stored in the `type` field of a TLV entry.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExtensionType {
+    /// Used as padding if the account size would otherwise be 355, same as a multisig
+    Uninitialized = 0,
+    /// Includes transfer fee rate info and accompanying authorities to withdraw and set the fee
+    TransferFeeConfig = 1,
+    /// Includes withheld transfer fees
+    TransferFeeAmount = 2,
+    /// Includes an optional mint close authority
+    MintCloseAuthority = 3,
+    /// Auditor configuration for confidential transfers
+    ConfidentialTransferMint = 4,
+    /// State for confidential transfers
+    ConfidentialTransferAccount = 5,
+    /// Specifies the default `AccountState` for new accounts
+    DefaultAccountState = 6,
+    /// Indicates that the account owner authority cannot be changed
+    ImmutableOwner = 7,
+    /// Indicates that the account requires memos for incoming transfers
+    MemoTransfer = 8,
+    /// Indicates that the tokens from this mint can't be transferred
+    NonTransferable = 9,
+    /// Tracks the rate and timestamps for an interest-bearing mint
+    InterestBearingConfig = 10,
+    /// Locks privileged token operations from happening via CPI
+    CpiGuard = 11,
+    /// Includes an optional permanent delegate
+    PermanentDelegate = 12,
+    /// Indicates that the tokens in this account belong to a non-transferable mint
+    NonTransferableAccount = 13,
+    /// Mint requires a CPI to a program implementing the "transfer hook" interface
+    TransferHook = 14,
+    /// Indicates that the tokens in this account belong to a mint with a transfer hook
+    TransferHookAccount = 15,
+    /// Includes encrypted withheld fees and the encryption public key they are encrypted under
+    ConfidentialTransferFeeConfig = 16,
+    /// Includes confidential withheld transfer fees
+    ConfidentialTransferFeeAmount = 17,
+    /// Mint contains a pointer to another account (or the same account) that holds metadata
+    MetadataPointer = 18,
+    /// Mint contains token metadata
+    TokenMetadata = 19,
+    /// Mint contains a pointer to another account (or the same account) that holds group
+    /// configurations
+    GroupPointer = 20,
+    /// Mint contains token group configurations
+    TokenGroup = 21,
+    /// Mint contains a pointer to another account (or the same account) that holds group
+    /// member configurations
+    GroupMemberPointer = 22,
+    /// Mint contains token group member configurations
+    TokenGroupMember = 23,
+    /// Mint allowing the minting and burning of confidential tokens
+    ConfidentialMintBurn = 24,
+    /// Mint has a multiplier applied to the UI amount
+    ScaledUiAmount = 25,
+    /// Mint can be paused
+    Pausable = 26,
+    /// Indicates that the account belongs to a pausable mint
+    PausableAccount = 27,
+}
+
+impl ExtensionType {
+    /// Return the length of the extension value, as laid out on-chain.
+    ///
+    /// Fails with `InvalidArgument` for extensions with a variable length, such as
+    /// `TokenMetadata`.
+    #[inline]
+    pub const fn try_get_type_len(&self) -> Result<usize, ProgramError> {
+        Ok(match self {
+            ExtensionType::Uninitialized => 0,
+            ExtensionType::TransferFeeConfig => 108,
+            ExtensionType::TransferFeeAmount => 8,
+            ExtensionType::MintCloseAuthority => 32,
+            ExtensionType::ConfidentialTransferMint => 65,
+            ExtensionType::ConfidentialTransferAccount => 295,
+            ExtensionType::DefaultAccountState => 1,
+            ExtensionType::ImmutableOwner => 0,
+            ExtensionType::MemoTransfer => 1,
+            ExtensionType::NonTransferable => 0,
+            ExtensionType::InterestBearingConfig => 52,
+            ExtensionType::CpiGuard => 1,
+            ExtensionType::PermanentDelegate => 32,
+            ExtensionType::NonTransferableAccount => 0,
+            ExtensionType::TransferHook => 64,
+            ExtensionType::TransferHookAccount => 1,
+            ExtensionType::ConfidentialTransferFeeConfig => 129,
+            ExtensionType::ConfidentialTransferFeeAmount => 64,
+            ExtensionType::MetadataPointer => 64,
+            ExtensionType::TokenMetadata => return Err(ProgramError::InvalidArgument),
+            ExtensionType::GroupPointer => 64,
+            ExtensionType::TokenGroup => 80,
+            ExtensionType::GroupMemberPointer => 64,
+            ExtensionType::TokenGroupMember => 72,
+            ExtensionType::ConfidentialMintBurn => 196,
+            ExtensionType::ScaledUiAmount => 56,
+            ExtensionType::Pausable => 33,
+            ExtensionType::PausableAccount => 0,
+        })
+    }
+
+    /// Return the length of the TLV entry of the extension, including its header.
+    ///
+    /// Fails with `InvalidArgument` for extensions with a variable length.
+    #[inline]
+    pub const fn try_get_tlv_len(&self) -> Result<usize, ProgramError> {
+        match self.try_get_type_len() {
+            Ok(len) => Ok(TLV_HEADER_LEN + len),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Return the account type that carries this extension.
+    #[inline]
+    pub const fn account_type(&self) -> AccountType {
+        match self {
+            ExtensionType::Uninitialized => AccountType::Uninitialized,
+            ExtensionType::TransferFeeConfig
+            | ExtensionType::MintCloseAuthority
+            | ExtensionType::ConfidentialTransferMint
+            | ExtensionType::DefaultAccountState
+            | ExtensionType::NonTransferable
+            | ExtensionType::InterestBearingConfig
+            | ExtensionType::PermanentDelegate
+            | ExtensionType::TransferHook
+            | ExtensionType::ConfidentialTransferFeeConfig
+            | ExtensionType::MetadataPointer
+            | ExtensionType::TokenMetadata
+            | ExtensionType::GroupPointer
+            | ExtensionType::TokenGroup
+            | ExtensionType::GroupMemberPointer
+            | ExtensionType::TokenGroupMember
+            | ExtensionType::ConfidentialMintBurn
+            | ExtensionType::ScaledUiAmount
+            | ExtensionType::Pausable => AccountType::Mint,
+            ExtensionType::TransferFeeAmount
+            | ExtensionType::ConfidentialTransferAccount
+            | ExtensionType::ImmutableOwner
+            | ExtensionType::MemoTransfer
+            | ExtensionType::CpiGuard
+            | ExtensionType::NonTransferableAccount
+            | ExtensionType::TransferHookAccount
+            | ExtensionType::ConfidentialTransferFeeAmount
+            | ExtensionType::PausableAccount => AccountType::Account,
+        }
+    }
+}
+
+impl TryFrom<u16> for ExtensionType {
+    type Error = ProgramError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => ExtensionType::Uninitialized,
+            1 => ExtensionType::TransferFeeConfig,
+            2 => ExtensionType::TransferFeeAmount,
+            3 => ExtensionType::MintCloseAuthority,
+            4 => ExtensionType::ConfidentialTransferMint,
+            5 => ExtensionType::ConfidentialTransferAccount,
+            6 => ExtensionType::DefaultAccountState,
+            7 => ExtensionType::ImmutableOwner,
+            8 => ExtensionType::MemoTransfer,
+            9 => ExtensionType::NonTransferable,
+            10 => ExtensionType::InterestBearingConfig,
+            11 => ExtensionType::CpiGuard,
+            12 => ExtensionType::PermanentDelegate,
+            13 => ExtensionType::NonTransferableAccount,
+            14 => ExtensionType::TransferHook,
+            15 => ExtensionType::TransferHookAccount,
+            16 => ExtensionType::ConfidentialTransferFeeConfig,
+            17 => ExtensionType::ConfidentialTransferFeeAmount,
+            18 => ExtensionType::MetadataPointer,
+            19 => ExtensionType::TokenMetadata,
+            20 => ExtensionType::GroupPointer,
+            21 => ExtensionType::TokenGroup,
+            22 => ExtensionType::GroupMemberPointer,
+            23 => ExtensionType::TokenGroupMember,
+            24 => ExtensionType::ConfidentialMintBurn,
+            25 => ExtensionType::ScaledUiAmount,
+            26 => ExtensionType::Pausable,
+            27 => ExtensionType::PausableAccount,
+            _ => return Err(ProgramError::InvalidAccountData),
+        })
+    }
+}
+
+/// Type of the account, stored in the byte right after the base account data
+/// of an account with extensions.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AccountType {
+    /// Marker for 0 data
+    Uninitialized = 0,
+    /// Mint account with additional extensions
+    Mint = 1,
+    /// Token holding account with additional extensions
+    Account = 2,
+}
+
+impl TryFrom<u8> for AccountType {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AccountType::Uninitialized),
+            1 => Ok(AccountType::Mint),
+            2 => Ok(AccountType::Account),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
 }
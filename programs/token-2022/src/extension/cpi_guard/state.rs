@@ -1,7 +1,13 @@
-use crate::extension::consts::ExtensionDiscriminator;
+use crate::extension::consts::{ExtensionDiscriminator, ExtensionType};
+use crate::extension::tlv::{
+    get_extension, get_extension_from_account_info_mut, get_extension_mut, Extension,
+};
 use crate::{write_bytes, UNINIT_BYTE};
 use core::mem::MaybeUninit;
-use pinocchio::program_error::ProgramError;
+use pinocchio::{
+    account_info::{AccountInfo, RefMut},
+    program_error::ProgramError,
+};
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -14,39 +20,56 @@ pub enum CpiGuardInstruction {
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CpiGuard {
     /// Lock privileged token operations from happening via CPI
-    pub lock_cpi: u8,
+    lock_cpi: u8,
 }
 
-impl CpiGuard {
-    /// The length of the account with `CpiGuard` extension data
-    const LEN: u8 = 171;
-    /// The index where CPI guard data starts in the account with `CpiGuard` extension data
-    const CPI_GUARD_START: u8 = 170;
+const _: () = assert!(core::mem::size_of::<CpiGuard>() == 1);
+const _: () = assert!(core::mem::align_of::<CpiGuard>() == 1);
+
+unsafe impl Extension for CpiGuard {
+    const TYPE: ExtensionType = ExtensionType::CpiGuard;
+}
 
+impl CpiGuard {
     /// The length of the `CpiGuard` extension data.
     pub const BASE_LEN: usize = core::mem::size_of::<CpiGuard>();
 
-    /// Return a `CpiGuard` from the given bytes.
+    /// Return a `CpiGuard` from the given extension bytes.
     ///
     /// # Safety
     ///
     /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
     /// 2. `bytes` contains a valid representation of `CpiGuard`
     /// 3. The data is properly aligned
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::CPI_GUARD_START as usize..].as_ptr() as *const CpiGuard)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const CpiGuard)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `CpiGuard` from the given token account data, locating it through
+    /// the account TLV entries.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
+        get_extension::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Return a mutable `CpiGuard` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
+
+    /// Return a mutable `CpiGuard` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, CpiGuard>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
     #[inline(always)]
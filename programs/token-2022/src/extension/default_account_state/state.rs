@@ -1,10 +1,13 @@
 use core::mem::MaybeUninit;
-use crate::{write_bytes, UNINIT_BYTE, ID};
-use crate::extension::consts::ExtensionDiscriminator;
+use crate::{write_bytes, UNINIT_BYTE};
+use crate::extension::consts::{ExtensionDiscriminator, ExtensionType};
+use crate::extension::tlv::{
+    get_extension, get_extension_from_account_info, get_extension_from_account_info_mut,
+    get_extension_from_account_info_unchecked, get_extension_mut, Extension,
+};
 use pinocchio::{
-    account_info::{AccountInfo, Ref},
+    account_info::{AccountInfo, Ref, RefMut},
     program_error::ProgramError,
-    pubkey::Pubkey,
 };
 
 /// Default Account State extension instructions
@@ -22,41 +25,34 @@ pub enum DefaultAccountStateInstruction {
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct DefaultAccountStateConfig {
     /// Default state for new accounts
-    pub state: u8,
+    state: u8,
 }
 
-impl DefaultAccountStateConfig {
-    pub const AUTHORITY_START: usize = 170;
+const _: () = assert!(core::mem::size_of::<DefaultAccountStateConfig>() == 1);
+const _: () = assert!(core::mem::align_of::<DefaultAccountStateConfig>() == 1);
 
-    pub const BASE_LEN: usize = core::mem::size_of::<DefaultAccountStateConfig>();
+unsafe impl Extension for DefaultAccountStateConfig {
+    const TYPE: ExtensionType = ExtensionType::DefaultAccountState;
+}
 
-    pub const LEN: usize = Self::AUTHORITY_START + Self::BASE_LEN;
+impl DefaultAccountStateConfig {
+    pub const BASE_LEN: usize = core::mem::size_of::<DefaultAccountStateConfig>();
 
     /// Return a `DefaultAccountStateConfig` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
-    ) -> Result<Self, pinocchio::program_error::ProgramError> {
-        let data = account_info.try_borrow_data()?;
-        if data.len() < Self::LEN as usize {
-            return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
-        }
-        
-        // SAFETY: We've validated the length above
-        let config = unsafe {
-            core::ptr::read((data.as_ptr().add(Self::AUTHORITY_START as usize)) as *const Self)
-        };
-        
-        Ok(config)
+    ) -> Result<Ref<'_, DefaultAccountStateConfig>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
 
     /// Return a `DefaultAccountStateConfig` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -66,42 +62,45 @@ impl DefaultAccountStateConfig {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-        Ok(Self::from_bytes_unchecked(data))
+        get_extension_from_account_info_unchecked::<Self>(account_info)
     }
 
-    /// Return a `DefaultAccountStateConfig` from the given bytes.
+    /// Return a `DefaultAccountStateConfig` from the given extension bytes.
     ///
     /// # Safety
     ///
     /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
     /// 2. `bytes` contains a valid representation of `DefaultAccountStateConfig`
     /// 3. The data is properly aligned
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::AUTHORITY_START as usize..].as_ptr() as *const DefaultAccountStateConfig)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const DefaultAccountStateConfig)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `DefaultAccountStateConfig` from the given mint data, locating it
+    /// through the account TLV entries.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `DefaultAccountStateConfig` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Return a mutable `DefaultAccountStateConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, DefaultAccountStateConfig>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
     /// Get the default account state
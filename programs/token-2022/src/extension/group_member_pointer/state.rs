@@ -1,7 +1,14 @@
 use {
-    crate::ID,
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{
+            get_extension, get_extension_from_account_info,
+            get_extension_from_account_info_mut, get_extension_from_account_info_unchecked,
+            get_extension_mut, Extension,
+        },
+    },
     pinocchio::{
-        account_info::{AccountInfo, Ref},
+        account_info::{AccountInfo, Ref, RefMut},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
@@ -43,47 +50,32 @@ pub struct GroupMemberPointer {
     member_address: Pubkey,
 }
 
-impl GroupMemberPointer {
-    /// The length of the mint with `GroupMemberPointer` extension data
-    const LEN: u8 = 234;
-    /// The index where authority address starts in the mint with `GroupMemberPointer` extension data
-    const AUTHORITY_START: u8 = 170;
+const _: () = assert!(core::mem::size_of::<GroupMemberPointer>() == 64);
+const _: () = assert!(core::mem::align_of::<GroupMemberPointer>() == 1);
+
+unsafe impl Extension for GroupMemberPointer {
+    const TYPE: ExtensionType = ExtensionType::GroupMemberPointer;
+}
 
+impl GroupMemberPointer {
     /// The length of the `GroupMemberPointer` extension data.
     pub const BASE_LEN: usize = core::mem::size_of::<GroupMemberPointer>();
 
     /// Return a `GroupMemberPointer` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
-    ) -> Result<Ref<GroupMemberPointer>, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Safely borrow and map the data
-        let data_ref = account_info
-            .try_borrow_data()
-            .map_err(|_| ProgramError::AccountBorrowFailed)?;
-
-        Ok(Ref::map(data_ref, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+    ) -> Result<Ref<'_, GroupMemberPointer>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
 
     /// Return a `GroupMemberPointer` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -93,43 +85,46 @@ impl GroupMemberPointer {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-        Ok(Self::from_bytes_unchecked(data))
+        get_extension_from_account_info_unchecked::<Self>(account_info)
     }
 
-    /// Return a `GroupMemberPointer` from the given bytes.
+    /// Return a `GroupMemberPointer` from the given extension bytes.
     ///
     /// # Safety
     ///
     /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
     /// 2. `bytes` contains a valid representation of `GroupMemberPointer`
     /// 3. The data is properly aligned (though GroupMemberPointer has alignment of 1)
     /// 4. The bytes represent valid flag values and pubkey data
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::AUTHORITY_START as usize..].as_ptr() as *const GroupMemberPointer)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const GroupMemberPointer)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `GroupMemberPointer` from the given account data, locating it through the account
+    /// TLV entries.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
+        get_extension::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Return a mutable `GroupMemberPointer` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
+
+    /// Return a mutable `GroupMemberPointer` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, GroupMemberPointer>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
     /// Creates a new state
@@ -0,0 +1,53 @@
+use {
+    crate::extension::{
+        consts::ExtensionDiscriminator,
+        immutable_owner::state::offset_immutable_owner_initialize as OFFSET,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Initialize the Immutable Owner extension for the given token account.
+///
+/// Fails if the account has already been initialized, so must be called
+/// before `InitializeAccount`.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]`  The account to initialize.
+///
+/// Data expected by this instruction:
+///   None
+pub struct InitializeImmutableOwner<'a> {
+    /// The token account to initialize with an immutable owner
+    pub account: &'a AccountInfo,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl InitializeImmutableOwner<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [AccountMeta::writable(self.account.key())];
+
+        let data = &[ExtensionDiscriminator::ImmutableOwner as u8; OFFSET::END as usize];
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(&instruction, &[self.account], signers)
+    }
+}
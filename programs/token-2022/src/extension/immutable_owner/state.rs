@@ -0,0 +1,57 @@
+use {
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{get_extension, get_extension_from_account_info, Extension},
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref},
+        program_error::ProgramError,
+    },
+};
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+pub mod offset_immutable_owner_initialize {
+    pub const START: u8 = 1;
+    pub const END: u8 = START;
+}
+
+/// Indicates that the account owner authority cannot be changed.
+///
+/// This is a marker extension without data: its presence in the token account
+/// TLV entries is all that matters.
+#[repr(C)]
+pub struct ImmutableOwner;
+
+const _: () = assert!(core::mem::size_of::<ImmutableOwner>() == 0);
+const _: () = assert!(core::mem::align_of::<ImmutableOwner>() == 1);
+
+unsafe impl Extension for ImmutableOwner {
+    const TYPE: ExtensionType = ExtensionType::ImmutableOwner;
+}
+
+impl ImmutableOwner {
+    /// Return an `ImmutableOwner` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, ImmutableOwner>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return an `ImmutableOwner` from the given account data, locating it through the account
+    /// TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return `true` if the given token account data contains the `ImmutableOwner` extension.
+    #[inline]
+    pub fn is_present(bytes: &[u8]) -> bool {
+        Self::from_bytes(bytes).is_ok()
+    }
+}
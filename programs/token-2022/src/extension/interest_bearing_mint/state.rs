@@ -1,8 +1,19 @@
 use {
-    crate::{write_bytes, ID, UNINIT_BYTE},
+    crate::{
+        extension::{
+            consts::ExtensionType,
+            tlv::{
+                get_extension, get_extension_from_account_info,
+                get_extension_from_account_info_mut, get_extension_from_account_info_unchecked,
+                get_extension_mut, Extension,
+            },
+            ui_amount::{exp, format_ui_amount, parse_ui_amount, pow10, round, try_into_amount},
+        },
+        write_bytes, UNINIT_BYTE,
+    },
     core::mem::MaybeUninit,
     pinocchio::{
-        account_info::{AccountInfo, Ref},
+        account_info::{AccountInfo, Ref, RefMut},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
@@ -15,61 +26,49 @@ pub enum InterestBearingMintInstruction {
     UpdateRate = 1,
 }
 
-#[repr(C, packed)]
+const ONE_IN_BASIS_POINTS: f64 = 10_000.;
+const SECONDS_PER_YEAR: f64 = 60. * 60. * 24. * 365.24;
+
+#[repr(C)]
 pub struct InterestBearingConfig {
     /// Authority that can set the interest rate
     rate_authority: Pubkey,
     /// Initialization timestamp
-    initialization_timestamp: i64,
+    initialization_timestamp: [u8; 8],
     /// Pre-update average rate
-    pre_update_average_rate: i16,
+    pre_update_average_rate: [u8; 2],
     /// Last update timestamp
-    last_update_timestamp: i64,
+    last_update_timestamp: [u8; 8],
     /// Current interest rate
-    current_rate: i16,
+    current_rate: [u8; 2],
 }
 
-impl InterestBearingConfig {
-    /// The index where rate_authority address starts in the mint with `InterestBearingConfig` extension data
-    pub const RATE_AUTHORITY_START: u8 = 170;
+const _: () = assert!(core::mem::size_of::<InterestBearingConfig>() == 52);
+const _: () = assert!(core::mem::align_of::<InterestBearingConfig>() == 1);
+
+unsafe impl Extension for InterestBearingConfig {
+    const TYPE: ExtensionType = ExtensionType::InterestBearingConfig;
+}
 
+impl InterestBearingConfig {
     /// The length of the `InterestBearingConfig` extension data.
     pub const BASE_LEN: usize = core::mem::size_of::<InterestBearingConfig>();
 
-    pub const LEN: usize = Self::BASE_LEN as usize + Self::RATE_AUTHORITY_START as usize;
-
     /// Return a `InterestBearingConfig` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
-    ) -> Result<Ref<InterestBearingConfig>, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Safely borrow and map the data
-        let data_ref = account_info
-            .try_borrow_data()
-            .map_err(|_| ProgramError::AccountBorrowFailed)?;
-
-        Ok(Ref::map(data_ref, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+    ) -> Result<Ref<'_, InterestBearingConfig>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
 
     /// Return a `InterestBearingConfig` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -79,53 +78,54 @@ impl InterestBearingConfig {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-        Ok(Self::from_bytes_unchecked(data))
+        get_extension_from_account_info_unchecked::<Self>(account_info)
     }
 
-    /// Return a `InterestBearingConfig` from the given bytes.
+    /// Return a `InterestBearingConfig` from the given extension bytes.
     ///
     /// # Safety
     ///
     /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
     /// 2. `bytes` contains a valid representation of `InterestBearingConfig`
-    /// 3. The data is properly aligned (though InterestBearingConfig has alignment of 1)
-    /// 4. The bytes represent valid flag values and pubkey data
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::RATE_AUTHORITY_START as usize..].as_ptr() as *const InterestBearingConfig)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const InterestBearingConfig)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `InterestBearingConfig` from the given account data, locating it through the account
+    /// TLV entries.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `InterestBearingConfig` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Return a mutable `InterestBearingConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, InterestBearingConfig>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
     /// Creates a new state
     pub fn new(rate_authority: Option<&Pubkey>, rate: i16) -> Self {
         Self {
             rate_authority: rate_authority.map(|&x| x).unwrap_or_default(),
-            initialization_timestamp: 0,
-            last_update_timestamp: 0,
-            pre_update_average_rate: rate,
-            current_rate: rate,
+            initialization_timestamp: [0; 8],
+            last_update_timestamp: [0; 8],
+            pre_update_average_rate: rate.to_le_bytes(),
+            current_rate: rate.to_le_bytes(),
         }
     }
 
@@ -154,22 +154,86 @@ impl InterestBearingConfig {
 
     #[inline(always)]
     pub fn initialization_timestamp(&self) -> i64 {
-        self.initialization_timestamp
+        i64::from_le_bytes(self.initialization_timestamp)
     }
 
     #[inline(always)]
     pub fn last_update_timestamp(&self) -> i64 {
-        self.last_update_timestamp
+        i64::from_le_bytes(self.last_update_timestamp)
     }
 
     #[inline(always)]
     pub fn pre_update_average_rate(&self) -> i16 {
-        self.pre_update_average_rate
+        i16::from_le_bytes(self.pre_update_average_rate)
     }
 
     #[inline(always)]
     pub fn current_rate(&self) -> i16 {
-        self.current_rate
+        i16::from_le_bytes(self.current_rate)
+    }
+
+    fn pre_update_timespan(&self) -> Option<i64> {
+        self.last_update_timestamp()
+            .checked_sub(self.initialization_timestamp())
+    }
+
+    fn pre_update_exp(&self) -> Option<f64> {
+        let numerator = (self.pre_update_average_rate() as i128)
+            .checked_mul(self.pre_update_timespan()? as i128)? as f64;
+        let exponent = numerator / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS;
+        Some(exp(exponent))
+    }
+
+    fn post_update_timespan(&self, unix_timestamp: i64) -> Option<i64> {
+        unix_timestamp.checked_sub(self.last_update_timestamp())
+    }
+
+    fn post_update_exp(&self, unix_timestamp: i64) -> Option<f64> {
+        let numerator = (self.current_rate() as i128)
+            .checked_mul(self.post_update_timespan(unix_timestamp)? as i128)?
+            as f64;
+        let exponent = numerator / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS;
+        Some(exp(exponent))
+    }
+
+    fn total_scale(&self, decimals: u8, unix_timestamp: i64) -> Option<f64> {
+        Some(self.pre_update_exp()? * self.post_update_exp(unix_timestamp)? / pow10(decimals))
+    }
+
+    /// Convert a raw amount to its UI representation, including the interest
+    /// accrued up to `unix_timestamp` (usually `Clock::unix_timestamp`).
+    ///
+    /// The UI amount is written into `buffer` with excess zeroes and an unneeded
+    /// decimal point trimmed. Returns `None` if the interest calculation
+    /// overflows or the buffer is too small.
+    pub fn amount_to_ui_amount<'b>(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: i64,
+        buffer: &'b mut [u8],
+    ) -> Option<&'b str> {
+        let scaled_amount_with_interest =
+            (amount as f64) * self.total_scale(decimals, unix_timestamp)?;
+        format_ui_amount(scaled_amount_with_interest, decimals, buffer)
+    }
+
+    /// Convert a UI representation of a token amount, including the interest
+    /// accrued up to `unix_timestamp`, to its raw amount.
+    ///
+    /// The raw amount is rounded to the nearest integer.
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Result<u64, ProgramError> {
+        let scaled_amount = parse_ui_amount(ui_amount)?;
+        let amount = scaled_amount
+            / self
+                .total_scale(decimals, unix_timestamp)
+                .ok_or(ProgramError::InvalidArgument)?;
+        Ok(round(try_into_amount(amount)?) as u64)
     }
 }
 
@@ -1,7 +1,14 @@
 use {
-    crate::ID,
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{
+            get_extension, get_extension_from_account_info,
+            get_extension_from_account_info_mut, get_extension_from_account_info_unchecked,
+            get_extension_mut, Extension,
+        },
+    },
     pinocchio::{
-        account_info::{AccountInfo, Ref},
+        account_info::{AccountInfo, Ref, RefMut},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
@@ -29,57 +36,40 @@ pub mod offset_memo_transfer {
 #[repr(C)]
 pub struct MemoTransfer {
     /// Indicates whether incoming transfers must include a memo.
-    pub require_incoming_transfer_memos: bool,
+    require_incoming_transfer_memos: u8,
 }
 
-impl MemoTransfer {
-    /// The length of the token_account with `MemoTransfer` extension data
-    const LEN: u8 = 171;
+const _: () = assert!(core::mem::size_of::<MemoTransfer>() == 1);
+const _: () = assert!(core::mem::align_of::<MemoTransfer>() == 1);
 
-    /// The byte index where the `require_incoming_transfer_memos` flag is stored
-    /// within a Token-2022 account initialized with the `MemoTransfer` extension.
-    const REQUIRE_MEMO_FLAG_INDEX: u8 = 170;
+unsafe impl Extension for MemoTransfer {
+    const TYPE: ExtensionType = ExtensionType::MemoTransfer;
+}
 
+impl MemoTransfer {
     /// Init with given memo requirement flag.
     #[inline]
     pub fn new(require_memo: bool) -> Self {
         Self {
-            require_incoming_transfer_memos: require_memo,
+            require_incoming_transfer_memos: require_memo as u8,
         }
     }
 
     /// Return a `MemoTransfer` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
-    ) -> Result<Ref<MemoTransfer>, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Safely borrow and map the data
-        let data_ref = account_info
-            .try_borrow_data()
-            .map_err(|_| ProgramError::AccountBorrowFailed)?;
-
-        Ok(Ref::map(data_ref, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+    ) -> Result<Ref<'_, MemoTransfer>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
 
     /// Return a `MemoTransfer` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -89,54 +79,62 @@ impl MemoTransfer {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-        Ok(Self::from_bytes_unchecked(data))
+        get_extension_from_account_info_unchecked::<Self>(account_info)
     }
 
-    /// Return a `MemoTransfer` from the given bytes.
+    /// Return a `MemoTransfer` from the given extension bytes.
     ///
     /// # Safety
     ///
     /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
     /// 2. `bytes` contains a valid representation of `MemoTransfer`
     /// 3. The data is properly aligned (though MemoTransfer has alignment of 1)
     /// 4. The bytes represent valid flag values and pubkey data
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::REQUIRE_MEMO_FLAG_INDEX as usize..].as_ptr() as *const MemoTransfer)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const MemoTransfer)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `MemoTransfer` from the given account data, locating it through the account
+    /// TLV entries.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `MemoTransfer` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Return a mutable `MemoTransfer` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, MemoTransfer>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
+    }
+
+    #[inline(always)]
+    pub fn require_incoming_transfer_memos(&self) -> bool {
+        self.require_incoming_transfer_memos != 0
     }
 
     /// Returns true if memo transfers are enabled.
     #[inline(always)]
     pub fn is_enabled(&self) -> bool {
-        self.require_incoming_transfer_memos
+        self.require_incoming_transfer_memos()
     }
 
     /// Returns true if memo transfers are disabled.
     #[inline(always)]
     pub fn is_disabled(&self) -> bool {
-        !self.require_incoming_transfer_memos
+        !self.require_incoming_transfer_memos()
     }
 }
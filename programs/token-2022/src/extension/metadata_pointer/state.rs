@@ -1,7 +1,14 @@
 use {
-    crate::ID,
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{
+            get_extension, get_extension_from_account_info,
+            get_extension_from_account_info_mut, get_extension_from_account_info_unchecked,
+            get_extension_mut, Extension,
+        },
+    },
     pinocchio::{
-        account_info::{AccountInfo, Ref},
+        account_info::{AccountInfo, Ref, RefMut},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
@@ -39,53 +46,37 @@ pub mod offset_metadata_pointer_update {
 #[repr(C)]
 pub struct MetadataPointer {
     /// Authority that can set the metadata address
-    pub authority: Pubkey,
+    authority: Pubkey,
     /// Account address that holds the metadata
-    pub metadata_address: Pubkey,
+    metadata_address: Pubkey,
 }
 
-impl MetadataPointer {
-    /// The length of the mint with `MetadataPointer` extension data
-    const LEN: u8 = 234;
+const _: () = assert!(core::mem::size_of::<MetadataPointer>() == 64);
+const _: () = assert!(core::mem::align_of::<MetadataPointer>() == 1);
 
-    /// The index where authority address starts in the mint with `MetadataPointer` extension data
-    const AUTHORITY_START: u8 = 170;
+unsafe impl Extension for MetadataPointer {
+    const TYPE: ExtensionType = ExtensionType::MetadataPointer;
+}
 
+impl MetadataPointer {
     /// The length of the `MetadataPointer` extension data.
     pub const BASE_LEN: usize = core::mem::size_of::<MetadataPointer>();
 
     /// Return a `MetadataPointer` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
-    ) -> Result<Ref<MetadataPointer>, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Safely borrow and map the data
-        let data_ref = account_info
-            .try_borrow_data()
-            .map_err(|_| ProgramError::AccountBorrowFailed)?;
-
-        Ok(Ref::map(data_ref, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+    ) -> Result<Ref<'_, MetadataPointer>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
 
     /// Return a `MetadataPointer` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -95,43 +86,46 @@ impl MetadataPointer {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-        Ok(Self::from_bytes_unchecked(data))
+        get_extension_from_account_info_unchecked::<Self>(account_info)
     }
 
-    /// Return a `MetadataPointer` from the given bytes.
+    /// Return a `MetadataPointer` from the given extension bytes.
     ///
     /// # Safety
     ///
     /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
     /// 2. `bytes` contains a valid representation of `MetadataPointer`
     /// 3. The data is properly aligned (though MetadataPointer has alignment of 1)
     /// 4. The bytes represent valid flag values and pubkey data
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::AUTHORITY_START as usize..].as_ptr() as *const MetadataPointer)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const MetadataPointer)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `MetadataPointer` from the given account data, locating it through the account
+    /// TLV entries.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN as usize {
-            Err(ProgramError::InvalidAccountData)?;
-        }
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `MetadataPointer` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Return a mutable `MetadataPointer` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, MetadataPointer>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
     /// Creates a new state
@@ -0,0 +1,85 @@
+use {
+    crate::extension::{
+        consts::ExtensionDiscriminator,
+        mint_close_authority::state::offset_mint_close_authority_initialize as OFFSET,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Initialize the close account authority on a new mint.
+///
+/// Fails if the mint has already been initialized, so must be called before
+/// `InitializeMint`.
+///
+/// The mint must have exactly enough space allocated for the base mint (82
+/// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+/// then space required for this extension, plus any others.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint to initialize.
+///
+/// Data expected by this instruction:
+///   Optional pubkey for the close authority
+pub struct InitializeMintCloseAuthority<'a> {
+    /// The mint to initialize the close authority
+    pub mint: &'a AccountInfo,
+    /// The public key for the account that can close the mint
+    pub close_authority: Option<&'a Pubkey>,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl InitializeMintCloseAuthority<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [AccountMeta::writable(self.mint.key())];
+
+        let mut buffer = [0u8; OFFSET::END as usize];
+        let data = initialize_instruction_data(&mut buffer, self.close_authority);
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(&instruction, &[self.mint], signers)
+    }
+}
+
+pub fn initialize_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    close_authority: Option<&'a Pubkey>,
+) -> &'a [u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminator
+    buffer[..offset].copy_from_slice(&[ExtensionDiscriminator::MintCloseAuthority as u8]);
+
+    // Set close_authority as COption
+    if let Some(close_authority) = close_authority {
+        buffer[offset] = 1;
+        offset += OFFSET::CLOSE_AUTHORITY_FLAG as usize;
+
+        buffer[offset..offset + OFFSET::CLOSE_AUTHORITY_PUBKEY as usize]
+            .copy_from_slice(close_authority);
+        offset += OFFSET::CLOSE_AUTHORITY_PUBKEY as usize;
+    } else {
+        buffer[offset] = 0;
+        offset += OFFSET::CLOSE_AUTHORITY_FLAG as usize;
+    }
+
+    &buffer[..offset]
+}
@@ -0,0 +1,141 @@
+use {
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{
+            get_extension, get_extension_from_account_info,
+            get_extension_from_account_info_mut, get_extension_from_account_info_unchecked,
+            get_extension_mut, Extension,
+        },
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref, RefMut},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : close authority presence flag (1 byte)
+/// - [2..34]                    : close authority pubkey (optional, 32 bytes)
+pub mod offset_mint_close_authority_initialize {
+    pub const START: u8 = 1;
+    pub const CLOSE_AUTHORITY_FLAG: u8 = 1;
+    pub const CLOSE_AUTHORITY_PUBKEY: u8 = 32;
+    pub const END: u8 = START + CLOSE_AUTHORITY_FLAG + CLOSE_AUTHORITY_PUBKEY;
+}
+
+/// Close authority extension data for mints.
+#[repr(C)]
+pub struct MintCloseAuthority {
+    /// Optional authority to close the mint
+    close_authority: Pubkey,
+}
+
+const _: () = assert!(core::mem::size_of::<MintCloseAuthority>() == 32);
+const _: () = assert!(core::mem::align_of::<MintCloseAuthority>() == 1);
+
+unsafe impl Extension for MintCloseAuthority {
+    const TYPE: ExtensionType = ExtensionType::MintCloseAuthority;
+}
+
+impl MintCloseAuthority {
+    /// The length of the `MintCloseAuthority` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<MintCloseAuthority>();
+
+    /// Return a `MintCloseAuthority` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, MintCloseAuthority>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return a `MintCloseAuthority` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        get_extension_from_account_info_unchecked::<Self>(account_info)
+    }
+
+    /// Return a `MintCloseAuthority` from the given extension bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
+    /// 2. `bytes` contains a valid representation of `MintCloseAuthority`
+    /// 3. The data is properly aligned (though MintCloseAuthority has alignment of 1)
+    /// 4. The bytes represent valid flag values and pubkey data
+    #[inline(always)]
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const MintCloseAuthority)
+    }
+
+    /// Return a `MintCloseAuthority` from the given account data, locating it through the account
+    /// TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `MintCloseAuthority` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
+
+    /// Return a mutable `MintCloseAuthority` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, MintCloseAuthority>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
+    }
+
+    /// Creates a new state
+    pub fn new(close_authority: Option<&Pubkey>) -> Self {
+        Self {
+            close_authority: close_authority.copied().unwrap_or_default(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn has_close_authority(&self) -> bool {
+        self.close_authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn close_authority(&self) -> Option<&Pubkey> {
+        if self.has_close_authority() {
+            Some(&self.close_authority)
+        } else {
+            None
+        }
+    }
+
+    /// Return the close authority.
+    ///
+    /// This method should be used when the caller knows that the mint will have a close
+    /// authority set since it skips the `Option` check.
+    #[inline(always)]
+    pub fn close_authority_unchecked(&self) -> &Pubkey {
+        &self.close_authority
+    }
+}
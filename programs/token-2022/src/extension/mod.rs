@@ -1,13 +1,24 @@
+pub mod confidential_transfer;
 pub mod consts;
 pub mod cpi_guard;
 pub mod group_member_pointer;
 pub mod group_pointer;
+pub mod immutable_owner;
 pub mod memo_transfer;
 pub mod metadata_pointer;
+pub mod mint_close_authority;
+pub mod non_transferable;
 pub mod permanent_delegate;
+pub mod tlv;
 pub mod token_group;
+pub mod token_metadata;
+pub mod transfer_fee;
 pub mod default_account_state;
 pub mod pausable;
 pub mod scaled_ui_amount;
 pub mod transfer_hook;
+pub mod transfer_hook_interface;
+pub mod transfer_preflight;
 pub mod interest_bearing_mint;
+
+mod ui_amount;
@@ -0,0 +1,53 @@
+use {
+    crate::extension::{
+        consts::ExtensionDiscriminator,
+        non_transferable::state::offset_non_transferable_initialize as OFFSET,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Initialize the non transferable extension for the given mint account.
+///
+/// Fails if the account has already been initialized, so must be called
+/// before `InitializeMint`.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]`  The mint account to initialize.
+///
+/// Data expected by this instruction:
+///   None
+pub struct InitializeNonTransferableMint<'a> {
+    /// The mint to initialize as non transferable
+    pub mint: &'a AccountInfo,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl InitializeNonTransferableMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [AccountMeta::writable(self.mint.key())];
+
+        let data = &[ExtensionDiscriminator::NonTransferable as u8; OFFSET::END as usize];
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(&instruction, &[self.mint], signers)
+    }
+}
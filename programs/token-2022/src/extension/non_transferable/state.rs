@@ -0,0 +1,98 @@
+use {
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{get_extension, get_extension_from_account_info, Extension},
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref},
+        program_error::ProgramError,
+    },
+};
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+pub mod offset_non_transferable_initialize {
+    pub const START: u8 = 1;
+    pub const END: u8 = START;
+}
+
+/// Indicates that the tokens from this mint can't be transferred.
+///
+/// This is a marker extension without data: its presence in the mint TLV
+/// entries is all that matters.
+#[repr(C)]
+pub struct NonTransferable;
+
+const _: () = assert!(core::mem::size_of::<NonTransferable>() == 0);
+const _: () = assert!(core::mem::align_of::<NonTransferable>() == 1);
+
+unsafe impl Extension for NonTransferable {
+    const TYPE: ExtensionType = ExtensionType::NonTransferable;
+}
+
+impl NonTransferable {
+    /// Return a `NonTransferable` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, NonTransferable>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return a `NonTransferable` from the given account data, locating it through the account
+    /// TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return `true` if the given mint data contains the `NonTransferable` extension.
+    #[inline]
+    pub fn is_present(bytes: &[u8]) -> bool {
+        Self::from_bytes(bytes).is_ok()
+    }
+}
+
+/// Indicates that the tokens from this account belong to a non-transferable mint.
+///
+/// This is a marker extension without data, added to token accounts of a
+/// `NonTransferable` mint.
+#[repr(C)]
+pub struct NonTransferableAccount;
+
+const _: () = assert!(core::mem::size_of::<NonTransferableAccount>() == 0);
+const _: () = assert!(core::mem::align_of::<NonTransferableAccount>() == 1);
+
+unsafe impl Extension for NonTransferableAccount {
+    const TYPE: ExtensionType = ExtensionType::NonTransferableAccount;
+}
+
+impl NonTransferableAccount {
+    /// Return a `NonTransferableAccount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, NonTransferableAccount>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return a `NonTransferableAccount` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return `true` if the given token account data contains the `NonTransferableAccount`
+    /// extension.
+    #[inline]
+    pub fn is_present(bytes: &[u8]) -> bool {
+        Self::from_bytes(bytes).is_ok()
+    }
+}
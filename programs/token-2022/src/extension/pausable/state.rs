@@ -1,8 +1,12 @@
 use core::mem::MaybeUninit;
-use crate::{write_bytes, UNINIT_BYTE, ID};
-use crate::extension::consts::ExtensionDiscriminator;
+use crate::{write_bytes, UNINIT_BYTE};
+use crate::extension::consts::{ExtensionDiscriminator, ExtensionType};
+use crate::extension::tlv::{
+    get_extension, get_extension_from_account_info, get_extension_from_account_info_mut,
+    get_extension_mut, Extension,
+};
 use pinocchio::{
-    account_info::{AccountInfo, Ref},
+    account_info::{AccountInfo, Ref, RefMut},
     program_error::ProgramError,
     pubkey::Pubkey,
 };
@@ -24,44 +28,55 @@ pub struct PausableConfig {
     paused: u8,
 }
 
-impl PausableConfig {
-    pub const AUTHORITY_START: usize = 170;
+const _: () = assert!(core::mem::size_of::<PausableConfig>() == 33);
+const _: () = assert!(core::mem::align_of::<PausableConfig>() == 1);
 
-    pub const BASE_LEN: usize = core::mem::size_of::<PausableConfig>();
+unsafe impl Extension for PausableConfig {
+    const TYPE: ExtensionType = ExtensionType::Pausable;
+}
 
-    pub const LEN: usize = Self::AUTHORITY_START + Self::BASE_LEN;
+impl PausableConfig {
+    pub const BASE_LEN: usize = core::mem::size_of::<PausableConfig>();
 
-    /// Return a `PausableConfig` from the given bytes (unsafe, unchecked).
+    /// Return a `PausableConfig` from the given extension bytes (unsafe, unchecked).
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::AUTHORITY_START as usize..].as_ptr() as *const PausableConfig)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const PausableConfig)
     }
 
-    /// Safe version that validates lengths
+    /// Safe version that locates the extension through the account TLV entries
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `PausableConfig` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
+
+    /// Return a mutable `PausableConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, PausableConfig>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
-    /// Like your old from_account_info method
+    /// Return a `PausableConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
-    ) -> Result<Self, ProgramError> {
-        let data = account_info.try_borrow_data()?;
-        if data.len() < Self::LEN as usize {
-            return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
-        }
-        
-        // SAFETY: We've validated the length above
-        let config = unsafe {
-            core::ptr::read((data.as_ptr().add(Self::AUTHORITY_START as usize)) as *const Self)
-        };
-        
-        Ok(config)
+    ) -> Result<Ref<'_, PausableConfig>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
     
     /// Get the authority
@@ -77,6 +92,47 @@ impl PausableConfig {
     }
 }
 
+/// Indicates that the tokens from this account belong to a pausable mint.
+///
+/// This is a marker extension without data, added to token accounts of a
+/// `Pausable` mint.
+#[repr(C)]
+pub struct PausableAccount;
+
+const _: () = assert!(core::mem::size_of::<PausableAccount>() == 0);
+const _: () = assert!(core::mem::align_of::<PausableAccount>() == 1);
+
+unsafe impl Extension for PausableAccount {
+    const TYPE: ExtensionType = ExtensionType::PausableAccount;
+}
+
+impl PausableAccount {
+    /// Return a `PausableAccount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, PausableAccount>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return a `PausableAccount` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return `true` if the given token account data contains the `PausableAccount`
+    /// extension.
+    #[inline]
+    pub fn is_present(bytes: &[u8]) -> bool {
+        Self::from_bytes(bytes).is_ok()
+    }
+}
+
 
 pub fn pausable_instruction_data(
     instruction_type: PausableInstruction,
@@ -1,7 +1,18 @@
 use core::mem::MaybeUninit;
 use crate::{write_bytes, UNINIT_BYTE};
-use crate::extension::consts::ExtensionDiscriminator;
-use pinocchio::pubkey::Pubkey;
+use crate::extension::consts::{ExtensionDiscriminator, ExtensionType};
+use crate::extension::tlv::{
+    get_extension, get_extension_from_account_info, get_extension_from_account_info_unchecked,
+    Extension,
+};
+use crate::extension::ui_amount::{
+    format_ui_amount, parse_ui_amount, pow10, trunc, try_into_amount,
+};
+use pinocchio::{
+    account_info::{AccountInfo, Ref},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -15,68 +26,156 @@ pub enum ScaledUiAmountInstruction {
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ScaledUiAmountConfig {
     /// Authority that can set the scaling amount and authority
-    pub authority: Pubkey,
+    authority: Pubkey,
     /// Amount to multiply raw amounts by, outside of the decimal
-    pub multiplier: f64,
+    multiplier: [u8; 8],
     /// Unix timestamp at which `new_multiplier` comes into effective
-    pub new_multiplier_effective_timestamp: i64,
+    new_multiplier_effective_timestamp: [u8; 8],
     /// Next multiplier, once `new_multiplier_effective_timestamp` is reached
-    pub new_multiplier: f64,
+    new_multiplier: [u8; 8],
 }
 
-impl ScaledUiAmountConfig {    
-    /// The index where authority address starts in the mint with `ScaledUiAmount` extension data
-    pub const AUTHORITY_START: usize = 170;
+const _: () = assert!(core::mem::size_of::<ScaledUiAmountConfig>() == 56);
+const _: () = assert!(core::mem::align_of::<ScaledUiAmountConfig>() == 1);
+
+unsafe impl Extension for ScaledUiAmountConfig {
+    const TYPE: ExtensionType = ExtensionType::ScaledUiAmount;
+}
 
+impl ScaledUiAmountConfig {
     /// The length of the `ScaledUiAmount` extension data.
     pub const BASE_LEN: usize = core::mem::size_of::<ScaledUiAmountConfig>();
 
-    /// The length of the mint with `ScaledUiAmount` extension data
-    pub const LEN: usize = Self::AUTHORITY_START + Self::BASE_LEN;
+    /// Return a `ScaledUiAmountConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, ScaledUiAmountConfig>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
 
     /// Return a `ScaledUiAmountConfig` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
-    pub fn from_account_info(account_info: &pinocchio::account_info::AccountInfo) -> Result<Self, pinocchio::program_error::ProgramError> {
-        let data = account_info.try_borrow_data()?;
-        if data.len() < Self::LEN as usize {
-            return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
-        }
-        
-        // SAFETY: We've validated the length above
-        let config = unsafe {
-            core::ptr::read((data.as_ptr().add(Self::AUTHORITY_START as usize)) as *const Self)
-        };
-        
-        Ok(config)
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        get_extension_from_account_info_unchecked::<Self>(account_info)
     }
-    
+
+    /// Return a `ScaledUiAmountConfig` from the given extension bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains at least `BASE_LEN` bytes.
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::AUTHORITY_START as usize..].as_ptr() as *const ScaledUiAmountConfig)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const ScaledUiAmountConfig)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `ScaledUiAmountConfig` from the given account data, locating it through
+    /// the account TLV entries.
     #[inline]
-    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, pinocchio::program_error::ProgramError> {
-        if bytes.len() < Self::LEN as usize {
-            Err(pinocchio::program_error::ProgramError::InvalidAccountData)?;
-        }
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Creates a new state
+    pub fn new(
+        authority: &Pubkey,
+        multiplier: f64,
+        new_multiplier_effective_timestamp: i64,
+        new_multiplier: f64,
+    ) -> Self {
+        Self {
+            authority: *authority,
+            multiplier: multiplier.to_le_bytes(),
+            new_multiplier_effective_timestamp: new_multiplier_effective_timestamp.to_le_bytes(),
+            new_multiplier: new_multiplier.to_le_bytes(),
+        }
     }
 
     /// Get the authority
-    #[inline]
+    #[inline(always)]
     pub fn authority(&self) -> &Pubkey {
         &self.authority
     }
 
     /// Get the multiplier
-    #[inline]
+    #[inline(always)]
     pub fn multiplier(&self) -> f64 {
-        self.multiplier
+        f64::from_le_bytes(self.multiplier)
+    }
+
+    /// Get the unix timestamp at which `new_multiplier` comes into effect
+    #[inline(always)]
+    pub fn new_multiplier_effective_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.new_multiplier_effective_timestamp)
+    }
+
+    /// Get the next multiplier
+    #[inline(always)]
+    pub fn new_multiplier(&self) -> f64 {
+        f64::from_le_bytes(self.new_multiplier)
+    }
+
+    /// Get the multiplier in effect at `unix_timestamp`, switching to
+    /// `new_multiplier` once `new_multiplier_effective_timestamp` is reached.
+    #[inline]
+    pub fn current_multiplier(&self, unix_timestamp: i64) -> f64 {
+        if unix_timestamp >= self.new_multiplier_effective_timestamp() {
+            self.new_multiplier()
+        } else {
+            self.multiplier()
+        }
+    }
+
+    fn total_multiplier(&self, decimals: u8, unix_timestamp: i64) -> f64 {
+        self.current_multiplier(unix_timestamp) / pow10(decimals)
+    }
+
+    /// Convert a raw amount to its UI representation at `unix_timestamp`
+    /// (usually `Clock::unix_timestamp`).
+    ///
+    /// The scaled amount is truncated towards 0 and written into `buffer` with
+    /// excess zeroes and an unneeded decimal point trimmed. Returns `None` if the
+    /// buffer is too small.
+    pub fn amount_to_ui_amount<'b>(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: i64,
+        buffer: &'b mut [u8],
+    ) -> Option<&'b str> {
+        let scaled_amount = (amount as f64) * self.current_multiplier(unix_timestamp);
+        let truncated_amount = trunc(scaled_amount) / pow10(decimals);
+        format_ui_amount(truncated_amount, decimals, buffer)
+    }
+
+    /// Convert a UI representation of a token amount at `unix_timestamp` to its
+    /// raw amount.
+    ///
+    /// The raw amount is truncated towards 0.
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Result<u64, ProgramError> {
+        let scaled_amount = parse_ui_amount(ui_amount)?;
+        let amount = scaled_amount / self.total_multiplier(decimals, unix_timestamp);
+        // truncating before the range check would turn large values into `inf`
+        Ok(trunc(try_into_amount(amount)?) as u64)
     }
 }
 
@@ -0,0 +1,482 @@
+use {
+    crate::{
+        extension::consts::{AccountType, ExtensionType},
+        state::Multisig,
+        ID,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref, RefMut},
+        program_error::ProgramError,
+    },
+};
+
+/// Length of a base token account. Mints carrying extensions are padded up to
+/// this length so both account kinds share the same TLV layout.
+pub const BASE_ACCOUNT_LEN: usize = 165;
+
+/// Index of the account type byte in an account with extensions.
+pub const ACCOUNT_TYPE_INDEX: usize = BASE_ACCOUNT_LEN;
+
+/// Index where the first TLV entry starts in an account with extensions.
+pub const TLV_START: usize = ACCOUNT_TYPE_INDEX + 1;
+
+/// Length of a TLV entry header:
+/// - [0..2]                     : extension type (2 bytes, u16)
+/// - [2..4]                     : value length (2 bytes, u16)
+pub const TLV_HEADER_LEN: usize = 4;
+
+/// Extension state stored as a TLV entry on a mint or token account.
+///
+/// # Safety
+///
+/// TLV values are neither aligned nor validated, so implementors must have an
+/// alignment of 1 and be valid for any bit pattern of `size_of::<Self>()` bytes
+/// (e.g., no `bool` or enum fields).
+pub unsafe trait Extension {
+    /// The extension type identifying the TLV entry.
+    const TYPE: ExtensionType;
+}
+
+/// A single TLV entry borrowed from the account data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TlvEntry<'a> {
+    /// Raw extension type of the entry.
+    pub extension_type: u16,
+    /// Value bytes of the entry.
+    pub value: &'a [u8],
+}
+
+/// Zero-copy iterator over the TLV entries of a mint or token account.
+///
+/// Iteration stops at the first `Uninitialized` entry or at the end of the
+/// data; a truncated entry yields a single `InvalidAccountData` error.
+pub struct TlvIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> TlvIter<'a> {
+    /// Creates an iterator over the TLV entries of the given account data.
+    ///
+    /// The data is expected to start with the base `Mint` or `TokenAccount`; accounts
+    /// without extensions yield no entries.
+    #[inline]
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data: data.get(TLV_START..).unwrap_or(&[]),
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for TlvIter<'a> {
+    type Item = Result<TlvEntry<'a>, ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.data.get(self.offset..)?;
+
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let extension_type = match remaining {
+            [a, b, ..] => u16::from_le_bytes([*a, *b]),
+            _ => {
+                self.offset = self.data.len();
+                return Some(Err(ProgramError::InvalidAccountData));
+            }
+        };
+
+        if extension_type == ExtensionType::Uninitialized as u16 {
+            self.offset = self.data.len();
+            return None;
+        }
+
+        let value = match remaining {
+            [_, _, a, b, value @ ..] => value.get(..u16::from_le_bytes([*a, *b]) as usize),
+            _ => None,
+        };
+
+        match value {
+            Some(value) => {
+                self.offset += TLV_HEADER_LEN + value.len();
+                Some(Ok(TlvEntry {
+                    extension_type,
+                    value,
+                }))
+            }
+            None => {
+                self.offset = self.data.len();
+                Some(Err(ProgramError::InvalidAccountData))
+            }
+        }
+    }
+}
+
+/// Validate the length and account type of the given account data.
+///
+/// Accounts of exactly `base_len` bytes carry no extensions. Longer accounts must
+/// extend past the base token account length, must not have the length of a
+/// `Multisig` and their account type byte must match `account_type`. An
+/// `Uninitialized` account type byte is accepted as long as the extensions
+/// already present belong to `account_type`, since the byte is only written
+/// when the account itself is initialized.
+#[inline]
+pub fn check_account_type(
+    data: &[u8],
+    base_len: usize,
+    account_type: AccountType,
+) -> Result<(), ProgramError> {
+    if data.len() == base_len {
+        return Ok(());
+    }
+
+    if data.len() < base_len || data.len() <= BASE_ACCOUNT_LEN || data.len() == Multisig::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match AccountType::try_from(data[ACCOUNT_TYPE_INDEX])? {
+        t if t == account_type => Ok(()),
+        AccountType::Uninitialized => match TlvIter::new(data).next() {
+            Some(entry) => {
+                if ExtensionType::try_from(entry?.extension_type)?.account_type() == account_type {
+                    Ok(())
+                } else {
+                    Err(ProgramError::InvalidAccountData)
+                }
+            }
+            None => Ok(()),
+        },
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Return the value bytes of the TLV entry with the given extension type.
+///
+/// The account type byte of the data must either match the account type of the
+/// extension or be `Uninitialized`.
+#[inline]
+pub fn get_extension_bytes(
+    data: &[u8],
+    extension_type: ExtensionType,
+) -> Result<&[u8], ProgramError> {
+    if data.len() <= ACCOUNT_TYPE_INDEX {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    find_extension_bytes(data, extension_type)?.ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Return the value bytes of the TLV entry with the given extension type, or
+/// `None` if the account does not carry it.
+///
+/// Accounts without extensions yield `None`. Otherwise the data must not have the
+/// length of a `Multisig` and its account type byte must either match the account
+/// type of the extension or be `Uninitialized`.
+#[inline]
+pub fn find_extension_bytes(
+    data: &[u8],
+    extension_type: ExtensionType,
+) -> Result<Option<&[u8]>, ProgramError> {
+    match data.get(ACCOUNT_TYPE_INDEX).copied() {
+        None => return Ok(None),
+        Some(_) if data.len() == Multisig::LEN => return Err(ProgramError::InvalidAccountData),
+        Some(t)
+            if t == AccountType::Uninitialized as u8
+                || t == extension_type.account_type() as u8 => {}
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    for entry in TlvIter::new(data) {
+        let entry = entry?;
+
+        if entry.extension_type == extension_type as u16 {
+            return Ok(Some(entry.value));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Return the mutable value bytes of the TLV entry with the given extension type.
+///
+/// The account type byte of the data must either match the account type of the
+/// extension or be `Uninitialized`.
+#[inline]
+pub fn get_extension_bytes_mut(
+    data: &mut [u8],
+    extension_type: ExtensionType,
+) -> Result<&mut [u8], ProgramError> {
+    let value = get_extension_bytes(data, extension_type)?;
+    let start = value.as_ptr() as usize - data.as_ptr() as usize;
+    let end = start + value.len();
+
+    Ok(&mut data[start..end])
+}
+
+/// Interpret the given TLV value as a `T` extension.
+///
+/// Fails with `InvalidAccountData` if the value does not have the length of `T`.
+#[inline(always)]
+fn value_as<T: Extension>(value: &[u8]) -> Result<&T, ProgramError> {
+    const { assert!(core::mem::align_of::<T>() == 1) };
+
+    if value.len() != core::mem::size_of::<T>() {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    // SAFETY: the value has the length of `T`, which has an alignment of 1 and is
+    // valid for any bit pattern as required by `Extension`.
+    Ok(unsafe { &*(value.as_ptr() as *const T) })
+}
+
+/// Interpret the given mutable TLV value as a `T` extension.
+///
+/// Fails with `InvalidAccountData` if the value does not have the length of `T`.
+#[inline(always)]
+fn value_as_mut<T: Extension>(value: &mut [u8]) -> Result<&mut T, ProgramError> {
+    const { assert!(core::mem::align_of::<T>() == 1) };
+
+    if value.len() != core::mem::size_of::<T>() {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    // SAFETY: the value has the length of `T`, which has an alignment of 1 and is
+    // valid for any bit pattern as required by `Extension`.
+    Ok(unsafe { &mut *(value.as_mut_ptr() as *mut T) })
+}
+
+/// Return a `T` extension from the given account data.
+///
+/// The data is expected to start with the base `Mint` or `TokenAccount`.
+#[inline]
+pub fn get_extension<T: Extension>(data: &[u8]) -> Result<&T, ProgramError> {
+    value_as(get_extension_bytes(data, T::TYPE)?)
+}
+
+/// Return a `T` extension from the given account data, or `None` if the account
+/// does not carry it.
+///
+/// The data is expected to start with the base `Mint` or `TokenAccount`.
+#[inline]
+pub fn find_extension<T: Extension>(data: &[u8]) -> Result<Option<&T>, ProgramError> {
+    find_extension_bytes(data, T::TYPE)?
+        .map(value_as)
+        .transpose()
+}
+
+/// Return a mutable `T` extension from the given account data.
+///
+/// The data is expected to start with the base `Mint` or `TokenAccount`.
+#[inline]
+pub fn get_extension_mut<T: Extension>(data: &mut [u8]) -> Result<&mut T, ProgramError> {
+    value_as_mut(get_extension_bytes_mut(data, T::TYPE)?)
+}
+
+/// Return a `T` extension from the given account info.
+///
+/// This method performs owner validation on `AccountInfo`, safe borrowing the
+/// account data.
+#[inline]
+pub fn get_extension_from_account_info<T: Extension>(
+    account_info: &AccountInfo,
+) -> Result<Ref<'_, T>, ProgramError> {
+    if !account_info.is_owned_by(&ID) {
+        Err(ProgramError::InvalidAccountOwner)?;
+    }
+
+    Ref::try_map(account_info.try_borrow_data()?, get_extension::<T>).map_err(|(_, e)| e)
+}
+
+/// Return a `T` extension from the given account info.
+///
+/// This method performs owner validation on `AccountInfo`, but does not perform
+/// the borrow check.
+///
+/// # Safety
+///
+/// The caller must ensure that it is safe to borrow the account data (e.g., there are
+/// no mutable borrows of the account data).
+#[inline]
+pub unsafe fn get_extension_from_account_info_unchecked<T: Extension>(
+    account_info: &AccountInfo,
+) -> Result<&T, ProgramError> {
+    if account_info.owner() != &ID {
+        Err(ProgramError::InvalidAccountOwner)?;
+    }
+
+    get_extension::<T>(account_info.borrow_data_unchecked())
+}
+
+/// Return a mutable `T` extension from the given account info.
+///
+/// This method performs owner validation on `AccountInfo`, safe mutably borrowing
+/// the account data.
+#[inline]
+pub fn get_extension_from_account_info_mut<T: Extension>(
+    account_info: &AccountInfo,
+) -> Result<RefMut<'_, T>, ProgramError> {
+    if !account_info.is_owned_by(&ID) {
+        Err(ProgramError::InvalidAccountOwner)?;
+    }
+
+    RefMut::try_map(account_info.try_borrow_mut_data()?, get_extension_mut::<T>).map_err(|(_, e)| e)
+}
+
+/// Return the account data length required by an account with the given base length
+/// and extensions.
+///
+/// Accounts without extensions only need their base length. Otherwise the data holds
+/// the base token account length, the account type byte and one TLV entry per distinct
+/// extension, with an extra padding entry header when that total would collide with
+/// the length of a `Multisig`. Fails with `InvalidArgument` if any of the extensions
+/// has a variable length.
+pub const fn get_account_len(
+    base_len: usize,
+    extension_types: &[ExtensionType],
+) -> Result<usize, ProgramError> {
+    if extension_types.is_empty() {
+        return Ok(base_len);
+    }
+
+    let mut account_len = TLV_START;
+    let mut i = 0;
+
+    while i < extension_types.len() {
+        let extension_type = extension_types[i] as u16;
+
+        // skip duplicated extensions, only the first occurrence takes space
+        let mut duplicated = false;
+        let mut j = 0;
+
+        while j < i {
+            if extension_types[j] as u16 == extension_type {
+                duplicated = true;
+                break;
+            }
+            j += 1;
+        }
+
+        if !duplicated {
+            match extension_types[i].try_get_tlv_len() {
+                Ok(tlv_len) => account_len += tlv_len,
+                Err(e) => return Err(e),
+            }
+        }
+
+        i += 1;
+    }
+
+    if account_len == Multisig::LEN {
+        account_len += core::mem::size_of::<ExtensionType>();
+    }
+
+    Ok(account_len)
+}
+
+/// Return the length of a TLV entry holding a `T` extension, including its header.
+#[inline(always)]
+pub const fn get_tlv_len<T: Extension>() -> usize {
+    TLV_HEADER_LEN + core::mem::size_of::<T>()
+}
+
+/// Return the length of the account data used by the base state, the account type
+/// byte and the TLV entries.
+///
+/// Accounts without extensions only use their base length, which is the full length
+/// of the data.
+#[inline]
+pub fn get_used_len(data: &[u8]) -> Result<usize, ProgramError> {
+    if data.len() <= BASE_ACCOUNT_LEN {
+        return Ok(data.len());
+    }
+
+    let mut used_len = TLV_START;
+
+    for entry in TlvIter::new(data) {
+        used_len += TLV_HEADER_LEN + entry?.value.len();
+    }
+
+    Ok(used_len)
+}
+
+/// Set the account type byte of the given account data.
+///
+/// The data must be long enough to carry extensions.
+#[inline]
+pub fn set_account_type(data: &mut [u8], account_type: AccountType) -> Result<(), ProgramError> {
+    if data.len() <= BASE_ACCOUNT_LEN || data.len() == Multisig::LEN {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    data[ACCOUNT_TYPE_INDEX] = account_type as u8;
+
+    Ok(())
+}
+
+/// Append a TLV entry of `length` zeroed bytes with the given extension type,
+/// returning its mutable value bytes.
+///
+/// The entry is written after the last TLV entry, so the remaining data is expected
+/// to be zeroed. Fails with `InvalidAccountData` if the extension is already present
+/// and with `AccountDataTooSmall` if the data can not fit the new entry.
+pub fn init_extension_bytes(
+    data: &mut [u8],
+    extension_type: ExtensionType,
+    length: u16,
+) -> Result<&mut [u8], ProgramError> {
+    if extension_type == ExtensionType::Uninitialized {
+        Err(ProgramError::InvalidArgument)?;
+    }
+
+    match data.get(ACCOUNT_TYPE_INDEX).copied() {
+        Some(t)
+            if t == AccountType::Uninitialized as u8
+                || t == extension_type.account_type() as u8 => {}
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    if data.len() == Multisig::LEN {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    let mut start = TLV_START;
+
+    for entry in TlvIter::new(data) {
+        let entry = entry?;
+
+        if entry.extension_type == extension_type as u16 {
+            Err(ProgramError::InvalidAccountData)?;
+        }
+
+        start += TLV_HEADER_LEN + entry.value.len();
+    }
+
+    let value_start = start + TLV_HEADER_LEN;
+    let end = value_start + length as usize;
+
+    if end > data.len() {
+        Err(ProgramError::AccountDataTooSmall)?;
+    }
+
+    data[start..start + 2].copy_from_slice(&(extension_type as u16).to_le_bytes());
+    data[start + 2..value_start].copy_from_slice(&length.to_le_bytes());
+
+    let value = &mut data[value_start..end];
+    value.fill(0);
+
+    Ok(value)
+}
+
+/// Append a zeroed `T` extension to the given account data, returning a mutable
+/// reference to it.
+///
+/// See [`init_extension_bytes`] for the requirements on the data.
+#[inline]
+pub fn init_extension<T: Extension>(data: &mut [u8]) -> Result<&mut T, ProgramError> {
+    value_as_mut(init_extension_bytes(
+        data,
+        T::TYPE,
+        core::mem::size_of::<T>() as u16,
+    )?)
+}
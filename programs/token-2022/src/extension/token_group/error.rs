@@ -0,0 +1,63 @@
+use pinocchio::program_error::{ProgramError, ToStr};
+
+/// Errors that may be returned by the token group interface.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenGroupError {
+    /// Size is greater than proposed max size
+    SizeExceedsNewMaxSize = 3_406_457_176,
+    /// Size is greater than max size
+    SizeExceedsMaxSize,
+    /// Group is immutable
+    ImmutableGroup,
+    /// Incorrect mint authority has signed the instruction
+    IncorrectMintAuthority,
+    /// Incorrect update authority has signed the instruction
+    IncorrectUpdateAuthority,
+    /// Member account should not be the same as the group account
+    MemberAccountIsGroupAccount,
+}
+
+impl From<TokenGroupError> for ProgramError {
+    fn from(e: TokenGroupError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+impl TryFrom<u32> for TokenGroupError {
+    type Error = ProgramError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            3_406_457_176 => Ok(TokenGroupError::SizeExceedsNewMaxSize),
+            3_406_457_177 => Ok(TokenGroupError::SizeExceedsMaxSize),
+            3_406_457_178 => Ok(TokenGroupError::ImmutableGroup),
+            3_406_457_179 => Ok(TokenGroupError::IncorrectMintAuthority),
+            3_406_457_180 => Ok(TokenGroupError::IncorrectUpdateAuthority),
+            3_406_457_181 => Ok(TokenGroupError::MemberAccountIsGroupAccount),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+impl ToStr for TokenGroupError {
+    fn to_str<E>(&self) -> &'static str
+    where
+        E: 'static + ToStr + TryFrom<u32>,
+    {
+        match self {
+            TokenGroupError::SizeExceedsNewMaxSize => "Size is greater than proposed max size",
+            TokenGroupError::SizeExceedsMaxSize => "Size is greater than max size",
+            TokenGroupError::ImmutableGroup => "Group is immutable",
+            TokenGroupError::IncorrectMintAuthority => {
+                "Incorrect mint authority has signed the instruction"
+            }
+            TokenGroupError::IncorrectUpdateAuthority => {
+                "Incorrect update authority has signed the instruction"
+            }
+            TokenGroupError::MemberAccountIsGroupAccount => {
+                "Member account should not be the same as the group account"
+            }
+        }
+    }
+}
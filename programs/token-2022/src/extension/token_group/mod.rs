@@ -1,5 +1,7 @@
+pub mod error;
 pub mod instructions;
 pub mod state;
 
+pub use error::*;
 pub use instructions::*;
 pub use state::*;
@@ -1,13 +1,23 @@
 use {
-    crate::ID,
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{
+            get_extension, get_extension_from_account_info,
+            get_extension_from_account_info_mut, get_extension_from_account_info_unchecked,
+            get_extension_mut, Extension,
+        },
+    },
     core::mem,
     pinocchio::{
-        account_info::{AccountInfo, Ref},
+        account_info::{AccountInfo, Ref, RefMut},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
 };
 
+// kept at its previous path
+pub use super::error::TokenGroupError;
+
 #[repr(u64)]
 pub enum InstructionDiscriminatorTokenGroup {
     InitializeGroup = 288286683834380665, // [121, 113, 108, 39, 54, 51, 0, 4]
@@ -61,51 +71,37 @@ pub struct TokenGroup {
     /// belongs to a particular mint
     mint: Pubkey,
     /// The current number of group members
-    size: u64,
+    size: [u8; 8],
     /// The maximum number of group members
-    max_size: u64,
+    max_size: [u8; 8],
 }
 
-impl TokenGroup {
-    /// The length of the account with `TokenGroup` data
-    const LEN: usize = Self::DATA_START + Self::BASE_LEN;
+const _: () = assert!(mem::size_of::<TokenGroup>() == 80);
+const _: () = assert!(mem::align_of::<TokenGroup>() == 1);
 
-    /// The index where TokenGroup data starts
-    const DATA_START: usize = 238;
+unsafe impl Extension for TokenGroup {
+    const TYPE: ExtensionType = ExtensionType::TokenGroup;
+}
 
+impl TokenGroup {
     /// The length of the `TokenGroup` data.
     pub const BASE_LEN: usize = mem::size_of::<TokenGroup>();
 
     /// Return a `TokenGroup` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
-    pub fn from_account_info(account_info: &AccountInfo) -> Result<Ref<TokenGroup>, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Safely borrow and map the data
-        let data_ref = account_info
-            .try_borrow_data()
-            .map_err(|_| ProgramError::AccountBorrowFailed)?;
-
-        Ok(Ref::map(data_ref, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, TokenGroup>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
 
     /// Return a `TokenGroup` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -115,43 +111,44 @@ impl TokenGroup {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-
-        Ok(Self::from_bytes_unchecked(data))
+        get_extension_from_account_info_unchecked::<Self>(account_info)
     }
 
-    /// Return a `TokenGroup` from the given bytes.
+    /// Return a `TokenGroup` from the given extension bytes.
     ///
     /// # Safety
     ///
     /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
     /// 2. `bytes` contains a valid representation of `TokenGroup`
-    /// 3. The data is properly aligned
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::DATA_START..].as_ptr() as *const TokenGroup)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TokenGroup)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `TokenGroup` from the given account data, locating it through the account
+    /// TLV entries.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `TokenGroup` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Return a mutable `TokenGroup` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, TokenGroup>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
     /// Creates a new `TokenGroup` state
@@ -159,8 +156,8 @@ impl TokenGroup {
         Self {
             mint: *mint,
             update_authority: update_authority.map(|&x| x).unwrap_or_default(),
-            size: 0,
-            max_size,
+            size: [0; 8],
+            max_size: max_size.to_le_bytes(),
         }
     }
 
@@ -194,21 +191,21 @@ impl TokenGroup {
 
     #[inline(always)]
     pub fn size(&self) -> u64 {
-        self.size
+        u64::from_le_bytes(self.size)
     }
 
     #[inline(always)]
     pub fn max_size(&self) -> u64 {
-        self.max_size
+        u64::from_le_bytes(self.max_size)
     }
 
     /// Updates the max size for a group
     pub fn update_max_size(&mut self, new_max_size: u64) -> Result<(), ProgramError> {
         // The new max size cannot be less than the current size
-        if new_max_size < self.size {
+        if new_max_size < self.size() {
             Err(TokenGroupError::SizeExceedsNewMaxSize)?;
         }
-        self.max_size = new_max_size;
+        self.max_size = new_max_size.to_le_bytes();
         Ok(())
     }
 
@@ -216,13 +213,13 @@ impl TokenGroup {
     pub fn increment_size(&mut self) -> Result<u64, ProgramError> {
         // The new size cannot be greater than the max size
         let new_size = self
-            .size
+            .size()
             .checked_add(1)
             .ok_or(ProgramError::ArithmeticOverflow)?;
-        if new_size > self.max_size {
+        if new_size > self.max_size() {
             Err(TokenGroupError::SizeExceedsMaxSize)?;
         }
-        self.size = new_size;
+        self.size = new_size.to_le_bytes();
         Ok(new_size)
     }
 }
@@ -236,51 +233,35 @@ pub struct TokenGroupMember {
     /// The pubkey of the `TokenGroup`
     group: Pubkey,
     /// The member number
-    member_number: u64,
+    member_number: [u8; 8],
 }
 
-impl TokenGroupMember {
-    /// The length of the account with `TokenGroupMember` data
-    const LEN: usize = Self::DATA_START + Self::BASE_LEN;
+const _: () = assert!(mem::size_of::<TokenGroupMember>() == 72);
+const _: () = assert!(mem::align_of::<TokenGroupMember>() == 1);
 
-    /// The index where TokenGroupMember data starts
-    const DATA_START: usize = 238;
+unsafe impl Extension for TokenGroupMember {
+    const TYPE: ExtensionType = ExtensionType::TokenGroupMember;
+}
 
+impl TokenGroupMember {
     /// The length of the `TokenGroupMember` data.
     pub const BASE_LEN: usize = mem::size_of::<TokenGroupMember>();
 
     /// Return a `TokenGroupMember` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
-    ) -> Result<Ref<TokenGroupMember>, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Safely borrow and map the data
-        let data_ref = account_info
-            .try_borrow_data()
-            .map_err(|_| ProgramError::AccountBorrowFailed)?;
-
-        Ok(Ref::map(data_ref, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+    ) -> Result<Ref<'_, TokenGroupMember>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
 
     /// Return a `TokenGroupMember` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -290,43 +271,44 @@ impl TokenGroupMember {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
-        // Check owner
-        if account_info.owner() != &ID {
-            Err(ProgramError::InvalidAccountOwner)?;
-        }
-
-        // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-
-        Ok(Self::from_bytes_unchecked(data))
+        get_extension_from_account_info_unchecked::<Self>(account_info)
     }
 
-    /// Return a `TokenGroupMember` from the given bytes.
+    /// Return a `TokenGroupMember` from the given extension bytes.
     ///
     /// # Safety
     ///
     /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
     /// 2. `bytes` contains a valid representation of `TokenGroupMember`
-    /// 3. The data is properly aligned
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::DATA_START..].as_ptr() as *const TokenGroupMember)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TokenGroupMember)
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `TokenGroupMember` from the given account data, locating it through the account
+    /// TLV entries.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
+        get_extension::<Self>(bytes)
+    }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    /// Return a mutable `TokenGroupMember` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
+
+    /// Return a mutable `TokenGroupMember` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, TokenGroupMember>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
     /// Creates a new `TokenGroupMember` state
@@ -334,7 +316,7 @@ impl TokenGroupMember {
         Self {
             mint: *mint,
             group: *group,
-            member_number,
+            member_number: member_number.to_le_bytes(),
         }
     }
 
@@ -350,52 +332,6 @@ impl TokenGroupMember {
 
     #[inline(always)]
     pub fn member_number(&self) -> u64 {
-        self.member_number
-    }
-}
-
-/// Errors that may be returned by the interface.
-#[repr(u32)]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum TokenGroupError {
-    /// Size is greater than proposed max size
-    SizeExceedsNewMaxSize = 3_406_457_176,
-    /// Size is greater than max size
-    SizeExceedsMaxSize,
-    /// Group is immutable
-    ImmutableGroup,
-    /// Incorrect mint authority has signed the instruction
-    IncorrectMintAuthority,
-    /// Incorrect update authority has signed the instruction
-    IncorrectUpdateAuthority,
-    /// Member account should not be the same as the group account
-    MemberAccountIsGroupAccount,
-}
-
-impl From<TokenGroupError> for ProgramError {
-    fn from(e: TokenGroupError) -> Self {
-        ProgramError::Custom(e as u32)
-    }
-}
-
-impl pinocchio::program_error::ToStr for TokenGroupError {
-    fn to_str<E>(&self) -> &'static str
-    where
-        E: 'static + pinocchio::program_error::ToStr + TryFrom<u32>,
-    {
-        match self {
-            TokenGroupError::SizeExceedsNewMaxSize => "Size is greater than proposed max size",
-            TokenGroupError::SizeExceedsMaxSize => "Size is greater than max size",
-            TokenGroupError::ImmutableGroup => "Group is immutable",
-            TokenGroupError::IncorrectMintAuthority => {
-                "Incorrect mint authority has signed the instruction"
-            }
-            TokenGroupError::IncorrectUpdateAuthority => {
-                "Incorrect update authority has signed the instruction"
-            }
-            TokenGroupError::MemberAccountIsGroupAccount => {
-                "Member account should not be the same as the group account"
-            }
-        }
+        u64::from_le_bytes(self.member_number)
     }
 }
@@ -0,0 +1,61 @@
+use pinocchio::program_error::{ProgramError, ToStr};
+
+/// Errors that may be returned by the token metadata interface.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenMetadataError {
+    /// Incorrect account provided
+    IncorrectAccount = 901_952_957,
+    /// Mint has no mint authority
+    MintHasNoMintAuthority,
+    /// Incorrect mint authority has signed the instruction
+    IncorrectMintAuthority,
+    /// Incorrect metadata update authority has signed the instruction
+    IncorrectUpdateAuthority,
+    /// Token metadata has no update authority
+    ImmutableMetadata,
+    /// Key not found in metadata account
+    KeyNotFound,
+}
+
+impl From<TokenMetadataError> for ProgramError {
+    fn from(e: TokenMetadataError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+impl TryFrom<u32> for TokenMetadataError {
+    type Error = ProgramError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            901_952_957 => Ok(TokenMetadataError::IncorrectAccount),
+            901_952_958 => Ok(TokenMetadataError::MintHasNoMintAuthority),
+            901_952_959 => Ok(TokenMetadataError::IncorrectMintAuthority),
+            901_952_960 => Ok(TokenMetadataError::IncorrectUpdateAuthority),
+            901_952_961 => Ok(TokenMetadataError::ImmutableMetadata),
+            901_952_962 => Ok(TokenMetadataError::KeyNotFound),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+impl ToStr for TokenMetadataError {
+    fn to_str<E>(&self) -> &'static str
+    where
+        E: 'static + ToStr + TryFrom<u32>,
+    {
+        match self {
+            TokenMetadataError::IncorrectAccount => "Incorrect account provided",
+            TokenMetadataError::MintHasNoMintAuthority => "Mint has no mint authority",
+            TokenMetadataError::IncorrectMintAuthority => {
+                "Incorrect mint authority has signed the instruction"
+            }
+            TokenMetadataError::IncorrectUpdateAuthority => {
+                "Incorrect metadata update authority has signed the instruction"
+            }
+            TokenMetadataError::ImmutableMetadata => "Token metadata has no update authority",
+            TokenMetadataError::KeyNotFound => "Key not found in metadata account",
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use {
+    crate::extension::token_metadata::state::{
+        offset_token_metadata_emit as OFFSET, InstructionDiscriminatorTokenMetadata,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Emits the token-metadata as return data
+///
+/// The format of the data emitted follows exactly the `TokenMetadata`
+/// struct, sliced by the optional `start` and `end` byte offsets.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[]` Metadata
+pub struct Emit<'a> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Start of range of data to emit
+    pub start: Option<u64>,
+    /// End of range of data to emit
+    pub end: Option<u64>,
+    /// Token Metadata Program
+    pub program_id: &'a Pubkey,
+}
+
+impl Emit<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [AccountMeta::readonly(self.metadata.key())];
+
+        let mut buffer = [0u8; OFFSET::MAX_END as usize];
+        let data = emit_instruction_data(&mut buffer, self.start, self.end);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(&instruction, &[self.metadata], signers)
+    }
+}
+
+pub fn emit_instruction_data(buffer: &mut [u8], start: Option<u64>, end: Option<u64>) -> &[u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset]
+        .copy_from_slice(&(InstructionDiscriminatorTokenMetadata::Emit as u64).to_le_bytes());
+
+    // Set start and end (optional)
+    for value in [start, end] {
+        if let Some(x) = value {
+            buffer[offset] = 1;
+            buffer[offset + 1..offset + OFFSET::MAX_OPTIONAL_U64 as usize]
+                .copy_from_slice(&x.to_le_bytes());
+            offset += OFFSET::MAX_OPTIONAL_U64 as usize;
+        } else {
+            buffer[offset] = 0;
+            offset += 1;
+        }
+    }
+
+    &buffer[..offset]
+}
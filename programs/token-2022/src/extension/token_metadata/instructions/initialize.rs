@@ -0,0 +1,99 @@
+use {
+    super::{write_data, write_str},
+    crate::{
+        extension::token_metadata::state::{
+            InstructionDiscriminatorTokenMetadata, MAX_INSTRUCTION_DATA_LEN,
+        },
+        UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Initializes a TLV entry with the basic token-metadata fields.
+///
+/// Assumes that the provided mint is an SPL token mint, that the metadata
+/// account is allocated and assigned to the program, and that the metadata
+/// account has enough lamports to cover the rent-exempt reserve.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata
+///   1. `[]` Update authority
+///   2. `[]` Mint
+///   3. `[signer]` Mint authority
+pub struct Initialize<'a, 'b> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Update authority
+    pub update_authority: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Mint authority
+    pub mint_authority: &'a AccountInfo,
+    /// Longer name of the token
+    pub name: &'b str,
+    /// Shortened symbol of the token
+    pub symbol: &'b str,
+    /// URI pointing to more metadata (image, video, etc.)
+    pub uri: &'b str,
+    /// Token Metadata Program
+    pub program_id: &'a Pubkey,
+}
+
+impl Initialize<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly(self.update_authority.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly_signer(self.mint_authority.key()),
+        ];
+
+        // Instruction data layout:
+        // - [0..8]: instruction discriminator (8 bytes)
+        // - [..]: name (u32 length + utf-8 bytes)
+        // - [..]: symbol (u32 length + utf-8 bytes)
+        // - [..]: uri (u32 length + utf-8 bytes)
+        let mut instruction_data = [UNINIT_BYTE; MAX_INSTRUCTION_DATA_LEN];
+        let mut offset = 0;
+
+        write_data(
+            &mut instruction_data,
+            &mut offset,
+            &(InstructionDiscriminatorTokenMetadata::Initialize as u64).to_le_bytes(),
+        )?;
+        write_str(&mut instruction_data, &mut offset, self.name)?;
+        write_str(&mut instruction_data, &mut offset, self.symbol)?;
+        write_str(&mut instruction_data, &mut offset, self.uri)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, offset) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.metadata,
+                self.update_authority,
+                self.mint,
+                self.mint_authority,
+            ],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,47 @@
+use {
+    crate::{extension::token_metadata::state::MAX_INSTRUCTION_DATA_LEN, write_bytes},
+    core::mem::MaybeUninit,
+    pinocchio::program_error::ProgramError,
+};
+
+mod emit;
+mod initialize;
+mod remove_key;
+mod update_authority;
+mod update_field;
+
+pub use emit::*;
+pub use initialize::*;
+pub use remove_key::*;
+pub use update_authority::*;
+pub use update_field::*;
+
+/// Write `bytes` into the instruction data at `offset`, advancing it.
+#[inline(always)]
+fn write_data(
+    buffer: &mut [MaybeUninit<u8>; MAX_INSTRUCTION_DATA_LEN],
+    offset: &mut usize,
+    bytes: &[u8],
+) -> Result<(), ProgramError> {
+    let end = *offset + bytes.len();
+
+    if end > MAX_INSTRUCTION_DATA_LEN {
+        Err(ProgramError::InvalidInstructionData)?;
+    }
+
+    write_bytes(&mut buffer[*offset..end], bytes);
+    *offset = end;
+
+    Ok(())
+}
+
+/// Write a borsh-encoded string (u32 length + utf-8 bytes) into the instruction data.
+#[inline(always)]
+fn write_str(
+    buffer: &mut [MaybeUninit<u8>; MAX_INSTRUCTION_DATA_LEN],
+    offset: &mut usize,
+    value: &str,
+) -> Result<(), ProgramError> {
+    write_data(buffer, offset, &(value.len() as u32).to_le_bytes())?;
+    write_data(buffer, offset, value.as_bytes())
+}
@@ -0,0 +1,82 @@
+use {
+    super::{write_data, write_str},
+    crate::{
+        extension::token_metadata::state::{
+            InstructionDiscriminatorTokenMetadata, MAX_INSTRUCTION_DATA_LEN,
+        },
+        UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Removes a key-value pair in a token-metadata account.
+///
+/// This only applies to additional fields, and not the base name / symbol /
+/// URI fields.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata
+///   1. `[signer]` Update authority
+pub struct RemoveKey<'a, 'b> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Update authority
+    pub update_authority: &'a AccountInfo,
+    /// If the idempotent flag is set to true, then the instruction will not
+    /// error if the key does not exist
+    pub idempotent: bool,
+    /// Key to remove in the additional metadata portion
+    pub key: &'b str,
+    /// Token Metadata Program
+    pub program_id: &'a Pubkey,
+}
+
+impl RemoveKey<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly_signer(self.update_authority.key()),
+        ];
+
+        // Instruction data layout:
+        // - [0..8]: instruction discriminator (8 bytes)
+        // - [8]: idempotent (1 byte, bool)
+        // - [..]: key (u32 length + utf-8 bytes)
+        let mut instruction_data = [UNINIT_BYTE; MAX_INSTRUCTION_DATA_LEN];
+        let mut offset = 0;
+
+        write_data(
+            &mut instruction_data,
+            &mut offset,
+            &(InstructionDiscriminatorTokenMetadata::RemoveKey as u64).to_le_bytes(),
+        )?;
+        write_data(&mut instruction_data, &mut offset, &[self.idempotent as u8])?;
+        write_str(&mut instruction_data, &mut offset, self.key)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, offset) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,78 @@
+use {
+    crate::extension::token_metadata::state::{
+        offset_token_metadata_update_authority as OFFSET, InstructionDiscriminatorTokenMetadata,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Updates the token-metadata authority
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata
+///   1. `[signer]` Current update authority
+pub struct UpdateAuthority<'a> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Current update authority
+    pub current_authority: &'a AccountInfo,
+    /// New authority for the metadata, or None to make it immutable
+    pub new_authority: Option<&'a Pubkey>,
+    /// Token Metadata Program
+    pub program_id: &'a Pubkey,
+}
+
+impl UpdateAuthority<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly_signer(self.current_authority.key()),
+        ];
+
+        let mut buffer = [0u8; OFFSET::END as usize];
+        let data = update_authority_instruction_data(&mut buffer, self.new_authority);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.current_authority],
+            signers,
+        )
+    }
+}
+
+pub fn update_authority_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    new_authority: Option<&'a Pubkey>,
+) -> &'a [u8] {
+    let offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset].copy_from_slice(
+        &(InstructionDiscriminatorTokenMetadata::UpdateAuthority as u64).to_le_bytes(),
+    );
+
+    // Set new_authority (optional)
+    if let Some(authority) = new_authority {
+        buffer[offset..offset + OFFSET::NEW_AUTHORITY as usize].copy_from_slice(authority.as_ref());
+    }
+
+    buffer
+}
@@ -0,0 +1,92 @@
+use {
+    super::{write_data, write_str},
+    crate::{
+        extension::token_metadata::state::{
+            Field, InstructionDiscriminatorTokenMetadata, MAX_INSTRUCTION_DATA_LEN,
+        },
+        UNINIT_BYTE,
+    },
+    core::slice,
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Updates a field in a token-metadata account.
+///
+/// The field can be one of the required fields (name, symbol, URI), or a
+/// totally new field denoted by a "key" string.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata
+///   1. `[signer]` Update authority
+pub struct UpdateField<'a, 'b> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Update authority
+    pub update_authority: &'a AccountInfo,
+    /// Field to update in the metadata
+    pub field: Field<'b>,
+    /// Value to write for the field
+    pub value: &'b str,
+    /// Token Metadata Program
+    pub program_id: &'a Pubkey,
+}
+
+impl UpdateField<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly_signer(self.update_authority.key()),
+        ];
+
+        // Instruction data layout:
+        // - [0..8]: instruction discriminator (8 bytes)
+        // - [8]: field variant (1 byte, u8)
+        // - [..]: field key, only for `Field::Key` (u32 length + utf-8 bytes)
+        // - [..]: value (u32 length + utf-8 bytes)
+        let mut instruction_data = [UNINIT_BYTE; MAX_INSTRUCTION_DATA_LEN];
+        let mut offset = 0;
+
+        write_data(
+            &mut instruction_data,
+            &mut offset,
+            &(InstructionDiscriminatorTokenMetadata::UpdateField as u64).to_le_bytes(),
+        )?;
+
+        match self.field {
+            Field::Name => write_data(&mut instruction_data, &mut offset, &[0])?,
+            Field::Symbol => write_data(&mut instruction_data, &mut offset, &[1])?,
+            Field::Uri => write_data(&mut instruction_data, &mut offset, &[2])?,
+            Field::Key(key) => {
+                write_data(&mut instruction_data, &mut offset, &[3])?;
+                write_str(&mut instruction_data, &mut offset, key)?;
+            }
+        }
+
+        write_str(&mut instruction_data, &mut offset, self.value)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, offset) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
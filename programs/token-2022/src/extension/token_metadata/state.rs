@@ -0,0 +1,269 @@
+use {
+    crate::{
+        extension::{consts::ExtensionType, tlv::get_extension_bytes},
+        ID,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+};
+
+#[repr(u64)]
+pub enum InstructionDiscriminatorTokenMetadata {
+    Initialize = 10181997023371125202, // [210, 225, 30, 162, 88, 184, 77, 141]
+    UpdateField = 14473666181958068701, // [221, 233, 49, 45, 181, 202, 220, 200]
+    RemoveKey = 13052994509336482538,  // [234, 18, 32, 56, 89, 141, 37, 181]
+    UpdateAuthority = 8887401230438425815, // [215, 228, 166, 228, 84, 100, 86, 123]
+    Emit = 5095836232549967610,        // [250, 166, 180, 250, 13, 12, 184, 70]
+}
+
+/// Maximum length of the instruction data built by the token metadata CPIs.
+///
+/// Instructions carrying strings (`Initialize`, `UpdateField` and `RemoveKey`)
+/// fail with `InvalidInstructionData` if their serialized data exceeds it.
+pub const MAX_INSTRUCTION_DATA_LEN: usize = 1024;
+
+/// Instruction data layout:
+/// - [0..8]                     : Instruction discriminator (8 bytes)
+/// - [8..40]                    : new_authority pubkey (32 bytes)
+pub mod offset_token_metadata_update_authority {
+    pub const START: u8 = 8;
+    pub const NEW_AUTHORITY: u8 = 32;
+    pub const END: u8 = START + NEW_AUTHORITY;
+}
+
+/// Instruction data layout:
+/// - [0..8]                     : Instruction discriminator (8 bytes)
+/// - [8..17]                    : start (optional, 1 + 8 bytes)
+/// - [..]                       : end (optional, 1 + 8 bytes)
+pub mod offset_token_metadata_emit {
+    pub const START: u8 = 8;
+    pub const MAX_OPTIONAL_U64: u8 = 9;
+    pub const MAX_END: u8 = START + 2 * MAX_OPTIONAL_U64;
+}
+
+/// Fields in the metadata account, used for updating
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Field<'a> {
+    /// The name field, corresponding to `TokenMetadata.name`
+    Name,
+    /// The symbol field, corresponding to `TokenMetadata.symbol`
+    Symbol,
+    /// The uri field, corresponding to `TokenMetadata.uri`
+    Uri,
+    /// A user field, whose key is given by the associated string
+    Key(&'a str),
+}
+
+/// Zero-copy view over a `TokenMetadata` TLV entry.
+///
+/// The entry is borsh-encoded with a variable length:
+/// - [0..32]                    : update_authority pubkey (32 bytes, zero if unset)
+/// - [32..64]                   : mint pubkey (32 bytes)
+/// - [..]                       : name, symbol and uri (u32 length + utf-8 bytes each)
+/// - [..]                       : additional metadata (u32 count + key/value string pairs)
+#[derive(Clone, Copy, Debug)]
+pub struct TokenMetadata<'a> {
+    /// The authority that can sign to update the metadata
+    update_authority: &'a Pubkey,
+    /// The associated mint, used to counter spoofing to be sure that metadata
+    /// belongs to a particular mint
+    mint: &'a Pubkey,
+    /// The longer name of the token
+    name: &'a str,
+    /// The shortened symbol for the token
+    symbol: &'a str,
+    /// The URI pointing to richer metadata
+    uri: &'a str,
+    /// Number of additional key/value pairs
+    additional_metadata_len: u32,
+    /// Serialized additional key/value pairs
+    additional_metadata: &'a [u8],
+}
+
+impl<'a> TokenMetadata<'a> {
+    /// Return a `TokenMetadata` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data) for the lifetime of the returned value.
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &'a AccountInfo,
+    ) -> Result<TokenMetadata<'a>, ProgramError> {
+        if account_info.owner() != &ID {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        Self::from_bytes(account_info.borrow_data_unchecked())
+    }
+
+    /// Return a `TokenMetadata` from the given account data, locating the extension
+    /// through the account TLV entries.
+    #[inline]
+    pub fn from_bytes(data: &'a [u8]) -> Result<TokenMetadata<'a>, ProgramError> {
+        Self::from_extension_bytes(get_extension_bytes(data, ExtensionType::TokenMetadata)?)
+    }
+
+    /// Return a `TokenMetadata` from the value of its TLV entry.
+    ///
+    /// All strings, including the additional key/value pairs, are validated as utf-8.
+    pub fn from_extension_bytes(bytes: &'a [u8]) -> Result<TokenMetadata<'a>, ProgramError> {
+        let mut offset = 0;
+
+        let update_authority = read_pubkey(bytes, &mut offset)?;
+        let mint = read_pubkey(bytes, &mut offset)?;
+        let name = read_str(bytes, &mut offset)?;
+        let symbol = read_str(bytes, &mut offset)?;
+        let uri = read_str(bytes, &mut offset)?;
+        let additional_metadata_len = read_u32(bytes, &mut offset)?;
+
+        let additional_metadata = &bytes[offset..];
+        let mut pairs_offset = 0;
+
+        for _ in 0..additional_metadata_len {
+            read_str(additional_metadata, &mut pairs_offset)?;
+            read_str(additional_metadata, &mut pairs_offset)?;
+        }
+
+        Ok(Self {
+            update_authority,
+            mint,
+            name,
+            symbol,
+            uri,
+            additional_metadata_len,
+            additional_metadata: &additional_metadata[..pairs_offset],
+        })
+    }
+
+    #[inline(always)]
+    pub fn has_update_authority(&self) -> bool {
+        self.update_authority != &Pubkey::default()
+    }
+
+    /// Get the update authority, `None` if the metadata is immutable.
+    #[inline]
+    pub fn update_authority(&self) -> Option<&'a Pubkey> {
+        if self.has_update_authority() {
+            Some(self.update_authority)
+        } else {
+            None
+        }
+    }
+
+    /// Return the update authority.
+    ///
+    /// This method should be used when the caller knows that the metadata has an
+    /// update authority set since it skips the `Option` check.
+    #[inline(always)]
+    pub fn update_authority_unchecked(&self) -> &'a Pubkey {
+        self.update_authority
+    }
+
+    #[inline(always)]
+    pub fn mint(&self) -> &'a Pubkey {
+        self.mint
+    }
+
+    #[inline(always)]
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    #[inline(always)]
+    pub fn symbol(&self) -> &'a str {
+        self.symbol
+    }
+
+    #[inline(always)]
+    pub fn uri(&self) -> &'a str {
+        self.uri
+    }
+
+    /// Return an iterator over the additional key/value pairs.
+    #[inline(always)]
+    pub fn additional_metadata(&self) -> AdditionalMetadataIter<'a> {
+        AdditionalMetadataIter {
+            data: self.additional_metadata,
+            offset: 0,
+            remaining: self.additional_metadata_len,
+        }
+    }
+
+    /// Return the value of the first additional field with the given key.
+    pub fn get_additional_metadata(&self, key: &str) -> Option<&'a str> {
+        self.additional_metadata()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Return the value of the given field.
+    pub fn get_field(&self, field: Field) -> Option<&'a str> {
+        match field {
+            Field::Name => Some(self.name),
+            Field::Symbol => Some(self.symbol),
+            Field::Uri => Some(self.uri),
+            Field::Key(key) => self.get_additional_metadata(key),
+        }
+    }
+}
+
+/// Iterator over the additional key/value pairs of a `TokenMetadata`.
+#[derive(Clone, Debug)]
+pub struct AdditionalMetadataIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+    remaining: u32,
+}
+
+impl<'a> Iterator for AdditionalMetadataIter<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // pairs are validated when the `TokenMetadata` is parsed
+        let key = read_str(self.data, &mut self.offset).ok()?;
+        let value = read_str(self.data, &mut self.offset).ok()?;
+
+        Some((key, value))
+    }
+}
+
+#[inline(always)]
+fn read_pubkey<'a>(bytes: &'a [u8], offset: &mut usize) -> Result<&'a Pubkey, ProgramError> {
+    let value = bytes
+        .get(*offset..*offset + 32)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    *offset += 32;
+
+    // SAFETY: `value` has exactly 32 bytes and `Pubkey` has an alignment of 1.
+    Ok(unsafe { &*(value.as_ptr() as *const Pubkey) })
+}
+
+#[inline(always)]
+fn read_u32(bytes: &[u8], offset: &mut usize) -> Result<u32, ProgramError> {
+    let value = bytes
+        .get(*offset..*offset + 4)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    *offset += 4;
+
+    Ok(u32::from_le_bytes([value[0], value[1], value[2], value[3]]))
+}
+
+#[inline(always)]
+fn read_str<'a>(bytes: &'a [u8], offset: &mut usize) -> Result<&'a str, ProgramError> {
+    let len = read_u32(bytes, offset)? as usize;
+    let value = bytes
+        .get(*offset..*offset + len)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    *offset += len;
+
+    core::str::from_utf8(value).map_err(|_| ProgramError::InvalidAccountData)
+}
@@ -0,0 +1,109 @@
+use core::{mem::MaybeUninit, slice};
+
+use crate::extension::{
+    consts::ExtensionDiscriminator, transfer_fee::state::TransferFeeInstruction,
+};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::{invoke_signed_with_bounds, MAX_CPI_ACCOUNTS},
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Permissionless instruction to transfer all withheld tokens to the mint.
+///
+/// Succeeds for frozen accounts.
+///
+/// Accounts provided should include the `TransferFeeAmount` extension. If not,
+/// the account is skipped.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint.
+///   1. `..1+N` `[writable]` The source accounts to harvest from.
+pub struct HarvestWithheldTokensToMint<'a> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Token accounts to harvest the withheld tokens from.
+    pub sources: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl HarvestWithheldTokensToMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            sources,
+            token_program,
+        } = self;
+
+        let num_accounts = 1 + sources.len();
+
+        if num_accounts > MAX_CPI_ACCOUNTS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; MAX_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_metas` is sized to MAX_CPI_ACCOUNTS
+            // - Index 0 is always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(mint.key()));
+        }
+
+        for (account_meta, source) in acc_metas[1..].iter_mut().zip(sources.iter()) {
+            account_meta.write(AccountMeta::writable(source.key()));
+        }
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        let instruction_data = [
+            ExtensionDiscriminator::TransferFee as u8,
+            TransferFeeInstruction::HarvestWithheldTokensToMint as u8,
+        ];
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data: &instruction_data,
+        };
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; MAX_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_infos` is sized to MAX_CPI_ACCOUNTS
+            // - Index 0 is always present
+            acc_infos.get_unchecked_mut(0).write(mint);
+        }
+
+        // Fill source accounts
+        for (account_info, source) in acc_infos[1..].iter_mut().zip(sources.iter()) {
+            account_info.write(source);
+        }
+
+        invoke_signed_with_bounds::<MAX_CPI_ACCOUNTS>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
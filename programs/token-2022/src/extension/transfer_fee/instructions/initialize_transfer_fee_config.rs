@@ -0,0 +1,112 @@
+use core::slice;
+
+use crate::{
+    extension::{consts::ExtensionDiscriminator, transfer_fee::state::TransferFeeInstruction},
+    write_bytes, UNINIT_BYTE,
+};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed,
+    instruction::{AccountMeta, Instruction, Signer},
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Initialize the transfer fee on a new mint.
+///
+/// Accounts expected by this instruction:
+///
+///  0. `[writable]` The mint to initialize.
+pub struct InitializeTransferFeeConfig<'a> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Pubkey that may update the fees
+    pub transfer_fee_config_authority: Option<&'a Pubkey>,
+    /// Withdraw instructions must be signed by this key
+    pub withdraw_withheld_authority: Option<&'a Pubkey>,
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee assessed on transfers
+    pub maximum_fee: u64,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl InitializeTransferFeeConfig<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+            token_program,
+        } = self;
+
+        let account_metas = [AccountMeta::writable(mint.key())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2]: transfer_fee_config_authority presence flag (1 byte, u8)
+        // -  [3..35]: transfer_fee_config_authority (optional, 32 bytes, Pubkey)
+        // -  [..]: withdraw_withheld_authority presence flag (1 byte, u8)
+        // -  [..]: withdraw_withheld_authority (optional, 32 bytes, Pubkey)
+        // -  [..]: transfer_fee_basis_points (2 bytes, u16)
+        // -  [..]: maximum_fee (8 bytes, u64)
+        let mut instruction_data = [UNINIT_BYTE; 78];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::TransferFee as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[TransferFeeInstruction::InitializeTransferFeeConfig as u8],
+        );
+
+        let mut offset = 2;
+
+        for authority in [transfer_fee_config_authority, withdraw_withheld_authority] {
+            if let Some(authority) = authority {
+                // Set Option = `true` & authority
+                write_bytes(&mut instruction_data[offset..offset + 1], &[1]);
+                write_bytes(&mut instruction_data[offset + 1..offset + 33], authority);
+                offset += 33;
+            } else {
+                // Set Option = `false`
+                write_bytes(&mut instruction_data[offset..offset + 1], &[0]);
+                offset += 1;
+            }
+        }
+
+        // Set transfer_fee_basis_points as u16
+        write_bytes(
+            &mut instruction_data[offset..offset + 2],
+            &transfer_fee_basis_points.to_le_bytes(),
+        );
+        // Set maximum_fee as u64
+        write_bytes(
+            &mut instruction_data[offset + 2..offset + 10],
+            &maximum_fee.to_le_bytes(),
+        );
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: &account_metas,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, offset + 10) },
+        };
+
+        invoke_signed(&instruction, &[mint], signers)
+    }
+}
@@ -0,0 +1,13 @@
+pub mod harvest_withheld_tokens_to_mint;
+pub mod initialize_transfer_fee_config;
+pub mod set_transfer_fee;
+pub mod transfer_checked_with_fee;
+pub mod withdraw_withheld_tokens_from_accounts;
+pub mod withdraw_withheld_tokens_from_mint;
+
+pub use harvest_withheld_tokens_to_mint::*;
+pub use initialize_transfer_fee_config::*;
+pub use set_transfer_fee::*;
+pub use transfer_checked_with_fee::*;
+pub use withdraw_withheld_tokens_from_accounts::*;
+pub use withdraw_withheld_tokens_from_mint::*;
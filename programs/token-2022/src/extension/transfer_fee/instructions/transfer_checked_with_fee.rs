@@ -0,0 +1,171 @@
+use core::{mem::MaybeUninit, slice};
+
+use crate::{
+    extension::{consts::ExtensionDiscriminator, transfer_fee::state::TransferFeeInstruction},
+    instructions::MAX_MULTISIG_SIGNERS,
+    write_bytes, UNINIT_BYTE,
+};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Transfer tokens from one account to another, asserting the transfer fee
+/// calculated for the current epoch.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The source account.
+///   1. `[]` The token mint.
+///   2. `[writable]` The destination account.
+///   3. `[signer]` The source account's owner/delegate.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The source account.
+///   1. `[]` The token mint.
+///   2. `[writable]` The destination account.
+///   3. `[]` The source account's multisignature owner/delegate.
+///   4. `..4+M` `[signer]` M signer accounts.
+pub struct TransferCheckedWithFee<'a> {
+    /// Sender account.
+    pub source: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Recipient account.
+    pub destination: &'a AccountInfo,
+    /// Source Owner/Delegate Account.
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Amount of tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token
+    pub decimals: u8,
+    /// Expected fee assessed on this transfer, calculated off-chain based on
+    /// the `transfer_fee_basis_points` and `maximum_fee` of the mint.
+    pub fee: u64,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl TransferCheckedWithFee<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            source,
+            mint,
+            destination,
+            authority,
+            signers: account_signers,
+            amount,
+            decimals,
+            fee,
+            token_program,
+        } = self;
+
+        if account_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 4 + account_signers.len();
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_metas` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0 to 3 are always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(source.key()));
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::readonly(mint.key()));
+            acc_metas
+                .get_unchecked_mut(2)
+                .write(AccountMeta::writable(destination.key()));
+            if account_signers.is_empty() {
+                acc_metas
+                    .get_unchecked_mut(3)
+                    .write(AccountMeta::readonly_signer(authority.key()));
+            } else {
+                acc_metas
+                    .get_unchecked_mut(3)
+                    .write(AccountMeta::readonly(authority.key()));
+            }
+        }
+
+        for (account_meta, signer) in acc_metas[4..].iter_mut().zip(account_signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2..10]: amount (8 bytes, u64)
+        // -  [10]: decimals (1 byte, u8)
+        // -  [11..19]: fee (8 bytes, u64)
+        let mut instruction_data = [UNINIT_BYTE; 19];
+
+        // Set extension discriminator at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::TransferFee as u8],
+        );
+        // Set sub-instruction at offset [1]
+        write_bytes(
+            &mut instruction_data[1..2],
+            &[TransferFeeInstruction::TransferCheckedWithFee as u8],
+        );
+        // Set amount as u64 at offset [2..10]
+        write_bytes(&mut instruction_data[2..10], &amount.to_le_bytes());
+        // Set decimals as u8 at offset [10]
+        write_bytes(&mut instruction_data[10..11], &[decimals]);
+        // Set fee as u64 at offset [11..19]
+        write_bytes(&mut instruction_data[11..19], &fee.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 19) },
+        };
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_infos` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0 to 3 are always present
+            acc_infos.get_unchecked_mut(0).write(source);
+            acc_infos.get_unchecked_mut(1).write(mint);
+            acc_infos.get_unchecked_mut(2).write(destination);
+            acc_infos.get_unchecked_mut(3).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_info, signer) in acc_infos[4..].iter_mut().zip(account_signers.iter()) {
+            account_info.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,160 @@
+use core::{mem::MaybeUninit, slice};
+
+use crate::{
+    extension::{consts::ExtensionDiscriminator, transfer_fee::state::TransferFeeInstruction},
+    instructions::MAX_MULTISIG_SIGNERS,
+};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::{invoke_signed_with_bounds, MAX_CPI_ACCOUNTS},
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Transfer all withheld tokens to an account. Signed by the mint's withdraw
+/// withheld tokens authority.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[]` The token mint.
+///   1. `[writable]` The fee receiver account.
+///   2. `[signer]` The mint's `withdraw_withheld_authority`.
+///   3. `..3+N` `[writable]` The source accounts to withdraw from.
+///
+///   * Multisignature owner/delegate
+///   0. `[]` The token mint.
+///   1. `[writable]` The destination account.
+///   2. `[]` The mint's multisig `withdraw_withheld_authority`.
+///   3. `..3+M` `[signer]` M signer accounts.
+///   4. `3+M+1..3+M+N` `[writable]` The source accounts to withdraw from.
+pub struct WithdrawWithheldTokensFromAccounts<'a> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Fee receiver account.
+    pub destination: &'a AccountInfo,
+    /// Withdraw Withheld Authority Account.
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Token accounts to withdraw the withheld tokens from.
+    pub sources: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl WithdrawWithheldTokensFromAccounts<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            destination,
+            authority,
+            signers: account_signers,
+            sources,
+            token_program,
+        } = self;
+
+        if account_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 3 + account_signers.len() + sources.len();
+
+        if num_accounts > MAX_CPI_ACCOUNTS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; MAX_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_metas` is sized to MAX_CPI_ACCOUNTS
+            // - Indexes 0 to 2 are always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::readonly(mint.key()));
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::writable(destination.key()));
+            if account_signers.is_empty() {
+                acc_metas
+                    .get_unchecked_mut(2)
+                    .write(AccountMeta::readonly_signer(authority.key()));
+            } else {
+                acc_metas
+                    .get_unchecked_mut(2)
+                    .write(AccountMeta::readonly(authority.key()));
+            }
+        }
+
+        for (account_meta, signer) in acc_metas[3..].iter_mut().zip(account_signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
+
+        for (account_meta, source) in acc_metas[3 + account_signers.len()..]
+            .iter_mut()
+            .zip(sources.iter())
+        {
+            account_meta.write(AccountMeta::writable(source.key()));
+        }
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        // -  [2]: num_token_accounts (1 byte, u8)
+        let instruction_data = [
+            ExtensionDiscriminator::TransferFee as u8,
+            TransferFeeInstruction::WithdrawWithheldTokensFromAccounts as u8,
+            sources.len() as u8,
+        ];
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data: &instruction_data,
+        };
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; MAX_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_infos` is sized to MAX_CPI_ACCOUNTS
+            // - Indexes 0 to 2 are always present
+            acc_infos.get_unchecked_mut(0).write(mint);
+            acc_infos.get_unchecked_mut(1).write(destination);
+            acc_infos.get_unchecked_mut(2).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_info, signer) in acc_infos[3..].iter_mut().zip(account_signers.iter()) {
+            account_info.write(signer);
+        }
+
+        // Fill source accounts
+        for (account_info, source) in acc_infos[3 + account_signers.len()..]
+            .iter_mut()
+            .zip(sources.iter())
+        {
+            account_info.write(source);
+        }
+
+        invoke_signed_with_bounds::<MAX_CPI_ACCOUNTS>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
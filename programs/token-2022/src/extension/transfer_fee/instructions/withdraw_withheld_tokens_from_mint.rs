@@ -0,0 +1,134 @@
+use core::{mem::MaybeUninit, slice};
+
+use crate::{
+    extension::{consts::ExtensionDiscriminator, transfer_fee::state::TransferFeeInstruction},
+    instructions::MAX_MULTISIG_SIGNERS,
+};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Transfer all withheld tokens in the mint to an account. Signed by the mint's
+/// withdraw withheld tokens authority.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token mint.
+///   1. `[writable]` The fee receiver account.
+///   2. `[signer]` The mint's `withdraw_withheld_authority`.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token mint.
+///   1. `[writable]` The destination account.
+///   2. `[]` The mint's multisig `withdraw_withheld_authority`.
+///   3. `..3+M` `[signer]` M signer accounts.
+pub struct WithdrawWithheldTokensFromMint<'a> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Fee receiver account.
+    pub destination: &'a AccountInfo,
+    /// Withdraw Withheld Authority Account.
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl WithdrawWithheldTokensFromMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            destination,
+            authority,
+            signers: account_signers,
+            token_program,
+        } = self;
+
+        if account_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 3 + account_signers.len();
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_metas` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0 to 2 are always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(mint.key()));
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::writable(destination.key()));
+            if account_signers.is_empty() {
+                acc_metas
+                    .get_unchecked_mut(2)
+                    .write(AccountMeta::readonly_signer(authority.key()));
+            } else {
+                acc_metas
+                    .get_unchecked_mut(2)
+                    .write(AccountMeta::readonly(authority.key()));
+            }
+        }
+
+        for (account_meta, signer) in acc_metas[3..].iter_mut().zip(account_signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction_type (1 byte, u8)
+        let instruction_data = [
+            ExtensionDiscriminator::TransferFee as u8,
+            TransferFeeInstruction::WithdrawWithheldTokensFromMint as u8,
+        ];
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data: &instruction_data,
+        };
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_infos` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0 to 2 are always present
+            acc_infos.get_unchecked_mut(0).write(mint);
+            acc_infos.get_unchecked_mut(1).write(destination);
+            acc_infos.get_unchecked_mut(2).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_info, signer) in acc_infos[3..].iter_mut().zip(account_signers.iter()) {
+            account_info.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
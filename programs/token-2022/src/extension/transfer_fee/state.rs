@@ -0,0 +1,465 @@
+use {
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{
+            get_extension, get_extension_from_account_info, get_extension_from_account_info_mut,
+            get_extension_from_account_info_unchecked, get_extension_mut, Extension,
+        },
+    },
+    core::cmp,
+    pinocchio::{
+        account_info::{AccountInfo, Ref, RefMut},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
+};
+
+/// Maximum possible fee in basis points is `100%`, aka 10,000 basis points
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+const ONE_IN_BASIS_POINTS: u128 = MAX_FEE_BASIS_POINTS as u128;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransferFeeInstruction {
+    InitializeTransferFeeConfig = 0,
+    TransferCheckedWithFee = 1,
+    WithdrawWithheldTokensFromMint = 2,
+    WithdrawWithheldTokensFromAccounts = 3,
+    HarvestWithheldTokensToMint = 4,
+    SetTransferFee = 5,
+}
+
+/// Transfer fee information
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferFee {
+    /// First epoch where the transfer fee takes effect
+    epoch: [u8; 8],
+    /// Maximum fee assessed on transfers, expressed as an amount of tokens
+    maximum_fee: [u8; 8],
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount (increments of `0.01%`)
+    transfer_fee_basis_points: [u8; 2],
+}
+
+const _: () = assert!(core::mem::size_of::<TransferFee>() == 18);
+const _: () = assert!(core::mem::align_of::<TransferFee>() == 1);
+
+impl TransferFee {
+    /// The length of the `TransferFee` data.
+    pub const BASE_LEN: usize = core::mem::size_of::<TransferFee>();
+
+    /// Creates a new `TransferFee`
+    pub fn new(epoch: u64, maximum_fee: u64, transfer_fee_basis_points: u16) -> Self {
+        Self {
+            epoch: epoch.to_le_bytes(),
+            maximum_fee: maximum_fee.to_le_bytes(),
+            transfer_fee_basis_points: transfer_fee_basis_points.to_le_bytes(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    #[inline(always)]
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    #[inline(always)]
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    /// Calculate ceiling-division
+    ///
+    /// Ceiling-division
+    ///     `ceil[ numerator / denominator ]`
+    /// can be represented as a floor-division
+    ///     `floor[ (numerator + denominator - 1) / denominator]`
+    fn ceil_div(numerator: u128, denominator: u128) -> Option<u128> {
+        numerator
+            .checked_add(denominator)?
+            .checked_sub(1)?
+            .checked_div(denominator)
+    }
+
+    /// Calculate the transfer fee
+    pub fn calculate_fee(&self, pre_fee_amount: u64) -> Option<u64> {
+        let transfer_fee_basis_points = self.transfer_fee_basis_points() as u128;
+        if transfer_fee_basis_points == 0 || pre_fee_amount == 0 {
+            Some(0)
+        } else {
+            let numerator = (pre_fee_amount as u128).checked_mul(transfer_fee_basis_points)?;
+            let raw_fee = Self::ceil_div(numerator, ONE_IN_BASIS_POINTS)?
+                .try_into() // guaranteed to be okay
+                .ok()?;
+
+            Some(cmp::min(raw_fee, self.maximum_fee()))
+        }
+    }
+
+    /// Calculate the gross transfer amount after deducting fees
+    pub fn calculate_post_fee_amount(&self, pre_fee_amount: u64) -> Option<u64> {
+        pre_fee_amount.checked_sub(self.calculate_fee(pre_fee_amount)?)
+    }
+
+    /// Calculate the transfer amount that will result in a specified net
+    /// transfer amount.
+    ///
+    /// The original transfer amount may not always be unique due to rounding.
+    /// In this case, the smaller amount will be chosen.
+    /// e.g. Both transfer amount 10, 11 with `10%` fee rate results in net
+    /// transfer amount of 9. In this case, 10 will be chosen.
+    /// e.g. Fee rate is `100%`. In this case, 0 will be chosen.
+    ///
+    /// The original transfer amount may not always exist on large net transfer
+    /// amounts due to overflow. In this case, `None` is returned.
+    /// e.g. The net fee amount is `u64::MAX` with a positive fee rate.
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        let maximum_fee = self.maximum_fee();
+        let transfer_fee_basis_points = self.transfer_fee_basis_points() as u128;
+        match (transfer_fee_basis_points, post_fee_amount) {
+            // no fee, same amount
+            (0, _) => Some(post_fee_amount),
+            // 0 zero out, 0 in
+            (_, 0) => Some(0),
+            // 100%, cap at max fee
+            (ONE_IN_BASIS_POINTS, _) => maximum_fee.checked_add(post_fee_amount),
+            _ => {
+                let numerator = (post_fee_amount as u128).checked_mul(ONE_IN_BASIS_POINTS)?;
+                let denominator = ONE_IN_BASIS_POINTS.checked_sub(transfer_fee_basis_points)?;
+                let raw_pre_fee_amount = Self::ceil_div(numerator, denominator)?;
+
+                if raw_pre_fee_amount.checked_sub(post_fee_amount as u128)? >= maximum_fee as u128 {
+                    post_fee_amount.checked_add(maximum_fee)
+                } else {
+                    // should return `None` if `pre_fee_amount` overflows
+                    u64::try_from(raw_pre_fee_amount).ok()
+                }
+            }
+        }
+    }
+
+    /// Calculate the fee that would produce the given output
+    ///
+    /// Note: this function is not an exact inverse operation of
+    /// `calculate_fee`. Meaning, it is not the case that:
+    ///
+    /// `calculate_fee(x) == calculate_inverse_fee(x - calculate_fee(x))`
+    ///
+    /// Only the following relationship holds:
+    ///
+    /// `calculate_fee(x) >= calculate_inverse_fee(x - calculate_fee(x))`
+    pub fn calculate_inverse_fee(&self, post_fee_amount: u64) -> Option<u64> {
+        let pre_fee_amount = self.calculate_pre_fee_amount(post_fee_amount)?;
+        self.calculate_fee(pre_fee_amount)
+    }
+}
+
+/// Transfer fee extension data for mints.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferFeeConfig {
+    /// Optional authority to set the fee
+    transfer_fee_config_authority: Pubkey,
+    /// Withdraw from mint instructions must be signed by this key
+    withdraw_withheld_authority: Pubkey,
+    /// Withheld transfer fee tokens that have been moved to the mint for
+    /// withdrawal
+    withheld_amount: [u8; 8],
+    /// Older transfer fee, used if `current epoch < new_transfer_fee.epoch`
+    older_transfer_fee: TransferFee,
+    /// Newer transfer fee, used if `current epoch >= new_transfer_fee.epoch`
+    newer_transfer_fee: TransferFee,
+}
+
+const _: () = assert!(core::mem::size_of::<TransferFeeConfig>() == 108);
+const _: () = assert!(core::mem::align_of::<TransferFeeConfig>() == 1);
+
+unsafe impl Extension for TransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+}
+
+impl TransferFeeConfig {
+    /// The length of the `TransferFeeConfig` data.
+    pub const BASE_LEN: usize = core::mem::size_of::<TransferFeeConfig>();
+
+    /// Return a `TransferFeeConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, TransferFeeConfig>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return a `TransferFeeConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        get_extension_from_account_info_unchecked::<Self>(account_info)
+    }
+
+    /// Return a `TransferFeeConfig` from the given extension bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
+    /// 2. `bytes` contains a valid representation of `TransferFeeConfig`
+    #[inline(always)]
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TransferFeeConfig)
+    }
+
+    /// Return a `TransferFeeConfig` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `TransferFeeConfig` from the given account data, locating it
+    /// through the account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
+
+    /// Return a mutable `TransferFeeConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, TransferFeeConfig>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
+    }
+
+    /// Creates a new `TransferFeeConfig` state, with the same fee for the older and
+    /// newer transfer fees
+    pub fn new(
+        transfer_fee_config_authority: Option<&Pubkey>,
+        withdraw_withheld_authority: Option<&Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+        epoch: u64,
+    ) -> Self {
+        let transfer_fee = TransferFee::new(epoch, maximum_fee, transfer_fee_basis_points);
+
+        Self {
+            transfer_fee_config_authority: transfer_fee_config_authority
+                .copied()
+                .unwrap_or_default(),
+            withdraw_withheld_authority: withdraw_withheld_authority.copied().unwrap_or_default(),
+            withheld_amount: [0; 8],
+            older_transfer_fee: transfer_fee,
+            newer_transfer_fee: transfer_fee,
+        }
+    }
+
+    #[inline(always)]
+    pub fn has_transfer_fee_config_authority(&self) -> bool {
+        self.transfer_fee_config_authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn transfer_fee_config_authority(&self) -> Option<&Pubkey> {
+        if self.has_transfer_fee_config_authority() {
+            Some(&self.transfer_fee_config_authority)
+        } else {
+            None
+        }
+    }
+
+    /// Return the transfer fee config authority.
+    ///
+    /// This method should be used when the caller knows that the transfer fee config will
+    /// have an authority set since it skips the `Option` check.
+    #[inline(always)]
+    pub fn transfer_fee_config_authority_unchecked(&self) -> &Pubkey {
+        &self.transfer_fee_config_authority
+    }
+
+    #[inline(always)]
+    pub fn has_withdraw_withheld_authority(&self) -> bool {
+        self.withdraw_withheld_authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn withdraw_withheld_authority(&self) -> Option<&Pubkey> {
+        if self.has_withdraw_withheld_authority() {
+            Some(&self.withdraw_withheld_authority)
+        } else {
+            None
+        }
+    }
+
+    /// Return the withdraw withheld authority.
+    ///
+    /// This method should be used when the caller knows that the transfer fee config will
+    /// have a withdraw withheld authority set since it skips the `Option` check.
+    #[inline(always)]
+    pub fn withdraw_withheld_authority_unchecked(&self) -> &Pubkey {
+        &self.withdraw_withheld_authority
+    }
+
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    #[inline(always)]
+    pub fn older_transfer_fee(&self) -> &TransferFee {
+        &self.older_transfer_fee
+    }
+
+    #[inline(always)]
+    pub fn newer_transfer_fee(&self) -> &TransferFee {
+        &self.newer_transfer_fee
+    }
+
+    /// Get the fee for the given epoch
+    pub fn get_epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+
+    /// Get the fee for the current epoch, read from the `Clock` sysvar
+    pub fn get_current_epoch_fee(&self) -> Result<&TransferFee, ProgramError> {
+        Ok(self.get_epoch_fee(Clock::get()?.epoch))
+    }
+
+    /// Calculate the fee for the given epoch and input amount
+    pub fn calculate_epoch_fee(&self, epoch: u64, pre_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch).calculate_fee(pre_fee_amount)
+    }
+
+    /// Calculate the fee for the given epoch and output amount
+    pub fn calculate_inverse_epoch_fee(&self, epoch: u64, post_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch)
+            .calculate_inverse_fee(post_fee_amount)
+    }
+
+    /// Calculate the amount to transfer in the given epoch so that the recipient
+    /// receives `post_fee_amount`
+    pub fn calculate_epoch_pre_fee_amount(&self, epoch: u64, post_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch)
+            .calculate_pre_fee_amount(post_fee_amount)
+    }
+}
+
+/// Transfer fee extension data for accounts.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferFeeAmount {
+    /// Amount withheld during transfers, to be harvested to the mint
+    withheld_amount: [u8; 8],
+}
+
+const _: () = assert!(core::mem::size_of::<TransferFeeAmount>() == 8);
+const _: () = assert!(core::mem::align_of::<TransferFeeAmount>() == 1);
+
+unsafe impl Extension for TransferFeeAmount {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeAmount;
+}
+
+impl TransferFeeAmount {
+    /// The length of the `TransferFeeAmount` data.
+    pub const BASE_LEN: usize = core::mem::size_of::<TransferFeeAmount>();
+
+    /// Return a `TransferFeeAmount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, TransferFeeAmount>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return a `TransferFeeAmount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        get_extension_from_account_info_unchecked::<Self>(account_info)
+    }
+
+    /// Return a `TransferFeeAmount` from the given extension bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
+    /// 2. `bytes` contains a valid representation of `TransferFeeAmount`
+    #[inline(always)]
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TransferFeeAmount)
+    }
+
+    /// Return a `TransferFeeAmount` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `TransferFeeAmount` from the given account data, locating it
+    /// through the account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
+
+    /// Return a mutable `TransferFeeAmount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, TransferFeeAmount>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
+    }
+
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    /// Check if the extension is in a closable state, i.e. no fees are withheld
+    #[inline(always)]
+    pub fn is_closable(&self) -> bool {
+        self.withheld_amount() == 0
+    }
+}
@@ -0,0 +1,446 @@
+use pinocchio::{
+    program_error::ProgramError,
+    pubkey::{find_program_address, try_find_program_address, Pubkey, MAX_SEEDS},
+};
+
+/// Seed of the validation account, derived as `["extra-account-metas", mint]` from
+/// the transfer hook program.
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// Discriminator of the transfer hook `Execute` instruction, also used as the type
+/// of its entry in the validation account.
+///
+/// First 8 bytes of `sha256("spl-transfer-hook-interface:execute")`.
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+
+/// Length of the `Execute` instruction data:
+/// - [0..8]                     : Instruction discriminator (8 bytes)
+/// - [8..16]                    : amount (8 bytes, u64)
+pub const EXECUTE_INSTRUCTION_DATA_LEN: usize = 16;
+
+/// Maximum number of extra accounts resolved for a transfer hook.
+pub const MAX_EXTRA_ACCOUNT_METAS: usize = 16;
+
+/// Length of a TLV entry header in the validation account:
+/// - [0..8]                     : entry discriminator (8 bytes)
+/// - [8..12]                    : value length (4 bytes, u32)
+const TLV_HEADER_LEN: usize = 12;
+
+/// Discriminator of an `ExtraAccountMeta` describing a fixed address.
+const PUBKEY_DISCRIMINATOR: u8 = 0;
+/// Discriminator of an `ExtraAccountMeta` describing a PDA of the transfer hook program.
+const PDA_DISCRIMINATOR: u8 = 1;
+/// Discriminator of an `ExtraAccountMeta` describing an address read from some data.
+const PUBKEY_DATA_DISCRIMINATOR: u8 = 2;
+/// Discriminators with the top bit set describe a PDA of the program at the
+/// account index given by the lower bits.
+const EXTERNAL_PDA_FLAG: u8 = 1 << 7;
+
+/// Return the address and bump seed of the validation account of the given mint.
+#[inline]
+pub fn find_extra_account_metas_address(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, mint], program_id)
+}
+
+/// An extra account required by a transfer hook, as stored in the validation
+/// account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExtraAccountMeta {
+    /// Tells whether the address is a fixed address, a PDA or read from some data
+    discriminator: u8,
+    /// The address, the PDA seeds or the location of the address data
+    address_config: [u8; 32],
+    /// Whether the account should sign
+    is_signer: u8,
+    /// Whether the account should be writable
+    is_writable: u8,
+}
+
+const _: () = assert!(core::mem::size_of::<ExtraAccountMeta>() == 35);
+const _: () = assert!(core::mem::align_of::<ExtraAccountMeta>() == 1);
+
+impl ExtraAccountMeta {
+    /// The length of an `ExtraAccountMeta`.
+    pub const LEN: usize = core::mem::size_of::<ExtraAccountMeta>();
+
+    /// Creates an `ExtraAccountMeta` with a fixed address.
+    #[inline]
+    pub fn new_with_pubkey(pubkey: &Pubkey, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            discriminator: PUBKEY_DISCRIMINATOR,
+            address_config: *pubkey,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        }
+    }
+
+    #[inline(always)]
+    pub fn discriminator(&self) -> u8 {
+        self.discriminator
+    }
+
+    #[inline(always)]
+    pub fn address_config(&self) -> &[u8; 32] {
+        &self.address_config
+    }
+
+    #[inline(always)]
+    pub fn is_signer(&self) -> bool {
+        self.is_signer != 0
+    }
+
+    #[inline(always)]
+    pub fn is_writable(&self) -> bool {
+        self.is_writable != 0
+    }
+
+    /// Return the fixed address, `None` if the address is resolved.
+    #[inline]
+    pub fn pubkey(&self) -> Option<&Pubkey> {
+        if self.discriminator == PUBKEY_DISCRIMINATOR {
+            Some(&self.address_config)
+        } else {
+            None
+        }
+    }
+
+    /// Return the seeds of the PDA, `None` if the address is not a PDA.
+    #[inline]
+    pub fn seeds(&self) -> Option<SeedIter<'_>> {
+        if self.discriminator == PDA_DISCRIMINATOR || self.discriminator >= EXTERNAL_PDA_FLAG {
+            Some(SeedIter {
+                data: &self.address_config,
+                offset: 0,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Return the account index of the program deriving the PDA, `None` if the
+    /// address is not a PDA of an external program.
+    #[inline]
+    pub fn external_program_index(&self) -> Option<usize> {
+        if self.discriminator >= EXTERNAL_PDA_FLAG {
+            Some((self.discriminator - EXTERNAL_PDA_FLAG) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Return the location of the address data, `None` if the address is not read
+    /// from some data.
+    #[inline]
+    pub fn pubkey_data(&self) -> Result<Option<PubkeyData>, ProgramError> {
+        if self.discriminator == PUBKEY_DATA_DISCRIMINATOR {
+            PubkeyData::unpack(&self.address_config).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Resolve the address of the account.
+    ///
+    /// `get_account` returns the key and, if available, the data of the account at
+    /// the given index of the instruction the extra accounts are resolved for.
+    /// Seeds and pubkey data referencing instruction data read from
+    /// `instruction_data`, and PDAs are derived from `program_id` unless they
+    /// belong to an external program.
+    pub fn resolve<'b, F>(
+        &self,
+        instruction_data: &[u8],
+        program_id: &Pubkey,
+        get_account: F,
+    ) -> Result<Pubkey, ProgramError>
+    where
+        F: Fn(usize) -> Option<(&'b Pubkey, Option<&'b [u8]>)>,
+    {
+        if let Some(pubkey) = self.pubkey() {
+            return Ok(*pubkey);
+        }
+
+        if let Some(seeds) = self.seeds() {
+            let program_id = match self.external_program_index() {
+                Some(index) => {
+                    get_account(index)
+                        .ok_or(ProgramError::NotEnoughAccountKeys)?
+                        .0
+                }
+                None => program_id,
+            };
+
+            let mut pda_seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+            let mut num_seeds = 0;
+
+            for seed in seeds {
+                let seed = match seed? {
+                    Seed::Literal(bytes) => bytes,
+                    Seed::InstructionData { index, length } => {
+                        read_bytes(instruction_data, index as usize, length as usize)
+                            .ok_or(ProgramError::InvalidInstructionData)?
+                    }
+                    Seed::AccountKey { index } => {
+                        get_account(index as usize)
+                            .ok_or(ProgramError::NotEnoughAccountKeys)?
+                            .0
+                    }
+                    Seed::AccountData {
+                        account_index,
+                        data_index,
+                        length,
+                    } => read_bytes(
+                        account_data(&get_account, account_index)?,
+                        data_index as usize,
+                        length as usize,
+                    )
+                    .ok_or(ProgramError::AccountDataTooSmall)?,
+                };
+
+                *pda_seeds
+                    .get_mut(num_seeds)
+                    .ok_or(ProgramError::MaxSeedLengthExceeded)? = seed;
+                num_seeds += 1;
+            }
+
+            return try_find_program_address(&pda_seeds[..num_seeds], program_id)
+                .map(|(address, _)| address)
+                .ok_or(ProgramError::InvalidSeeds);
+        }
+
+        let address = match self.pubkey_data()? {
+            Some(PubkeyData::InstructionData { index }) => {
+                read_bytes(instruction_data, index as usize, 32)
+                    .ok_or(ProgramError::InvalidInstructionData)?
+            }
+            Some(PubkeyData::AccountData {
+                account_index,
+                data_index,
+            }) => read_bytes(
+                account_data(&get_account, account_index)?,
+                data_index as usize,
+                32,
+            )
+            .ok_or(ProgramError::AccountDataTooSmall)?,
+            None => return Err(ProgramError::InvalidAccountData),
+        };
+
+        // SAFETY: `address` has exactly 32 bytes.
+        Ok(unsafe { *(address.as_ptr() as *const Pubkey) })
+    }
+}
+
+/// A seed of a PDA extra account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Seed<'a> {
+    /// Literal bytes.
+    Literal(&'a [u8]),
+    /// A slice of the instruction data.
+    InstructionData {
+        /// The index where the bytes begin in the instruction data
+        index: u8,
+        /// The length of the bytes
+        length: u8,
+    },
+    /// The key of an account of the instruction.
+    AccountKey {
+        /// The index of the account in the instruction accounts
+        index: u8,
+    },
+    /// A slice of the data of an account of the instruction.
+    AccountData {
+        /// The index of the account in the instruction accounts
+        account_index: u8,
+        /// The index where the bytes begin in the account data
+        data_index: u8,
+        /// The length of the bytes
+        length: u8,
+    },
+}
+
+/// Iterator over the seeds packed in the address configuration of a PDA extra
+/// account.
+///
+/// Iteration stops at the first uninitialized seed or at the end of the
+/// configuration; a malformed seed yields a single `InvalidAccountData` error.
+#[derive(Clone, Debug)]
+pub struct SeedIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for SeedIter<'a> {
+    type Item = Result<Seed<'a>, ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data.get(self.offset..)?;
+
+        let (seed, len) = match *data {
+            [] | [0, ..] => return None,
+            [1, length, ref rest @ ..] if rest.len() >= length as usize => {
+                (Seed::Literal(&rest[..length as usize]), 2 + length as usize)
+            }
+            [2, index, length, ..] => (Seed::InstructionData { index, length }, 3),
+            [3, index, ..] => (Seed::AccountKey { index }, 2),
+            [4, account_index, data_index, length, ..] => (
+                Seed::AccountData {
+                    account_index,
+                    data_index,
+                    length,
+                },
+                4,
+            ),
+            _ => {
+                self.offset = self.data.len();
+                return Some(Err(ProgramError::InvalidAccountData));
+            }
+        };
+
+        self.offset += len;
+
+        Some(Ok(seed))
+    }
+}
+
+/// The location of the address of an extra account read from some data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PubkeyData {
+    /// The address is read from the instruction data.
+    InstructionData {
+        /// The index where the address begins in the instruction data
+        index: u8,
+    },
+    /// The address is read from the data of an account of the instruction.
+    AccountData {
+        /// The index of the account in the instruction accounts
+        account_index: u8,
+        /// The index where the address begins in the account data
+        data_index: u8,
+    },
+}
+
+impl PubkeyData {
+    #[inline]
+    fn unpack(bytes: &[u8]) -> Result<Self, ProgramError> {
+        match *bytes {
+            [1, index, ..] => Ok(Self::InstructionData { index }),
+            [2, account_index, data_index, ..] => Ok(Self::AccountData {
+                account_index,
+                data_index,
+            }),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Zero-copy view over the extra accounts of an instruction stored in a
+/// validation account.
+///
+/// The validation account holds TLV entries, each made of:
+/// - [0..8]                     : instruction discriminator (8 bytes)
+/// - [8..12]                    : value length (4 bytes, u32)
+/// - [..]                       : number of extra accounts (4 bytes, u32), then the
+///   `ExtraAccountMeta`s
+#[derive(Clone, Copy, Debug)]
+pub struct ExtraAccountMetaList<'a> {
+    metas: &'a [ExtraAccountMeta],
+}
+
+impl<'a> ExtraAccountMetaList<'a> {
+    /// Return the extra accounts of the `Execute` instruction from the given
+    /// validation account data.
+    #[inline]
+    pub fn from_bytes(data: &'a [u8]) -> Result<ExtraAccountMetaList<'a>, ProgramError> {
+        Self::from_bytes_with_discriminator(data, &EXECUTE_DISCRIMINATOR)
+    }
+
+    /// Return the extra accounts of the instruction with the given discriminator
+    /// from the given validation account data.
+    pub fn from_bytes_with_discriminator(
+        data: &'a [u8],
+        discriminator: &[u8; 8],
+    ) -> Result<ExtraAccountMetaList<'a>, ProgramError> {
+        let mut offset = 0;
+
+        while let Some(header) = data.get(offset..offset + TLV_HEADER_LEN) {
+            // an uninitialized discriminator marks the end of the entries
+            if header[..8] == [0; 8] {
+                break;
+            }
+
+            let len = u32::from_le_bytes([header[8], header[9], header[10], header[11]]) as usize;
+            let value = data
+                .get(offset + TLV_HEADER_LEN..offset + TLV_HEADER_LEN + len)
+                .ok_or(ProgramError::InvalidAccountData)?;
+
+            if &header[..8] == discriminator {
+                return Self::from_value_bytes(value);
+            }
+
+            offset += TLV_HEADER_LEN + len;
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+
+    /// Return the extra accounts from the value of their TLV entry.
+    #[inline]
+    pub fn from_value_bytes(value: &'a [u8]) -> Result<ExtraAccountMetaList<'a>, ProgramError> {
+        let count = value
+            .get(..4)
+            .map(|count| u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let metas = value
+            .get(4..4 + count * ExtraAccountMeta::LEN)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok(Self {
+            // SAFETY: `metas` holds `count` entries and `ExtraAccountMeta` has an
+            // alignment of 1.
+            metas: unsafe {
+                core::slice::from_raw_parts(metas.as_ptr() as *const ExtraAccountMeta, count)
+            },
+        })
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.metas.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.metas.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<&'a ExtraAccountMeta> {
+        self.metas.get(index)
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [ExtraAccountMeta] {
+        self.metas
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'a, ExtraAccountMeta> {
+        self.metas.iter()
+    }
+}
+
+#[inline(always)]
+fn read_bytes(data: &[u8], index: usize, length: usize) -> Option<&[u8]> {
+    data.get(index..index + length)
+}
+
+#[inline(always)]
+fn account_data<'b, F>(get_account: &F, index: u8) -> Result<&'b [u8], ProgramError>
+where
+    F: Fn(usize) -> Option<(&'b Pubkey, Option<&'b [u8]>)>,
+{
+    get_account(index as usize)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .1
+        .ok_or(ProgramError::AccountBorrowFailed)
+}
@@ -1,5 +1,7 @@
 pub mod initialize;
+pub mod transfer_checked_with_hook;
 pub mod update;
 
 pub use initialize::*;
+pub use transfer_checked_with_hook::*;
 pub use update::*;
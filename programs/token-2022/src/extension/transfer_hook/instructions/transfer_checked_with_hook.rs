@@ -0,0 +1,299 @@
+use core::{mem::MaybeUninit, slice};
+
+use crate::{
+    extension::{
+        tlv::find_extension,
+        transfer_hook::{
+            extra_account_meta::{
+                find_extra_account_metas_address, ExtraAccountMetaList, EXECUTE_DISCRIMINATOR,
+                EXECUTE_INSTRUCTION_DATA_LEN, MAX_EXTRA_ACCOUNT_METAS,
+            },
+            state::TransferHook,
+        },
+    },
+    instructions::{TransferChecked, MAX_MULTISIG_SIGNERS},
+    write_bytes, UNINIT_BYTE,
+};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Maximum number of accounts of the `TransferChecked` instruction: the 4 base
+/// accounts, the multisig signers, the extra accounts, the validation account and
+/// the transfer hook program.
+const MAX_ACCOUNTS: usize = 4 + MAX_MULTISIG_SIGNERS + MAX_EXTRA_ACCOUNT_METAS + 2;
+
+/// Number of accounts of the `Execute` instruction the extra accounts are resolved
+/// for, before the extra accounts: source, mint, destination, authority and the
+/// validation account.
+const EXECUTE_BASE_ACCOUNTS: usize = 5;
+
+/// Transfer Tokens from one Token Account to another, passing the accounts
+/// required by the transfer hook of the mint.
+///
+/// The transfer hook program is read from the mint. The extra accounts listed in
+/// its validation account are resolved for the `Execute` instruction and looked up
+/// in `additional_accounts`, which must also hold the transfer hook program and the
+/// validation account. Mints without a transfer hook program are transferred with
+/// a plain `TransferChecked`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The source account's owner/delegate.
+///   4. ..`4+M` `[SIGNER]` M signer accounts, if the authority is a multisig.
+///   5. `4+M..4+M+E` E extra accounts required by the transfer hook.
+///   6. `4+M+E` `[]` The validation account, if the transfer hook program has one.
+///   7. `5+M+E` `[]` The transfer hook program.
+pub struct TransferCheckedWithHook<'a, 'b> {
+    /// Sender account.
+    pub from: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Recipient account.
+    pub to: &'a AccountInfo,
+    /// Authority account.
+    pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
+    /// Accounts searched for the transfer hook program, the validation account
+    /// and the extra accounts
+    pub additional_accounts: &'a [AccountInfo],
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token
+    pub decimals: u8,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl TransferCheckedWithHook<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let program_id = {
+            let mint_data = self.mint.try_borrow_data()?;
+
+            match find_extension::<TransferHook>(&mint_data)?.and_then(TransferHook::program_id) {
+                Some(program_id) => *program_id,
+                None => {
+                    return TransferChecked {
+                        from: self.from,
+                        mint: self.mint,
+                        to: self.to,
+                        authority: self.authority,
+                        signers: self.signers,
+                        amount: self.amount,
+                        decimals: self.decimals,
+                        token_program: self.token_program,
+                    }
+                    .invoke_signed(signers)
+                }
+            }
+        };
+
+        if self.signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let program = self
+            .additional_accounts
+            .iter()
+            .find(|account| account.key() == &program_id)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        let (validation_address, _) =
+            find_extra_account_metas_address(self.mint.key(), &program_id);
+        let validation = self
+            .additional_accounts
+            .iter()
+            .find(|account| account.key() == &validation_address);
+
+        // Accounts of the `Execute` instruction, followed by the resolved extra
+        // accounts and their privileges
+        let mut execute_accounts = [self.from; EXECUTE_BASE_ACCOUNTS + MAX_EXTRA_ACCOUNT_METAS];
+        let mut extra_privileges = [(false, false); MAX_EXTRA_ACCOUNT_METAS];
+        let mut num_extra_accounts = 0;
+
+        if let Some(validation) = validation {
+            execute_accounts[1] = self.mint;
+            execute_accounts[2] = self.to;
+            execute_accounts[3] = self.authority;
+            execute_accounts[4] = validation;
+
+            // Instruction data layout:
+            // -  [0..8]: instruction discriminator (8 bytes)
+            // -  [8..16]: amount (8 bytes, u64)
+            let mut execute_data = [0; EXECUTE_INSTRUCTION_DATA_LEN];
+            execute_data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+            execute_data[8..].copy_from_slice(&self.amount.to_le_bytes());
+
+            let validation_data = validation.try_borrow_data()?;
+            let extra_account_metas = ExtraAccountMetaList::from_bytes(&validation_data)?;
+
+            if extra_account_metas.len() > MAX_EXTRA_ACCOUNT_METAS {
+                Err(ProgramError::InvalidArgument)?;
+            }
+
+            for extra_account_meta in extra_account_metas.iter() {
+                let resolved_accounts =
+                    &execute_accounts[..EXECUTE_BASE_ACCOUNTS + num_extra_accounts];
+
+                let address = extra_account_meta.resolve(&execute_data, &program_id, |index| {
+                    resolved_accounts.get(index).map(|account| {
+                        // SAFETY: the data is only read while the address is
+                        // resolved, and only if it is not mutably borrowed.
+                        let data = account
+                            .can_borrow_data()
+                            .ok()
+                            .map(|_| unsafe { account.borrow_data_unchecked() });
+                        (account.key(), data)
+                    })
+                })?;
+
+                let account = self
+                    .additional_accounts
+                    .iter()
+                    .find(|account| account.key() == &address)
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+                // Accounts already present in the `Execute` instruction keep their
+                // privileges: the base accounts are all read-only
+                let (mut is_signer, mut is_writable) = (
+                    extra_account_meta.is_signer(),
+                    extra_account_meta.is_writable(),
+                );
+
+                if execute_accounts[..EXECUTE_BASE_ACCOUNTS]
+                    .iter()
+                    .any(|account| account.key() == &address)
+                {
+                    is_signer = false;
+                    is_writable = false;
+                }
+
+                for (account, (signer, writable)) in execute_accounts[EXECUTE_BASE_ACCOUNTS..]
+                    .iter()
+                    .zip(extra_privileges.iter())
+                    .take(num_extra_accounts)
+                {
+                    if account.key() == &address {
+                        is_signer &= *signer;
+                        is_writable &= *writable;
+                    }
+                }
+
+                execute_accounts[EXECUTE_BASE_ACCOUNTS + num_extra_accounts] = account;
+                extra_privileges[num_extra_accounts] = (is_signer, is_writable);
+                num_extra_accounts += 1;
+            }
+        }
+
+        let extra_accounts =
+            &execute_accounts[EXECUTE_BASE_ACCOUNTS..EXECUTE_BASE_ACCOUNTS + num_extra_accounts];
+        let num_accounts =
+            4 + self.signers.len() + num_extra_accounts + if validation.is_some() { 2 } else { 1 };
+
+        // account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; MAX_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_metas` is sized to MAX_ACCOUNTS
+            // - Indexes 0, 1, 2 and 3 are always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(self.from.key()));
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::readonly(self.mint.key()));
+            acc_metas
+                .get_unchecked_mut(2)
+                .write(AccountMeta::writable(self.to.key()));
+            // - The authority only signs when it is not a multisig
+            acc_metas.get_unchecked_mut(3).write(AccountMeta::new(
+                self.authority.key(),
+                false,
+                self.signers.is_empty(),
+            ));
+        }
+
+        let mut metas = acc_metas[4..].iter_mut();
+
+        for (account_meta, signer) in metas.by_ref().zip(self.signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
+
+        for (account_meta, (account, (is_signer, is_writable))) in metas
+            .by_ref()
+            .zip(extra_accounts.iter().zip(extra_privileges.iter()))
+        {
+            account_meta.write(AccountMeta::new(account.key(), *is_writable, *is_signer));
+        }
+
+        for (account_meta, account) in metas.zip(validation.into_iter().chain([program])) {
+            account_meta.write(AccountMeta::readonly(account.key()));
+        }
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        // -  [9]: decimals (1 byte, u8)
+        let mut instruction_data = [UNINIT_BYTE; 10];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[12]);
+        // Set amount as u64 at offset [1..9]
+        write_bytes(&mut instruction_data[1..9], &self.amount.to_le_bytes());
+        // Set decimals as u8 at offset [9]
+        write_bytes(&mut instruction_data[9..], &[self.decimals]);
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 10) },
+        };
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; MAX_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_infos` is sized to MAX_ACCOUNTS
+            // - Indexes 0, 1, 2 and 3 are always present
+            acc_infos.get_unchecked_mut(0).write(self.from);
+            acc_infos.get_unchecked_mut(1).write(self.mint);
+            acc_infos.get_unchecked_mut(2).write(self.to);
+            acc_infos.get_unchecked_mut(3).write(self.authority);
+        }
+
+        let accounts = self
+            .signers
+            .iter()
+            .chain(extra_accounts.iter().copied())
+            .chain(validation)
+            .chain([program]);
+
+        for (account_info, account) in acc_infos[4..].iter_mut().zip(accounts) {
+            account_info.write(account);
+        }
+
+        invoke_signed_with_bounds::<MAX_ACCOUNTS>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
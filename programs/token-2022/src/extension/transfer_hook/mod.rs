@@ -1,5 +1,7 @@
+pub mod extra_account_meta;
 pub mod instructions;
 pub mod state;
 
+pub use extra_account_meta::*;
 pub use instructions::*;
 pub use state::*;
@@ -1,8 +1,10 @@
-use core::mem::MaybeUninit;
-use crate::{write_bytes, UNINIT_BYTE, ID};
-use crate::extension::consts::ExtensionDiscriminator;
+use crate::extension::consts::{ExtensionDiscriminator, ExtensionType};
+use crate::extension::tlv::{
+    get_extension, get_extension_from_account_info, get_extension_from_account_info_mut,
+    get_extension_mut, Extension,
+};
 use pinocchio::{
-    account_info::{AccountInfo, Ref},
+    account_info::{AccountInfo, Ref, RefMut},
     program_error::ProgramError,
     pubkey::Pubkey,
 };
@@ -23,44 +25,55 @@ pub struct TransferHook {
     program_id: Pubkey,
 }
 
-impl TransferHook {
-    pub const AUTHORITY_START: usize = 170;
+const _: () = assert!(core::mem::size_of::<TransferHook>() == 64);
+const _: () = assert!(core::mem::align_of::<TransferHook>() == 1);
 
-    pub const BASE_LEN: usize = core::mem::size_of::<TransferHook>();
+unsafe impl Extension for TransferHook {
+    const TYPE: ExtensionType = ExtensionType::TransferHook;
+}
 
-    pub const LEN: usize = Self::AUTHORITY_START + Self::BASE_LEN;
+impl TransferHook {
+    pub const BASE_LEN: usize = core::mem::size_of::<TransferHook>();
 
-    /// Return a `TransferHook` from the given bytes (unsafe, unchecked).
+    /// Return a `TransferHook` from the given extension bytes (unsafe, unchecked).
     #[inline(always)]
-    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::AUTHORITY_START as usize..].as_ptr() as *const TransferHook)
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TransferHook)
     }
 
-    /// Safe version that validates lengths
+    /// Safe version that locates the extension through the account TLV entries
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a mutable `TransferHook` from the given account data, locating it through the
+    /// account TLV entries.
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        get_extension_mut::<Self>(bytes)
+    }
+
+    /// Return a mutable `TransferHook` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe mutably borrowing the account data.
+    #[inline]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<'_, TransferHook>, ProgramError> {
+        get_extension_from_account_info_mut::<Self>(account_info)
     }
 
-    /// Like your old from_account_info method
+    /// Return a `TransferHook` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
-    ) -> Result<Self, ProgramError> {
-        let data = account_info.try_borrow_data()?;
-        if data.len() < Self::LEN as usize {
-            return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
-        }
-        
-        // SAFETY: We've validated the length above
-        let config = unsafe {
-            core::ptr::read((data.as_ptr().add(Self::AUTHORITY_START as usize)) as *const Self)
-        };
-        
-        Ok(config)
+    ) -> Result<Ref<'_, TransferHook>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
     }
 
     /// Creates a new state
@@ -118,6 +131,64 @@ impl TransferHook {
     }
 }
 
+/// Indicates that the tokens from this account belong to a mint with a transfer
+/// hook.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferHookAccount {
+    /// Flag to indicate that the account is in the middle of a transfer
+    transferring: u8,
+}
+
+const _: () = assert!(core::mem::size_of::<TransferHookAccount>() == 1);
+const _: () = assert!(core::mem::align_of::<TransferHookAccount>() == 1);
+
+unsafe impl Extension for TransferHookAccount {
+    const TYPE: ExtensionType = ExtensionType::TransferHookAccount;
+}
+
+impl TransferHookAccount {
+    /// The length of the `TransferHookAccount` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<TransferHookAccount>();
+
+    /// Return a `TransferHookAccount` from the given extension bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    /// 1. `bytes` contains at least `BASE_LEN` bytes
+    /// 2. `bytes` contains a valid representation of `TransferHookAccount`
+    #[inline(always)]
+    pub unsafe fn from_extension_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TransferHookAccount)
+    }
+
+    /// Return a `TransferHookAccount` from the given token account data, locating it
+    /// through the account TLV entries.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension::<Self>(bytes)
+    }
+
+    /// Return a `TransferHookAccount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and locates the extension
+    /// through the account TLV entries, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<'_, TransferHookAccount>, ProgramError> {
+        get_extension_from_account_info::<Self>(account_info)
+    }
+
+    /// Return `true` while the token program is transferring from or to the account,
+    /// i.e. while it invokes the transfer hook program.
+    #[inline(always)]
+    pub fn transferring(&self) -> bool {
+        self.transferring != 0
+    }
+}
+
 pub fn transfer_hook_initialize_instruction_data<'a>(
     buffer: &'a mut [u8],
     instruction_type: TransferHookInstruction,
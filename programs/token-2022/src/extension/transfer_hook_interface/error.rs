@@ -0,0 +1,56 @@
+use pinocchio::program_error::{ProgramError, ToStr};
+
+/// Errors that may be returned by a transfer hook program.
+///
+/// Each variant converts into the `ProgramError::Custom` code of the matching
+/// transfer hook interface error.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferHookError {
+    /// Incorrect account provided
+    IncorrectAccount = 2_110_272_652,
+    /// Mint has no mint authority
+    MintHasNoMintAuthority,
+    /// Incorrect mint authority has signed the instruction
+    IncorrectMintAuthority,
+    /// Program called outside of a token transfer
+    ProgramCalledOutsideOfTransfer,
+}
+
+impl From<TransferHookError> for ProgramError {
+    fn from(error: TransferHookError) -> Self {
+        ProgramError::Custom(error as u32)
+    }
+}
+
+impl TryFrom<u32> for TransferHookError {
+    type Error = ProgramError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            2_110_272_652 => Ok(TransferHookError::IncorrectAccount),
+            2_110_272_653 => Ok(TransferHookError::MintHasNoMintAuthority),
+            2_110_272_654 => Ok(TransferHookError::IncorrectMintAuthority),
+            2_110_272_655 => Ok(TransferHookError::ProgramCalledOutsideOfTransfer),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+impl ToStr for TransferHookError {
+    fn to_str<E>(&self) -> &'static str
+    where
+        E: 'static + ToStr + TryFrom<u32>,
+    {
+        match self {
+            TransferHookError::IncorrectAccount => "Incorrect account provided",
+            TransferHookError::MintHasNoMintAuthority => "Mint has no mint authority",
+            TransferHookError::IncorrectMintAuthority => {
+                "Incorrect mint authority has signed the instruction"
+            }
+            TransferHookError::ProgramCalledOutsideOfTransfer => {
+                "Program called outside of a token transfer"
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use {
+    crate::extension::{
+        transfer_hook::TransferHookAccount, transfer_hook_interface::TransferHookError,
+    },
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+};
+
+/// Check that the `Execute` instruction is invoked by the token program during a
+/// transfer from the given source account.
+///
+/// The token program flags its source account as transferring only while it
+/// invokes the transfer hook program, so a flagged token-2022 account can only
+/// be observed from within a token-2022 transfer. Fails with
+/// `ProgramCalledOutsideOfTransfer` otherwise.
+#[inline]
+pub fn check_transferring(source: &AccountInfo) -> ProgramResult {
+    // validates that the account is owned by the token program
+    let extension = TransferHookAccount::from_account_info(source)?;
+
+    if !extension.transferring() {
+        return Err(TransferHookError::ProgramCalledOutsideOfTransfer.into());
+    }
+
+    Ok(())
+}
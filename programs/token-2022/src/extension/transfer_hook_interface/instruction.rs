@@ -0,0 +1,103 @@
+use {
+    crate::extension::transfer_hook::{ExtraAccountMetaList, EXECUTE_DISCRIMINATOR},
+    pinocchio::program_error::ProgramError,
+};
+
+/// Discriminator of the `InitializeExtraAccountMetaList` instruction.
+///
+/// First 8 bytes of `sha256("spl-transfer-hook-interface:initialize-extra-account-metas")`.
+pub const INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR: [u8; 8] =
+    [43, 34, 13, 49, 167, 88, 235, 235];
+
+/// Discriminator of the `UpdateExtraAccountMetaList` instruction.
+///
+/// First 8 bytes of `sha256("spl-transfer-hook-interface:update-extra-account-metas")`.
+pub const UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR: [u8; 8] =
+    [157, 105, 42, 146, 102, 85, 241, 174];
+
+/// Instructions of the transfer hook interface.
+#[derive(Clone, Copy, Debug)]
+pub enum TransferHookInterfaceInstruction<'a> {
+    /// Runs additional transfer logic.
+    ///
+    /// ### Accounts:
+    ///   0. `[]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The destination account.
+    ///   3. `[]` The source account's owner/delegate.
+    ///   4. `[]` The validation account.
+    ///   5. `..5+E` E extra accounts, as listed in the validation account.
+    Execute {
+        /// Amount of tokens to transfer
+        amount: u64,
+    },
+
+    /// Initializes the extra accounts required by the `Execute` instruction.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` The validation account.
+    ///   1. `[]` The token mint.
+    ///   2. `[SIGNER]` The mint authority.
+    ///   3. `[]` The system program.
+    InitializeExtraAccountMetaList {
+        /// Extra accounts to list in the validation account
+        extra_account_metas: ExtraAccountMetaList<'a>,
+    },
+
+    /// Updates the extra accounts required by the `Execute` instruction.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` The validation account.
+    ///   1. `[]` The token mint.
+    ///   2. `[SIGNER]` The mint authority.
+    UpdateExtraAccountMetaList {
+        /// Extra accounts replacing the ones in the validation account
+        extra_account_metas: ExtraAccountMetaList<'a>,
+    },
+}
+
+impl<'a> TransferHookInterfaceInstruction<'a> {
+    /// Decode a transfer hook interface instruction from its data.
+    ///
+    /// Instruction data layout:
+    /// - [0..8]                     : instruction discriminator (8 bytes)
+    /// - [8..16]                    : amount (8 bytes, u64), for `Execute`
+    /// - [8..12]                    : number of extra accounts (4 bytes, u32), then the
+    ///   `ExtraAccountMeta`s, for `InitializeExtraAccountMetaList` and
+    ///   `UpdateExtraAccountMetaList`
+    pub fn unpack(instruction_data: &'a [u8]) -> Result<Self, ProgramError> {
+        if instruction_data.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (discriminator, rest) = instruction_data.split_at(8);
+
+        match discriminator {
+            d if d == EXECUTE_DISCRIMINATOR => {
+                let amount = rest
+                    .get(..8)
+                    .and_then(|amount| amount.try_into().ok())
+                    .map(u64::from_le_bytes)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+
+                Ok(Self::Execute { amount })
+            }
+            d if d == INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR => {
+                Ok(Self::InitializeExtraAccountMetaList {
+                    extra_account_metas: unpack_extra_account_metas(rest)?,
+                })
+            }
+            d if d == UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR => {
+                Ok(Self::UpdateExtraAccountMetaList {
+                    extra_account_metas: unpack_extra_account_metas(rest)?,
+                })
+            }
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+#[inline(always)]
+fn unpack_extra_account_metas(data: &[u8]) -> Result<ExtraAccountMetaList<'_>, ProgramError> {
+    ExtraAccountMetaList::from_value_bytes(data).map_err(|_| ProgramError::InvalidInstructionData)
+}
@@ -0,0 +1,14 @@
+//! Interface implemented by transfer hook programs.
+//!
+//! The token program invokes the transfer hook program of a mint with the
+//! `Execute` instruction on every transfer. The extra accounts it requires are
+//! listed in a validation account, written by the `InitializeExtraAccountMetaList`
+//! and `UpdateExtraAccountMetaList` instructions.
+
+pub mod error;
+pub mod execute;
+pub mod instruction;
+
+pub use error::*;
+pub use execute::*;
+pub use instruction::*;
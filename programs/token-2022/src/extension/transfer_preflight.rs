@@ -0,0 +1,267 @@
+//! Extension-aware validation of a `TransferChecked` before it is invoked.
+//!
+//! The checks follow the order of the token program transfer processor, so the
+//! first failing check is the one that would have aborted the CPI.
+
+use {
+    crate::{
+        error::TokenError,
+        extension::{
+            consts::AccountType,
+            cpi_guard::state::CpiGuard,
+            memo_transfer::state::MemoTransfer,
+            non_transferable::state::NonTransferableAccount,
+            pausable::state::PausableConfig,
+            permanent_delegate::state::PermanentDelegate,
+            tlv::{check_account_type, find_extension},
+            transfer_fee::state::TransferFeeConfig,
+            transfer_hook::state::TransferHook,
+        },
+        state::{Mint, TokenAccount},
+        ID,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
+};
+
+/// Reasons for which the token program would reject a transfer.
+///
+/// Each variant converts into the `ProgramError::Custom` code of the matching
+/// token program error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransferPreflightError {
+    /// An account is not a valid token-2022 mint or token account.
+    InvalidAccount(ProgramError),
+    /// The source account does not hold enough tokens, or the delegate was not
+    /// approved for enough tokens.
+    InsufficientFunds,
+    /// The accounts do not belong to the given mint.
+    MintMismatch,
+    /// The authority is neither the owner nor a delegate of the source account.
+    OwnerMismatch,
+    /// The fee calculation overflowed.
+    Overflow,
+    /// The source or destination account is frozen.
+    AccountFrozen,
+    /// The decimals do not match the mint decimals.
+    MintDecimalsMismatch,
+    /// The tokens of the mint cannot be transferred.
+    NonTransferable,
+    /// The source account has CPI Guard enabled and the transfer is signed by
+    /// its owner.
+    CpiGuardTransferBlocked,
+    /// The mint is paused.
+    MintPaused,
+}
+
+impl From<ProgramError> for TransferPreflightError {
+    fn from(error: ProgramError) -> Self {
+        TransferPreflightError::InvalidAccount(error)
+    }
+}
+
+impl From<TransferPreflightError> for ProgramError {
+    fn from(error: TransferPreflightError) -> Self {
+        match error {
+            TransferPreflightError::InvalidAccount(error) => error,
+            TransferPreflightError::InsufficientFunds => TokenError::InsufficientFunds.into(),
+            TransferPreflightError::MintMismatch => TokenError::MintMismatch.into(),
+            TransferPreflightError::OwnerMismatch => TokenError::OwnerMismatch.into(),
+            TransferPreflightError::Overflow => TokenError::Overflow.into(),
+            TransferPreflightError::AccountFrozen => TokenError::AccountFrozen.into(),
+            TransferPreflightError::MintDecimalsMismatch => TokenError::MintDecimalsMismatch.into(),
+            TransferPreflightError::NonTransferable => TokenError::NonTransferable.into(),
+            TransferPreflightError::CpiGuardTransferBlocked => TokenError::CpiGuardTransferBlocked.into(),
+            TransferPreflightError::MintPaused => TokenError::MintPaused.into(),
+        }
+    }
+}
+
+/// Outcome of a successful transfer pre-flight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferVerdict {
+    /// Fee withheld by the mint transfer fee, deducted from the amount credited
+    /// to the destination.
+    pub fee: u64,
+    /// Indicates whether the transfer must be preceded by a memo instruction.
+    pub memo_required: bool,
+    /// Transfer hook program invoked by the transfer, whose extra accounts must
+    /// be provided to the CPI.
+    pub transfer_hook_program_id: Option<Pubkey>,
+}
+
+/// Pre-flight validation of a `TransferChecked` against the mint and token
+/// account extensions.
+///
+/// Signatures are not validated, since a multisig authority only signs through
+/// its signer accounts.
+pub struct TransferPreflight<'a> {
+    /// Sender account.
+    pub from: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Recipient account.
+    pub to: &'a AccountInfo,
+    /// Authority account.
+    pub authority: &'a AccountInfo,
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token
+    pub decimals: u8,
+}
+
+impl TransferPreflight<'_> {
+    /// Validate the transfer, reading the current epoch from the `Clock` sysvar
+    /// if the mint has a transfer fee.
+    #[inline]
+    pub fn check(&self) -> Result<TransferVerdict, TransferPreflightError> {
+        self.check_at_epoch(None)
+    }
+
+    /// Validate the transfer, calculating the transfer fee for the given epoch.
+    #[inline]
+    pub fn check_with_epoch(&self, epoch: u64) -> Result<TransferVerdict, TransferPreflightError> {
+        self.check_at_epoch(Some(epoch))
+    }
+
+    fn check_at_epoch(
+        &self,
+        epoch: Option<u64>,
+    ) -> Result<TransferVerdict, TransferPreflightError> {
+        let source_data = borrow_data(self.from, TokenAccount::BASE_LEN, AccountType::Account)?;
+        // SAFETY: the length and account type were validated above.
+        let source = unsafe { TokenAccount::from_bytes_unchecked(&source_data) };
+
+        if !source.is_initialized() {
+            return Err(ProgramError::UninitializedAccount.into());
+        }
+        if source.is_frozen() {
+            return Err(TransferPreflightError::AccountFrozen);
+        }
+        if source.amount() < self.amount {
+            return Err(TransferPreflightError::InsufficientFunds);
+        }
+        if find_extension::<NonTransferableAccount>(&source_data)?.is_some() {
+            return Err(TransferPreflightError::NonTransferable);
+        }
+
+        if source.mint() != self.mint.key() {
+            return Err(TransferPreflightError::MintMismatch);
+        }
+
+        let mint_data = borrow_data(self.mint, Mint::BASE_LEN, AccountType::Mint)?;
+        // SAFETY: the length and account type were validated above.
+        let mint = unsafe { Mint::from_bytes_unchecked(&mint_data) };
+
+        if !mint.is_initialized() {
+            return Err(ProgramError::UninitializedAccount.into());
+        }
+        if mint.decimals() != self.decimals {
+            return Err(TransferPreflightError::MintDecimalsMismatch);
+        }
+
+        let fee = match find_extension::<TransferFeeConfig>(&mint_data)? {
+            Some(config) => {
+                let epoch = match epoch {
+                    Some(epoch) => epoch,
+                    None => Clock::get()?.epoch,
+                };
+                config
+                    .calculate_epoch_fee(epoch, self.amount)
+                    .ok_or(TransferPreflightError::Overflow)?
+            }
+            None => 0,
+        };
+
+        if let Some(config) = find_extension::<PausableConfig>(&mint_data)? {
+            if config.is_paused() {
+                return Err(TransferPreflightError::MintPaused);
+            }
+        }
+
+        let permanent_delegate = find_extension::<PermanentDelegate>(&mint_data)?
+            .and_then(|extension| extension.delegate());
+        let transfer_hook_program_id = find_extension::<TransferHook>(&mint_data)?
+            .and_then(|extension| extension.program_id())
+            .copied();
+
+        let authority = self.authority.key();
+
+        // the owner is blocked even when it is also a delegate of the account; the
+        // helper is meant to be called by programs, so the transfer always happens
+        // within a CPI
+        if authority == source.owner() {
+            if let Some(cpi_guard) = find_extension::<CpiGuard>(&source_data)? {
+                if cpi_guard.lock_cpi() {
+                    return Err(TransferPreflightError::CpiGuardTransferBlocked);
+                }
+            }
+        }
+
+        if permanent_delegate == Some(authority) {
+            // the permanent delegate is not limited by a delegated amount
+        } else if source.delegate() == Some(authority) {
+            if source.delegated_amount() < self.amount {
+                return Err(TransferPreflightError::InsufficientFunds);
+            }
+        } else if authority != source.owner() {
+            return Err(TransferPreflightError::OwnerMismatch);
+        }
+
+        // a self-transfer only requires a memo if the source requires it
+        if self.from.key() == self.to.key() {
+            return Ok(TransferVerdict {
+                fee,
+                memo_required: memo_required(&source_data)?,
+                transfer_hook_program_id,
+            });
+        }
+
+        let destination_data = borrow_data(self.to, TokenAccount::BASE_LEN, AccountType::Account)?;
+        // SAFETY: the length and account type were validated above.
+        let destination = unsafe { TokenAccount::from_bytes_unchecked(&destination_data) };
+
+        if !destination.is_initialized() {
+            return Err(ProgramError::UninitializedAccount.into());
+        }
+        if destination.is_frozen() {
+            return Err(TransferPreflightError::AccountFrozen);
+        }
+        if destination.mint() != source.mint() {
+            return Err(TransferPreflightError::MintMismatch);
+        }
+
+        Ok(TransferVerdict {
+            fee,
+            memo_required: memo_required(&destination_data)?,
+            transfer_hook_program_id,
+        })
+    }
+}
+
+/// Borrow the data of a token-2022 account, validating its owner, length and
+/// account type.
+#[inline(always)]
+fn borrow_data(
+    account_info: &AccountInfo,
+    base_len: usize,
+    account_type: AccountType,
+) -> Result<Ref<'_, [u8]>, ProgramError> {
+    if !account_info.is_owned_by(&ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data = account_info.try_borrow_data()?;
+    check_account_type(&data, base_len, account_type)?;
+
+    Ok(data)
+}
+
+#[inline(always)]
+fn memo_required(data: &[u8]) -> Result<bool, ProgramError> {
+    Ok(find_extension::<MemoTransfer>(data)?.is_some_and(MemoTransfer::is_enabled))
+}
@@ -0,0 +1,391 @@
+//! `no_std` float helpers shared by the UI amount conversions of the
+//! `InterestBearingConfig` and `ScaledUiAmountConfig` extensions.
+//!
+//! The token program computes UI amounts with `f64` arithmetic from `std`,
+//! which on-chain resolves to the `compiler-builtins` implementations. The
+//! helpers below follow those implementations so the results match the
+//! program bit-for-bit.
+
+// the fdlibm constants are kept verbatim
+#![allow(clippy::approx_constant, clippy::excessive_precision)]
+
+use {core::fmt::Write, pinocchio::program_error::ProgramError};
+
+const LN2_HI: f64 = 6.93147180369123816490e-01; // 0x3fe62e42, 0xfee00000
+const LN2_LO: f64 = 1.90821492927058770002e-10; // 0x3dea39ef, 0x35793c76
+const INV_LN2: f64 = 1.44269504088896338700e+00; // 0x3ff71547, 0x652b82fe
+const P1: f64 = 1.66666666666666019037e-01; // 0x3FC55555, 0x5555553E
+const P2: f64 = -2.77777777770155933842e-03; // 0xBF66C16C, 0x16BEBD93
+const P3: f64 = 6.61375632143793436117e-05; // 0x3F11566A, 0xAF25DE2C
+const P4: f64 = -1.65339022054652515390e-06; // 0xBEBBBD41, 0xC5D26BF1
+const P5: f64 = 4.13813679705723846039e-08; // 0x3E663769, 0x72BEA4D0
+
+/// `e^x`, following the fdlibm implementation used by `compiler-builtins`.
+pub(crate) fn exp(mut x: f64) -> f64 {
+    let x1p1023 = f64::from_bits(0x7fe0000000000000); // 2^1023
+
+    let sign = (x.to_bits() >> 63) as i32;
+    // high word of |x|
+    let hx = (x.to_bits() >> 32) as u32 & 0x7fffffff;
+
+    // special cases: |x| >= 708.39 or nan
+    if hx >= 0x4086232b {
+        if x.is_nan() {
+            return x;
+        }
+        if x > 709.782712893383973096 {
+            // overflow
+            return x * x1p1023;
+        }
+        if x < -745.13321910194110842 {
+            // underflow
+            return 0.;
+        }
+    }
+
+    // argument reduction
+    let (hi, lo, k) = if hx > 0x3fd62e42 {
+        // |x| > 0.5 ln2
+        let k = if hx >= 0x3ff0a2b2 {
+            // |x| >= 1.5 ln2
+            let half = if sign == 0 { 0.5 } else { -0.5 };
+            (INV_LN2 * x + half) as i32
+        } else {
+            1 - sign - sign
+        };
+        // k * LN2_HI is exact here
+        let hi = x - k as f64 * LN2_HI;
+        let lo = k as f64 * LN2_LO;
+        x = hi - lo;
+        (hi, lo, k)
+    } else if hx > 0x3e300000 {
+        // |x| > 2^-28
+        (x, 0., 0)
+    } else {
+        return 1. + x;
+    };
+
+    // x is now in primary range
+    let xx = x * x;
+    let c = x - xx * (P1 + xx * (P2 + xx * (P3 + xx * (P4 + xx * P5))));
+    let y = 1. + (x * c / (2. - c) - lo + hi);
+
+    scalbn(y, k)
+}
+
+/// `x * 2^n`
+fn scalbn(x: f64, mut n: i32) -> f64 {
+    let x1p1023 = f64::from_bits(0x7fe0000000000000); // 2^1023
+    let x1p53 = f64::from_bits(0x4340000000000000); // 2^53
+    let x1p_1022 = f64::from_bits(0x0010000000000000); // 2^-1022
+
+    let mut y = x;
+
+    if n > 1023 {
+        y *= x1p1023;
+        n -= 1023;
+        if n > 1023 {
+            y *= x1p1023;
+            n -= 1023;
+            if n > 1023 {
+                n = 1023;
+            }
+        }
+    } else if n < -1022 {
+        // make sure the final n < -53 to avoid double rounding in the
+        // subnormal range
+        y *= x1p_1022 * x1p53;
+        n += 1022 - 53;
+        if n < -1022 {
+            y *= x1p_1022 * x1p53;
+            n += 1022 - 53;
+            if n < -1022 {
+                n = -1022;
+            }
+        }
+    }
+
+    y * f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+/// `10^decimals`, computed by repeated squaring as `f64::powi` does.
+pub(crate) fn pow10(decimals: u8) -> f64 {
+    let mut base = 10_f64;
+    let mut exponent = decimals;
+    let mut result = 1_f64;
+
+    loop {
+        if exponent & 1 != 0 {
+            result *= base;
+        }
+        exponent >>= 1;
+        if exponent == 0 {
+            break;
+        }
+        base *= base;
+    }
+
+    result
+}
+
+/// Round towards zero.
+pub(crate) fn trunc(x: f64) -> f64 {
+    let mut bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 0x3ff + 12;
+
+    // no fractional part (or nan / inf)
+    if exponent >= 52 + 12 {
+        return x;
+    }
+
+    // |x| < 1 truncates to a signed zero
+    let mask = u64::MAX >> if exponent < 12 { 1 } else { exponent };
+    if bits & mask == 0 {
+        return x;
+    }
+    bits &= !mask;
+
+    f64::from_bits(bits)
+}
+
+/// Round half away from zero.
+pub(crate) fn round(x: f64) -> f64 {
+    let truncated = trunc(x);
+
+    // the difference with the truncated value is exact
+    if x >= 0. && x - truncated >= 0.5 {
+        truncated + 1.
+    } else if x < 0. && truncated - x >= 0.5 {
+        truncated - 1.
+    } else {
+        truncated
+    }
+}
+
+/// Parse a UI amount string the same way as `str::parse::<f64>`.
+#[inline(always)]
+pub(crate) fn parse_ui_amount(ui_amount: &str) -> Result<f64, ProgramError> {
+    ui_amount
+        .parse::<f64>()
+        .map_err(|_| ProgramError::InvalidArgument)
+}
+
+/// Convert a scaled amount back to a raw amount, failing if it does not fit
+/// in a `u64`.
+#[inline(always)]
+pub(crate) fn try_into_amount(amount: f64) -> Result<f64, ProgramError> {
+    if amount > (u64::MAX as f64) || amount < (u64::MIN as f64) || amount.is_nan() {
+        Err(ProgramError::InvalidArgument)
+    } else {
+        Ok(amount)
+    }
+}
+
+/// Format `value` with `decimals` digits into `buffer`, trimming excess zeroes
+/// and an unneeded decimal point.
+///
+/// Returns `None` if the buffer is too small for the formatted value.
+pub(crate) fn format_ui_amount(value: f64, decimals: u8, buffer: &mut [u8]) -> Option<&str> {
+    let mut writer = BufferWriter {
+        buffer: &mut *buffer,
+        len: 0,
+    };
+    write!(writer, "{value:.*}", decimals as usize).ok()?;
+    let len = writer.len;
+
+    // only ascii characters are written by the float formatter
+    let mut ui_amount = core::str::from_utf8(&buffer[..len]).ok()?;
+
+    if decimals > 0 {
+        ui_amount = ui_amount.trim_end_matches('0').trim_end_matches('.');
+    }
+
+    Some(ui_amount)
+}
+
+/// `core::fmt::Write` implementation over a fixed-size buffer.
+struct BufferWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for BufferWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{exp, format_ui_amount, pow10, round, trunc};
+
+    /// Edge inputs of the rounding helpers.
+    const ROUNDING_INPUTS: [f64; 22] = [
+        0.,
+        -0.,
+        0.5,
+        -0.5,
+        0.49999999999999994,
+        -0.49999999999999994,
+        1.5,
+        -1.5,
+        2.5,
+        -2.5,
+        4503599627370495.5,
+        -4503599627370495.5,
+        4503599627370497.,
+        1e300,
+        f64::MIN_POSITIVE,
+        5e-324,
+        -5e-324,
+        f64::MAX,
+        f64::MIN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::EPSILON,
+    ];
+
+    /// Assert that `actual` and `expected` have the same bits, or are both NaN.
+    fn assert_same(actual: f64, expected: f64, input: f64) {
+        assert!(
+            actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+            "{input:e}: {actual:e} != {expected:e}"
+        );
+    }
+
+    /// Assert that `actual` is within one ulp of `expected`, since `std` may use a
+    /// correctly rounded `exp`.
+    fn assert_within_ulp(actual: f64, expected: f64, input: f64) {
+        assert!(
+            actual.to_bits().abs_diff(expected.to_bits()) <= 1
+                || (actual.is_nan() && expected.is_nan()),
+            "{input:e}: {actual:e} != {expected:e}"
+        );
+    }
+
+    #[test]
+    fn test_exp() {
+        let inputs = [
+            0.,
+            -0.,
+            1.,
+            -1.,
+            0.5,
+            1e-9,
+            -1e-9,
+            // |x| <= 2^-28 and subnormal inputs
+            3e-9,
+            5e-324,
+            -5e-324,
+            // around the reduction thresholds
+            0.34657359027997264,
+            1.0397207708399179,
+            708.,
+            -708.,
+            708.39,
+            -708.39,
+            709.782712893383973096,
+            709.79,
+            // subnormal results
+            -710.,
+            -740.,
+            -745.,
+            -745.13321910194110842,
+            -745.14,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ];
+
+        for x in inputs.into_iter().chain(
+            // the exponents of the interest-bearing mint, for each annual rate
+            (i16::MIN..=i16::MAX).map(|rate| (rate as f64 / 10_000.) * 2.5),
+        ) {
+            assert_same(exp(x), libm::exp(x), x);
+            assert_within_ulp(exp(x), x.exp(), x);
+        }
+    }
+
+    #[test]
+    fn test_trunc() {
+        for x in ROUNDING_INPUTS.into_iter().chain([f64::NAN]) {
+            assert_same(trunc(x), x.trunc(), x);
+        }
+    }
+
+    #[test]
+    fn test_round() {
+        for x in ROUNDING_INPUTS.into_iter().chain([f64::NAN]) {
+            assert_same(round(x), x.round(), x);
+        }
+    }
+
+    #[test]
+    fn test_pow10() {
+        for decimals in 0..=u8::MAX {
+            assert_same(
+                pow10(decimals),
+                10_f64.powi(decimals as i32),
+                decimals as f64,
+            );
+        }
+
+        assert_eq!(pow10(0), 1.);
+        assert_eq!(pow10(9), 1_000_000_000.);
+    }
+
+    #[test]
+    fn test_format_ui_amount() {
+        let mut buffer = [0u8; 64];
+
+        assert_eq!(format_ui_amount(0., 0, &mut buffer), Some("0"));
+        assert_eq!(format_ui_amount(100., 0, &mut buffer), Some("100"));
+        assert_eq!(format_ui_amount(0., 9, &mut buffer), Some("0"));
+        assert_eq!(format_ui_amount(100., 9, &mut buffer), Some("100"));
+        assert_eq!(format_ui_amount(1.5, 9, &mut buffer), Some("1.5"));
+        assert_eq!(
+            format_ui_amount(0.000000001, 9, &mut buffer),
+            Some("0.000000001")
+        );
+        assert_eq!(
+            format_ui_amount(18446744073.709551615, 9, &mut buffer),
+            Some("18446744073.709552765")
+        );
+
+        // formatted as `std` does, with excess zeroes trimmed
+        for value in [0.5, 1.5, 2.5, 0.49999999999999994, 123456789.123456789] {
+            for decimals in [0, 9] {
+                let expected = std::format!("{value:.*}", decimals as usize);
+                let expected = if decimals > 0 {
+                    expected.trim_end_matches('0').trim_end_matches('.')
+                } else {
+                    &expected
+                };
+                assert_eq!(
+                    format_ui_amount(value, decimals, &mut buffer),
+                    Some(expected)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_ui_amount_small_buffer() {
+        // "123.450000000" is written before trimming
+        assert_eq!(format_ui_amount(123.45, 9, &mut [0u8; 13]), Some("123.45"));
+        assert_eq!(format_ui_amount(123.45, 9, &mut [0u8; 12]), None);
+
+        assert_eq!(format_ui_amount(123., 0, &mut [0u8; 3]), Some("123"));
+        assert_eq!(format_ui_amount(123., 0, &mut [0u8; 2]), None);
+        assert_eq!(format_ui_amount(0., 0, &mut []), None);
+    }
+}
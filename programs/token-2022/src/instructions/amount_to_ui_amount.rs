@@ -0,0 +1,55 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::{get_return_data, invoke, ReturnData},
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Convert an amount of tokens to a `UiAmount` string, using the given
+/// mint.
+///
+/// The `UiAmount` is read back from the return data set by the token
+/// program, as utf-8 bytes.
+///
+/// ### Accounts:
+///   0. `[]` The mint to calculate for.
+pub struct AmountToUiAmount<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+    /// The amount of tokens to convert.
+    pub amount: u64,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl AmountToUiAmount<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> Result<ReturnData, ProgramError> {
+        // account metadata
+        let account_metas: [AccountMeta; 1] = [AccountMeta::readonly(self.mint.key())];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        let mut instruction_data = [0; 9];
+        instruction_data[0] = 23;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke(&instruction, &[self.mint])?;
+
+        let return_data = get_return_data().ok_or(ProgramError::InvalidAccountData)?;
+
+        if return_data.program_id() != self.token_program {
+            Err(ProgramError::IncorrectProgramId)?;
+        }
+
+        Ok(return_data)
+    }
+}
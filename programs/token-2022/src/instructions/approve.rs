@@ -1,14 +1,15 @@
-use core::slice::from_raw_parts;
+use core::{mem::MaybeUninit, slice::from_raw_parts};
 
 use pinocchio::{
     account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
+    program_error::ProgramError,
     pubkey::Pubkey,
     ProgramResult,
 };
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{instructions::MAX_MULTISIG_SIGNERS, write_bytes, UNINIT_BYTE};
 
 /// Approves a delegate.
 ///
@@ -16,6 +17,7 @@ use crate::{write_bytes, UNINIT_BYTE};
 ///   0. `[WRITE]` The token account.
 ///   1. `[]` The delegate.
 ///   2. `[SIGNER]` The source account owner.
+///   3. ..`3+M` `[SIGNER]` M signer accounts, if the authority is a multisig.
 pub struct Approve<'a, 'b> {
     /// Source Account.
     pub source: &'a AccountInfo,
@@ -23,6 +25,8 @@ pub struct Approve<'a, 'b> {
     pub delegate: &'a AccountInfo,
     /// Source Owner Account
     pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
     /// Amount
     pub amount: u64,
     /// Token Program
@@ -37,12 +41,37 @@ impl Approve<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 3 + self.signers.len();
+
         // Account metadata
-        let account_metas: [AccountMeta; 3] = [
-            AccountMeta::writable(self.source.key()),
-            AccountMeta::readonly(self.delegate.key()),
-            AccountMeta::readonly_signer(self.authority.key()),
-        ];
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_metas` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0, 1 and 2 are always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(self.source.key()));
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::readonly(self.delegate.key()));
+            // - The authority only signs when it is not a multisig
+            acc_metas.get_unchecked_mut(2).write(AccountMeta::new(
+                self.authority.key(),
+                false,
+                self.signers.is_empty(),
+            ));
+        }
+
+        for (account_meta, signer) in acc_metas[3..].iter_mut().zip(self.signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
 
         // Instruction data
         // -  [0]: instruction discriminator (1 byte, u8)
@@ -56,13 +85,30 @@ impl Approve<'_, '_> {
 
         let instruction = Instruction {
             program_id: self.token_program,
-            accounts: &account_metas,
+            accounts: unsafe { from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 9) },
         };
 
-        invoke_signed(
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_infos` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0, 1 and 2 are always present
+            acc_infos.get_unchecked_mut(0).write(self.source);
+            acc_infos.get_unchecked_mut(1).write(self.delegate);
+            acc_infos.get_unchecked_mut(2).write(self.authority);
+        }
+
+        for (account_info, signer) in acc_infos[3..].iter_mut().zip(self.signers.iter()) {
+            account_info.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
             &instruction,
-            &[self.source, self.delegate, self.authority],
+            unsafe { from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
             signers,
         )
     }
@@ -1,10 +1,11 @@
-use core::slice::from_raw_parts;
+use core::{mem::MaybeUninit, slice::from_raw_parts};
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{instructions::MAX_MULTISIG_SIGNERS, write_bytes, UNINIT_BYTE};
 use pinocchio::{
     account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
+    program_error::ProgramError,
     pubkey::Pubkey,
     ProgramResult,
 };
@@ -15,6 +16,7 @@ use pinocchio::{
 ///   0. `[WRITE]` The account to burn from.
 ///   1. `[WRITE]` The token mint.
 ///   2. `[SIGNER]` The account's owner/delegate.
+///   3. ..`3+M` `[SIGNER]` M signer accounts, if the authority is a multisig.
 pub struct BurnChecked<'a, 'b> {
     /// Source of the Burn Account
     pub account: &'a AccountInfo,
@@ -22,6 +24,8 @@ pub struct BurnChecked<'a, 'b> {
     pub mint: &'a AccountInfo,
     /// Owner of the Token Account
     pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
     /// Amount
     pub amount: u64,
     /// Decimals
@@ -38,12 +42,37 @@ impl BurnChecked<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 3 + self.signers.len();
+
         // Account metadata
-        let account_metas: [AccountMeta; 3] = [
-            AccountMeta::writable(self.account.key()),
-            AccountMeta::writable(self.mint.key()),
-            AccountMeta::readonly_signer(self.authority.key()),
-        ];
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_metas` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0, 1 and 2 are always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(self.account.key()));
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::writable(self.mint.key()));
+            // - The authority only signs when it is not a multisig
+            acc_metas.get_unchecked_mut(2).write(AccountMeta::new(
+                self.authority.key(),
+                false,
+                self.signers.is_empty(),
+            ));
+        }
+
+        for (account_meta, signer) in acc_metas[3..].iter_mut().zip(self.signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
 
         // Instruction data
         // -  [0]: instruction discriminator (1 byte, u8)
@@ -60,13 +89,30 @@ impl BurnChecked<'_, '_> {
 
         let instruction = Instruction {
             program_id: self.token_program,
-            accounts: &account_metas,
+            accounts: unsafe { from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
             data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 10) },
         };
 
-        invoke_signed(
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_infos` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0, 1 and 2 are always present
+            acc_infos.get_unchecked_mut(0).write(self.account);
+            acc_infos.get_unchecked_mut(1).write(self.mint);
+            acc_infos.get_unchecked_mut(2).write(self.authority);
+        }
+
+        for (account_info, signer) in acc_infos[3..].iter_mut().zip(self.signers.iter()) {
+            account_info.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
             &instruction,
-            &[self.account, self.mint, self.authority],
+            unsafe { from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
             signers,
         )
     }
@@ -1,17 +1,23 @@
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+
 use pinocchio::{
     account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
+    program_error::ProgramError,
     pubkey::Pubkey,
     ProgramResult,
 };
 
+use crate::instructions::MAX_MULTISIG_SIGNERS;
+
 /// Close an account by transferring all its SOL to the destination account.
 ///
 /// ### Accounts:
 ///   0. `[WRITE]` The account to close.
 ///   1. `[WRITE]` The destination account.
 ///   2. `[SIGNER]` The account's owner.
+///   3. ..`3+M` `[SIGNER]` M signer accounts, if the authority is a multisig.
 pub struct CloseAccount<'a, 'b> {
     /// Token Account.
     pub account: &'a AccountInfo,
@@ -19,6 +25,8 @@ pub struct CloseAccount<'a, 'b> {
     pub destination: &'a AccountInfo,
     /// Owner Account
     pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
     /// Token Program
     pub token_program: &'b Pubkey,
 }
@@ -31,22 +39,64 @@ impl CloseAccount<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 3 + self.signers.len();
+
         // account metadata
-        let account_metas: [AccountMeta; 3] = [
-            AccountMeta::writable(self.account.key()),
-            AccountMeta::writable(self.destination.key()),
-            AccountMeta::readonly_signer(self.authority.key()),
-        ];
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_metas` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0, 1 and 2 are always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(self.account.key()));
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::writable(self.destination.key()));
+            // - The authority only signs when it is not a multisig
+            acc_metas.get_unchecked_mut(2).write(AccountMeta::new(
+                self.authority.key(),
+                false,
+                self.signers.is_empty(),
+            ));
+        }
+
+        for (account_meta, signer) in acc_metas[3..].iter_mut().zip(self.signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
 
         let instruction = Instruction {
             program_id: self.token_program,
-            accounts: &account_metas,
+            accounts: unsafe { from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
             data: &[9],
         };
 
-        invoke_signed(
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_infos` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0, 1 and 2 are always present
+            acc_infos.get_unchecked_mut(0).write(self.account);
+            acc_infos.get_unchecked_mut(1).write(self.destination);
+            acc_infos.get_unchecked_mut(2).write(self.authority);
+        }
+
+        for (account_info, signer) in acc_infos[3..].iter_mut().zip(self.signers.iter()) {
+            account_info.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
             &instruction,
-            &[self.account, self.destination, self.authority],
+            unsafe { from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
             signers,
         )
     }
@@ -0,0 +1,57 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed,
+    instruction::{AccountMeta, Instruction, Signer},
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Creates the native mint.
+///
+/// This instruction only needs to be invoked once after deployment and is
+/// permissionless, Wrapped SOL will not be available until this instruction
+/// is successfully executed.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account (must be a system account).
+///   1. `[WRITE]` The native mint address.
+///   2. `[]` System program for mint account funding.
+pub struct CreateNativeMint<'a, 'b> {
+    /// Funding Account.
+    pub payer: &'a AccountInfo,
+    /// Native Mint Account.
+    pub native_mint: &'a AccountInfo,
+    /// System Program.
+    pub system_program: &'a AccountInfo,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl CreateNativeMint<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable_signer(self.payer.key()),
+            AccountMeta::writable(self.native_mint.key()),
+            AccountMeta::readonly(self.system_program.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: &[31],
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.payer, self.native_mint, self.system_program],
+            signers,
+        )
+    }
+}
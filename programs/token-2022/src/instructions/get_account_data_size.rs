@@ -0,0 +1,89 @@
+use core::slice::from_raw_parts;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::{get_return_data, invoke},
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{extension::consts::ExtensionType, write_bytes, UNINIT_BYTE};
+
+/// Maximum number of extension types accepted by `GetAccountDataSize` and `Reallocate`.
+pub(crate) const MAX_EXTENSION_TYPES: usize = 28;
+
+/// Gets the required size of an account for the given mint as a
+/// little-endian `u64`.
+///
+/// The size is read back from the return data set by the token program.
+///
+/// ### Accounts:
+///   0. `[]` The mint to calculate for.
+pub struct GetAccountDataSize<'a, 'b, 'c> {
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+    /// Additional extension types to include in the returned account size.
+    pub extension_types: &'c [ExtensionType],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl GetAccountDataSize<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> Result<u64, ProgramError> {
+        if self.extension_types.len() > MAX_EXTENSION_TYPES {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // account metadata
+        let account_metas: [AccountMeta; 1] = [AccountMeta::readonly(self.mint.key())];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..]: extension types (2 bytes each, u16)
+        let mut instruction_data = [UNINIT_BYTE; 1 + 2 * MAX_EXTENSION_TYPES];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[21]);
+        // Set extension types as u16 at offset [1..]
+        for (i, extension_type) in self.extension_types.iter().enumerate() {
+            write_bytes(
+                &mut instruction_data[1 + 2 * i..3 + 2 * i],
+                &(*extension_type as u16).to_le_bytes(),
+            );
+        }
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: unsafe {
+                from_raw_parts(
+                    instruction_data.as_ptr() as _,
+                    1 + 2 * self.extension_types.len(),
+                )
+            },
+        };
+
+        invoke(&instruction, &[self.mint])?;
+
+        read_u64_return_data(self.token_program)
+    }
+}
+
+/// Read a little-endian `u64` set as return data by `program_id`.
+#[inline(always)]
+pub(crate) fn read_u64_return_data(program_id: &Pubkey) -> Result<u64, ProgramError> {
+    let return_data = get_return_data().ok_or(ProgramError::InvalidAccountData)?;
+
+    if return_data.program_id() != program_id {
+        Err(ProgramError::IncorrectProgramId)?;
+    }
+
+    let bytes: [u8; 8] = return_data
+        .as_slice()
+        .try_into()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    Ok(u64::from_le_bytes(bytes))
+}
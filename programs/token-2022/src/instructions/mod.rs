@@ -1,9 +1,12 @@
+mod amount_to_ui_amount;
 mod approve;
 mod approve_checked;
 mod burn;
 mod burn_checked;
 mod close_account;
+mod create_native_mint;
 mod freeze_account;
+mod get_account_data_size;
 mod initialize_account;
 mod initialize_account_2;
 mod initialize_account_3;
@@ -13,19 +16,25 @@ mod initialize_multisig;
 mod initialize_multisig_2;
 mod mint_to;
 mod mint_to_checked;
+mod reallocate;
 mod revoke;
 mod set_authority;
 mod sync_native;
 mod thaw_account;
 mod transfer;
 mod transfer_checked;
+mod ui_amount_to_amount;
+mod withdraw_excess_lamports;
 
+pub use amount_to_ui_amount::*;
 pub use approve::*;
 pub use approve_checked::*;
 pub use burn::*;
 pub use burn_checked::*;
 pub use close_account::*;
+pub use create_native_mint::*;
 pub use freeze_account::*;
+pub use get_account_data_size::*;
 pub use initialize_account::*;
 pub use initialize_account_2::*;
 pub use initialize_account_3::*;
@@ -35,9 +44,12 @@ pub use initialize_multisig::*;
 pub use initialize_multisig_2::*;
 pub use mint_to::*;
 pub use mint_to_checked::*;
+pub use reallocate::*;
 pub use revoke::*;
 pub use set_authority::*;
 pub use sync_native::*;
 pub use thaw_account::*;
 pub use transfer::*;
 pub use transfer_checked::*;
+pub use ui_amount_to_amount::*;
+pub use withdraw_excess_lamports::*;
@@ -0,0 +1,93 @@
+use core::slice::from_raw_parts;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    extension::consts::ExtensionType, instructions::get_account_data_size::MAX_EXTENSION_TYPES,
+    write_bytes, UNINIT_BYTE,
+};
+
+/// Check to see if a token account is large enough for a list of
+/// `ExtensionType`s, and if not, use reallocation to increase the data
+/// size.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to reallocate.
+///   1. `[SIGNER, WRITE]` The payer account to fund reallocation.
+///   2. `[]` System program for reallocation funding.
+///   3. `[SIGNER]` The account's owner.
+pub struct Reallocate<'a, 'b, 'c> {
+    /// Token Account.
+    pub account: &'a AccountInfo,
+    /// Payer Account.
+    pub payer: &'a AccountInfo,
+    /// System Program.
+    pub system_program: &'a AccountInfo,
+    /// Owner Account.
+    pub owner: &'a AccountInfo,
+    /// New extension types to include in the reallocated account.
+    pub extension_types: &'c [ExtensionType],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl Reallocate<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.extension_types.len() > MAX_EXTENSION_TYPES {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // account metadata
+        let account_metas: [AccountMeta; 4] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::writable_signer(self.payer.key()),
+            AccountMeta::readonly(self.system_program.key()),
+            AccountMeta::readonly_signer(self.owner.key()),
+        ];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..]: extension types (2 bytes each, u16)
+        let mut instruction_data = [UNINIT_BYTE; 1 + 2 * MAX_EXTENSION_TYPES];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[29]);
+        // Set extension types as u16 at offset [1..]
+        for (i, extension_type) in self.extension_types.iter().enumerate() {
+            write_bytes(
+                &mut instruction_data[1 + 2 * i..3 + 2 * i],
+                &(*extension_type as u16).to_le_bytes(),
+            );
+        }
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: unsafe {
+                from_raw_parts(
+                    instruction_data.as_ptr() as _,
+                    1 + 2 * self.extension_types.len(),
+                )
+            },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.account, self.payer, self.system_program, self.owner],
+            signers,
+        )
+    }
+}
@@ -1,21 +1,29 @@
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+
 use pinocchio::{
     account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
+    program_error::ProgramError,
     pubkey::Pubkey,
     ProgramResult,
 };
 
+use crate::instructions::MAX_MULTISIG_SIGNERS;
+
 /// Revokes the delegate's authority.
 ///
 /// ### Accounts:
 ///   0. `[WRITE]` The source account.
 ///   1. `[SIGNER]` The source account owner.
+///   2. ..`2+M` `[SIGNER]` M signer accounts, if the authority is a multisig.
 pub struct Revoke<'a, 'b> {
     /// Source Account.
     pub source: &'a AccountInfo,
     ///  Source Owner Account.
     pub authority: &'a AccountInfo,
+    /// Signer Accounts (for multisig support)
+    pub signers: &'a [AccountInfo],
     /// Token Program
     pub token_program: &'b Pubkey,
 }
@@ -28,18 +36,61 @@ impl Revoke<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 2 + self.signers.len();
+
         // account metadata
-        let account_metas: [AccountMeta; 2] = [
-            AccountMeta::writable(self.source.key()),
-            AccountMeta::readonly_signer(self.authority.key()),
-        ];
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 2 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_metas` is sized to 2 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0 and 1 are always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(self.source.key()));
+            // - The authority only signs when it is not a multisig
+            acc_metas.get_unchecked_mut(1).write(AccountMeta::new(
+                self.authority.key(),
+                false,
+                self.signers.is_empty(),
+            ));
+        }
+
+        for (account_meta, signer) in acc_metas[2..].iter_mut().zip(self.signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
 
         let instruction = Instruction {
             program_id: self.token_program,
-            accounts: &account_metas,
+            accounts: unsafe { from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
             data: &[5],
         };
 
-        invoke_signed(&instruction, &[self.source, self.authority], signers)
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 2 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `acc_infos` is sized to 2 + MAX_MULTISIG_SIGNERS
+            // - Indexes 0 and 1 are always present
+            acc_infos.get_unchecked_mut(0).write(self.source);
+            acc_infos.get_unchecked_mut(1).write(self.authority);
+        }
+
+        for (account_info, signer) in acc_infos[2..].iter_mut().zip(self.signers.iter()) {
+            account_info.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe { from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
     }
 }
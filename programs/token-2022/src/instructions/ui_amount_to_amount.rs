@@ -0,0 +1,64 @@
+use core::slice::from_raw_parts;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{instructions::get_account_data_size::read_u64_return_data, write_bytes, UNINIT_BYTE};
+
+/// Maximum length of the `UiAmount` string accepted by `UiAmountToAmount`.
+const MAX_UI_AMOUNT_LEN: usize = 64;
+
+/// Convert a `UiAmount` of tokens to a little-endian `u64` raw Amount,
+/// using the given mint.
+///
+/// The amount is read back from the return data set by the token program.
+///
+/// ### Accounts:
+///   0. `[]` The mint to calculate for.
+pub struct UiAmountToAmount<'a, 'b, 'c> {
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+    /// The `ui_amount` of tokens to convert.
+    pub ui_amount: &'c str,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl UiAmountToAmount<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> Result<u64, ProgramError> {
+        if self.ui_amount.len() > MAX_UI_AMOUNT_LEN {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // account metadata
+        let account_metas: [AccountMeta; 1] = [AccountMeta::readonly(self.mint.key())];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..]: ui_amount (utf-8 bytes)
+        let mut instruction_data = [UNINIT_BYTE; 1 + MAX_UI_AMOUNT_LEN];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[24]);
+        // Set ui_amount at offset [1..]
+        write_bytes(&mut instruction_data[1..], self.ui_amount.as_bytes());
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: unsafe {
+                from_raw_parts(instruction_data.as_ptr() as _, 1 + self.ui_amount.len())
+            },
+        };
+
+        invoke(&instruction, &[self.mint])?;
+
+        read_u64_return_data(self.token_program)
+    }
+}
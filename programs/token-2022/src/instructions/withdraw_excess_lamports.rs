@@ -0,0 +1,54 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed,
+    instruction::{AccountMeta, Instruction, Signer},
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Rescue SOL sent to any token program owned account by sending them to
+/// any other account, leaving behind only lamports for rent exemption.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Source Account owned by the token program.
+///   1. `[WRITE]` Destination account.
+///   2. `[SIGNER]` Authority.
+pub struct WithdrawExcessLamports<'a, 'b> {
+    /// Source Account.
+    pub source: &'a AccountInfo,
+    /// Destination Account.
+    pub destination: &'a AccountInfo,
+    /// Authority Account.
+    pub authority: &'a AccountInfo,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl WithdrawExcessLamports<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.source.key()),
+            AccountMeta::writable(self.destination.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: &[38],
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.source, self.destination, self.authority],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,16 @@
+//! Interface shared by the Token and the Token-2022 programs.
+//!
+//! Programs accepting the accounts of either token program dispatch on the owner
+//! of the mint or token account through [`TokenProgram`], and read their base
+//! state through [`InterfaceMint`] and [`InterfaceTokenAccount`].
+//!
+//! The instructions of this crate that take a `token_program` and exist in both
+//! programs (e.g. `TransferChecked` or `MintTo`) can be invoked with
+//! [`TokenProgram::id`]. Extension instructions are only supported by the
+//! Token-2022 program.
+
+mod state;
+mod token_program;
+
+pub use state::*;
+pub use token_program::*;
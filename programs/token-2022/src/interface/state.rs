@@ -0,0 +1,160 @@
+use core::ops::Deref;
+
+use pinocchio::{
+    account_info::{AccountInfo, Ref},
+    program_error::ProgramError,
+};
+
+use crate::{
+    extension::{consts::AccountType, tlv::check_account_type},
+    interface::TokenProgram,
+    state::{Mint, TokenAccount},
+};
+
+/// Validate the data of an account owned by the given token program.
+///
+/// Token accounts have the exact base length, while Token-2022 accounts may carry
+/// extensions of the given account type after the base state.
+#[inline(always)]
+fn check_account_data(
+    token_program: TokenProgram,
+    data: &[u8],
+    base_len: usize,
+    account_type: AccountType,
+) -> Result<(), ProgramError> {
+    match token_program {
+        TokenProgram::Token if data.len() != base_len => Err(ProgramError::InvalidAccountData),
+        TokenProgram::Token => Ok(()),
+        TokenProgram::Token2022 => check_account_type(data, base_len, account_type),
+    }
+}
+
+/// Mint of either the Token or the Token-2022 program.
+///
+/// Only the base state is read: the extensions of a Token-2022 mint are ignored.
+#[repr(transparent)]
+pub struct InterfaceMint(Mint);
+
+impl InterfaceMint {
+    /// Return an `InterfaceMint` from the given account info.
+    ///
+    /// This method performs owner, length and account type validation on `AccountInfo`,
+    /// safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<InterfaceMint>, ProgramError> {
+        let token_program = TokenProgram::from_account_info(account_info)?;
+
+        Ref::try_map(account_info.try_borrow_data()?, |data| {
+            Self::from_bytes(token_program, data)
+        })
+        .map_err(|(_, e)| e)
+    }
+
+    /// Return an `InterfaceMint` from the given account info.
+    ///
+    /// This method performs owner, length and account type validation on `AccountInfo`,
+    /// but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        let token_program = TokenProgram::from_account_info(account_info)?;
+        Self::from_bytes(token_program, account_info.borrow_data_unchecked())
+    }
+
+    /// Return an `InterfaceMint` from the data of a mint owned by the given token
+    /// program.
+    ///
+    /// This method performs length and account type validation on the bytes.
+    #[inline]
+    pub fn from_bytes(token_program: TokenProgram, bytes: &[u8]) -> Result<&Self, ProgramError> {
+        check_account_data(token_program, bytes, Mint::BASE_LEN, AccountType::Mint)?;
+        // SAFETY: `InterfaceMint` is a transparent wrapper of `Mint`.
+        Ok(unsafe { &*(Mint::from_bytes_unchecked(bytes) as *const Mint as *const Self) })
+    }
+}
+
+impl Deref for InterfaceMint {
+    type Target = Mint;
+
+    #[inline(always)]
+    fn deref(&self) -> &Mint {
+        &self.0
+    }
+}
+
+/// Token account of either the Token or the Token-2022 program.
+///
+/// Only the base state is read: the extensions of a Token-2022 token account are
+/// ignored.
+#[repr(transparent)]
+pub struct InterfaceTokenAccount(TokenAccount);
+
+impl InterfaceTokenAccount {
+    /// Return an `InterfaceTokenAccount` from the given account info.
+    ///
+    /// This method performs owner, length and account type validation on `AccountInfo`,
+    /// safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<InterfaceTokenAccount>, ProgramError> {
+        let token_program = TokenProgram::from_account_info(account_info)?;
+
+        Ref::try_map(account_info.try_borrow_data()?, |data| {
+            Self::from_bytes(token_program, data)
+        })
+        .map_err(|(_, e)| e)
+    }
+
+    /// Return an `InterfaceTokenAccount` from the given account info.
+    ///
+    /// This method performs owner, length and account type validation on `AccountInfo`,
+    /// but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        let token_program = TokenProgram::from_account_info(account_info)?;
+        Self::from_bytes(token_program, account_info.borrow_data_unchecked())
+    }
+
+    /// Return an `InterfaceTokenAccount` from the data of a token account owned by the
+    /// given token program.
+    ///
+    /// This method performs length and account type validation on the bytes.
+    #[inline]
+    pub fn from_bytes(token_program: TokenProgram, bytes: &[u8]) -> Result<&Self, ProgramError> {
+        check_account_data(
+            token_program,
+            bytes,
+            TokenAccount::BASE_LEN,
+            AccountType::Account,
+        )?;
+        // SAFETY: `InterfaceTokenAccount` is a transparent wrapper of `TokenAccount`.
+        Ok(unsafe {
+            &*(TokenAccount::from_bytes_unchecked(bytes) as *const TokenAccount as *const Self)
+        })
+    }
+}
+
+impl Deref for InterfaceTokenAccount {
+    type Target = TokenAccount;
+
+    #[inline(always)]
+    fn deref(&self) -> &TokenAccount {
+        &self.0
+    }
+}
@@ -0,0 +1,67 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// Program id of the Token program.
+pub const TOKEN_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Token program owning a mint or a token account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenProgram {
+    /// The Token program.
+    Token,
+    /// The Token-2022 program.
+    Token2022,
+}
+
+impl TokenProgram {
+    /// Return the token program with the given program id.
+    ///
+    /// Fails with `IncorrectProgramId` if the program id is neither the Token nor
+    /// the Token-2022 program.
+    #[inline]
+    pub fn from_program_id(program_id: &Pubkey) -> Result<Self, ProgramError> {
+        if program_id == &crate::ID {
+            Ok(TokenProgram::Token2022)
+        } else if program_id == &TOKEN_PROGRAM_ID {
+            Ok(TokenProgram::Token)
+        } else {
+            Err(ProgramError::IncorrectProgramId)
+        }
+    }
+
+    /// Return the token program owning the given mint or token account.
+    ///
+    /// Fails with `InvalidAccountOwner` if the account is owned by neither the Token
+    /// nor the Token-2022 program.
+    #[inline]
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_program_id(account_info.owner()).map_err(|_| ProgramError::InvalidAccountOwner)
+    }
+
+    /// Return the program id of the token program.
+    #[inline(always)]
+    pub const fn id(&self) -> &'static Pubkey {
+        match self {
+            TokenProgram::Token => &TOKEN_PROGRAM_ID,
+            TokenProgram::Token2022 => &crate::ID,
+        }
+    }
+
+    /// Check that the given program account is the token program, e.g. before
+    /// invoking the program passed by the caller.
+    ///
+    /// Fails with `IncorrectProgramId` if it is not.
+    #[inline]
+    pub fn check_program(&self, program: &AccountInfo) -> Result<(), ProgramError> {
+        if program.key() != self.id() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        Ok(())
+    }
+
+    /// Indicates whether the token program supports extensions.
+    #[inline(always)]
+    pub const fn supports_extensions(&self) -> bool {
+        matches!(self, TokenProgram::Token2022)
+    }
+}
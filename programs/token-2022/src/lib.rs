@@ -1,5 +1,7 @@
 #![no_std]
+pub mod error;
 pub mod extension;
+pub mod interface;
 pub mod instructions;
 pub mod state;
 
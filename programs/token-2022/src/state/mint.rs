@@ -4,7 +4,13 @@ use pinocchio::{
     pubkey::Pubkey,
 };
 
-use crate::ID;
+use crate::{
+    extension::{
+        consts::{AccountType, ExtensionType},
+        tlv::{check_account_type, get_account_len},
+    },
+    ID,
+};
 
 /// Mint data.
 #[repr(C)]
@@ -38,10 +44,18 @@ impl Mint {
     /// The length of the `Mint` account data.
     pub const BASE_LEN: usize = core::mem::size_of::<Mint>();
 
+    /// Return the account data length of a mint carrying the given extensions.
+    ///
+    /// Fails with `InvalidArgument` if any of the extensions has a variable length.
+    #[inline]
+    pub const fn get_account_len(extension_types: &[ExtensionType]) -> Result<usize, ProgramError> {
+        get_account_len(Self::BASE_LEN, extension_types)
+    }
+
     /// Return a `Mint` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner, length and account type validation on `AccountInfo`,
+    /// safe borrowing the account data.
     #[inline]
     pub fn from_account_info(account_info: &AccountInfo) -> Result<Ref<Mint>, ProgramError> {
         if account_info.data_len() < Self::BASE_LEN {
@@ -50,15 +64,17 @@ impl Mint {
         if !account_info.is_owned_by(&ID) {
             return Err(ProgramError::InvalidAccountOwner);
         }
-        Ok(Ref::map(account_info.try_borrow_data()?, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+        Ref::try_map(account_info.try_borrow_data()?, |data| {
+            check_account_type(data, Self::BASE_LEN, AccountType::Mint)?;
+            Ok(unsafe { Self::from_bytes_unchecked(data) })
+        })
+        .map_err(|(_, e)| e)
     }
 
     /// Return a `Mint` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner, length and account type validation on `AccountInfo`,
+    /// but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -74,9 +90,9 @@ impl Mint {
         if account_info.owner() != &ID {
             return Err(ProgramError::InvalidAccountOwner);
         }
-        Ok(Self::from_bytes_unchecked(
-            account_info.borrow_data_unchecked(),
-        ))
+        let data = account_info.borrow_data_unchecked();
+        check_account_type(data, Self::BASE_LEN, AccountType::Mint)?;
+        Ok(Self::from_bytes_unchecked(data))
     }
 
     /// Return a `Mint` from the given bytes.
@@ -5,7 +5,13 @@ use pinocchio::{
     pubkey::Pubkey,
 };
 
-use crate::ID;
+use crate::{
+    extension::{
+        consts::{AccountType, ExtensionType},
+        tlv::{check_account_type, get_account_len},
+    },
+    ID,
+};
 
 /// Token account data.
 #[repr(C)]
@@ -51,10 +57,18 @@ pub struct TokenAccount {
 impl TokenAccount {
     pub const BASE_LEN: usize = core::mem::size_of::<TokenAccount>();
 
+    /// Return the account data length of a token account carrying the given extensions.
+    ///
+    /// Fails with `InvalidArgument` if any of the extensions has a variable length.
+    #[inline]
+    pub const fn get_account_len(extension_types: &[ExtensionType]) -> Result<usize, ProgramError> {
+        get_account_len(Self::BASE_LEN, extension_types)
+    }
+
     /// Return a `TokenAccount` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner, length and account type validation on `AccountInfo`,
+    /// safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
@@ -65,15 +79,17 @@ impl TokenAccount {
         if !account_info.is_owned_by(&ID) {
             return Err(ProgramError::InvalidAccountData);
         }
-        Ok(Ref::map(account_info.try_borrow_data()?, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+        Ref::try_map(account_info.try_borrow_data()?, |data| {
+            check_account_type(data, Self::BASE_LEN, AccountType::Account)?;
+            Ok(unsafe { Self::from_bytes_unchecked(data) })
+        })
+        .map_err(|(_, e)| e)
     }
 
     /// Return a `TokenAccount` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner, length and account type validation on `AccountInfo`,
+    /// but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -89,9 +105,9 @@ impl TokenAccount {
         if account_info.owner() != &ID {
             return Err(ProgramError::InvalidAccountData);
         }
-        Ok(Self::from_bytes_unchecked(
-            account_info.borrow_data_unchecked(),
-        ))
+        let data = account_info.borrow_data_unchecked();
+        check_account_type(data, Self::BASE_LEN, AccountType::Account)?;
+        Ok(Self::from_bytes_unchecked(data))
     }
 
     /// Return a `TokenAccount` from the given bytes.
@@ -0,0 +1,58 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::instructions::ProofInstruction;
+
+/// Close a proof context state account, reclaiming its lamports.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The proof context account to close
+///   1. `[WRITE]` The destination account for lamports
+///   2. `[SIGNER]` The context account's authority
+pub struct CloseContextState<'a> {
+    /// Proof context account.
+    pub context_state_account: &'a AccountInfo,
+
+    /// Destination account for lamports.
+    pub destination: &'a AccountInfo,
+
+    /// Context account's authority.
+    pub authority: &'a AccountInfo,
+}
+
+impl CloseContextState<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // account metadata
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.context_state_account.key()),
+            AccountMeta::writable(self.destination.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // instruction data
+        // -  [0]: instruction discriminator
+        let instruction_data = [ProofInstruction::CloseContextState as u8];
+
+        let instruction = Instruction {
+            program_id: &crate::ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.context_state_account, self.destination, self.authority],
+            signers,
+        )
+    }
+}
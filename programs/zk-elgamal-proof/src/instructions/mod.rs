@@ -0,0 +1,115 @@
+mod close_context_state;
+mod verify_proof;
+mod verify_proof_from_account;
+
+pub use close_context_state::*;
+pub use verify_proof::*;
+pub use verify_proof_from_account::*;
+
+use pinocchio::account_info::AccountInfo;
+
+use crate::state::ProofType;
+
+/// Instructions of the ZK ElGamal Proof program.
+///
+/// Each proof verification instruction verifies a type of zero-knowledge proof,
+/// either provided as instruction data or pre-written to an account, and
+/// optionally stores its context data in a context state account.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofInstruction {
+    CloseContextState,
+    VerifyZeroCiphertext,
+    VerifyCiphertextCiphertextEquality,
+    VerifyCiphertextCommitmentEquality,
+    VerifyPubkeyValidity,
+    VerifyPercentageWithCap,
+    VerifyBatchedRangeProofU64,
+    VerifyBatchedRangeProofU128,
+    VerifyBatchedRangeProofU256,
+    VerifyGroupedCiphertext2HandlesValidity,
+    VerifyBatchedGroupedCiphertext2HandlesValidity,
+    VerifyGroupedCiphertext3HandlesValidity,
+    VerifyBatchedGroupedCiphertext3HandlesValidity,
+}
+
+impl ProofInstruction {
+    /// Return the type of the proof verified by the instruction, or `None` for
+    /// `CloseContextState`.
+    pub const fn proof_type(self) -> Option<ProofType> {
+        match self {
+            ProofInstruction::CloseContextState => None,
+            ProofInstruction::VerifyZeroCiphertext => Some(ProofType::ZeroCiphertext),
+            ProofInstruction::VerifyCiphertextCiphertextEquality => {
+                Some(ProofType::CiphertextCiphertextEquality)
+            }
+            ProofInstruction::VerifyCiphertextCommitmentEquality => {
+                Some(ProofType::CiphertextCommitmentEquality)
+            }
+            ProofInstruction::VerifyPubkeyValidity => Some(ProofType::PubkeyValidity),
+            ProofInstruction::VerifyPercentageWithCap => Some(ProofType::PercentageWithCap),
+            ProofInstruction::VerifyBatchedRangeProofU64 => Some(ProofType::BatchedRangeProofU64),
+            ProofInstruction::VerifyBatchedRangeProofU128 => Some(ProofType::BatchedRangeProofU128),
+            ProofInstruction::VerifyBatchedRangeProofU256 => Some(ProofType::BatchedRangeProofU256),
+            ProofInstruction::VerifyGroupedCiphertext2HandlesValidity => {
+                Some(ProofType::GroupedCiphertext2HandlesValidity)
+            }
+            ProofInstruction::VerifyBatchedGroupedCiphertext2HandlesValidity => {
+                Some(ProofType::BatchedGroupedCiphertext2HandlesValidity)
+            }
+            ProofInstruction::VerifyGroupedCiphertext3HandlesValidity => {
+                Some(ProofType::GroupedCiphertext3HandlesValidity)
+            }
+            ProofInstruction::VerifyBatchedGroupedCiphertext3HandlesValidity => {
+                Some(ProofType::BatchedGroupedCiphertext3HandlesValidity)
+            }
+        }
+    }
+}
+
+/// Context state account written by a proof verification instruction.
+///
+/// The account must be owned by the ZK ElGamal Proof program and allocated with
+/// the length given by [`ProofType::context_state_len`].
+pub struct ContextStateInfo<'a> {
+    /// The proof context account.
+    pub context_state_account: &'a AccountInfo,
+
+    /// The authority that can close the proof context account.
+    pub context_state_authority: &'a AccountInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{instructions::ProofInstruction, state::ProofType};
+
+    const VERIFY_INSTRUCTIONS: [ProofInstruction; 12] = [
+        ProofInstruction::VerifyZeroCiphertext,
+        ProofInstruction::VerifyCiphertextCiphertextEquality,
+        ProofInstruction::VerifyCiphertextCommitmentEquality,
+        ProofInstruction::VerifyPubkeyValidity,
+        ProofInstruction::VerifyPercentageWithCap,
+        ProofInstruction::VerifyBatchedRangeProofU64,
+        ProofInstruction::VerifyBatchedRangeProofU128,
+        ProofInstruction::VerifyBatchedRangeProofU256,
+        ProofInstruction::VerifyGroupedCiphertext2HandlesValidity,
+        ProofInstruction::VerifyBatchedGroupedCiphertext2HandlesValidity,
+        ProofInstruction::VerifyGroupedCiphertext3HandlesValidity,
+        ProofInstruction::VerifyBatchedGroupedCiphertext3HandlesValidity,
+    ];
+
+    #[test]
+    fn test_instruction_discriminators() {
+        assert_eq!(ProofInstruction::CloseContextState as u8, 0);
+        assert_eq!(ProofInstruction::CloseContextState.proof_type(), None);
+
+        for (i, instruction) in VERIFY_INSTRUCTIONS.into_iter().enumerate() {
+            assert_eq!(instruction as u8, i as u8 + 1);
+
+            // the proof types follow the order of the verification instructions
+            let proof_type = instruction.proof_type().unwrap();
+            assert_eq!(proof_type as u8, instruction as u8);
+            assert_eq!(ProofType::try_from(instruction as u8).unwrap(), proof_type);
+        }
+    }
+}
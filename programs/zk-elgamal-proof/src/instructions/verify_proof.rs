@@ -0,0 +1,187 @@
+use core::{mem::MaybeUninit, slice};
+
+use pinocchio::{
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    ProgramResult,
+};
+
+use crate::{
+    instructions::{ContextStateInfo, ProofInstruction},
+    state::ProofType,
+    write_bytes, UNINIT_BYTE,
+};
+
+/// Maximum length of the proof data of a proof verification instruction.
+const MAX_PROOF_DATA_LEN: usize = ProofType::BatchedRangeProofU256.proof_data_len();
+
+/// Verify a zero-knowledge proof provided as instruction data.
+///
+/// The proof data is the context data followed by the proof, with the length
+/// given by [`ProofType::proof_data_len`] for the proof type of `instruction`.
+///
+/// ### Accounts:
+///   * Creating a proof context account
+///   0. `[WRITE]` The proof context account
+///   1. `[]` The proof context account authority
+///
+///   * Otherwise
+///     None
+pub struct VerifyProof<'a, 'b> {
+    /// Proof verification instruction.
+    pub instruction: ProofInstruction,
+
+    /// Context data followed by the proof.
+    pub proof_data: &'b [u8],
+
+    /// Context state account to store the context data, if any.
+    pub context_state: Option<ContextStateInfo<'a>>,
+}
+
+impl VerifyProof<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut instruction_data = [UNINIT_BYTE; 1 + MAX_PROOF_DATA_LEN];
+        let data = self.instruction_data(&mut instruction_data)?;
+
+        match &self.context_state {
+            Some(context_state) => {
+                // account metadata
+                let account_metas: [AccountMeta; 2] = [
+                    AccountMeta::writable(context_state.context_state_account.key()),
+                    AccountMeta::readonly(context_state.context_state_authority.key()),
+                ];
+
+                let instruction = Instruction {
+                    program_id: &crate::ID,
+                    accounts: &account_metas,
+                    data,
+                };
+
+                invoke_signed(
+                    &instruction,
+                    &[
+                        context_state.context_state_account,
+                        context_state.context_state_authority,
+                    ],
+                    signers,
+                )
+            }
+            None => {
+                let instruction = Instruction {
+                    program_id: &crate::ID,
+                    accounts: &[],
+                    data,
+                };
+
+                invoke_signed::<0>(&instruction, &[], signers)
+            }
+        }
+    }
+
+    /// Write the instruction data to `buffer`, validating the proof data length.
+    fn instruction_data<'d>(
+        &self,
+        buffer: &'d mut [MaybeUninit<u8>; 1 + MAX_PROOF_DATA_LEN],
+    ) -> Result<&'d [u8], ProgramError> {
+        let proof_type = self
+            .instruction
+            .proof_type()
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        let proof_data_len = self.proof_data.len();
+
+        if proof_data_len != proof_type.proof_data_len() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // instruction data
+        // -  [0]: instruction discriminator
+        // -  [1..1 + N]: proof data
+        write_bytes(buffer, &[self.instruction as u8]);
+        write_bytes(&mut buffer[1..], self.proof_data);
+
+        // SAFETY: the first `1 + proof_data_len` bytes are initialized.
+        Ok(unsafe { slice::from_raw_parts(buffer.as_ptr() as _, 1 + proof_data_len) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::program_error::ProgramError;
+
+    use crate::{
+        instructions::{ProofInstruction, VerifyProof},
+        state::ProofType,
+        UNINIT_BYTE,
+    };
+
+    use super::MAX_PROOF_DATA_LEN;
+
+    #[test]
+    fn test_instruction_data() {
+        let proof_data = [7; ProofType::PubkeyValidity.proof_data_len()];
+        let verify_proof = VerifyProof {
+            instruction: ProofInstruction::VerifyPubkeyValidity,
+            proof_data: &proof_data,
+            context_state: None,
+        };
+
+        let mut buffer = [UNINIT_BYTE; 1 + MAX_PROOF_DATA_LEN];
+        let data = verify_proof.instruction_data(&mut buffer).unwrap();
+
+        assert_eq!(data.len(), 1 + 96);
+        assert_eq!(data[0], ProofInstruction::VerifyPubkeyValidity as u8);
+        assert_eq!(&data[1..], &proof_data);
+    }
+
+    #[test]
+    fn test_instruction_data_largest_proof() {
+        let proof_data = [7; MAX_PROOF_DATA_LEN];
+        let verify_proof = VerifyProof {
+            instruction: ProofInstruction::VerifyBatchedRangeProofU256,
+            proof_data: &proof_data,
+            context_state: None,
+        };
+
+        let mut buffer = [UNINIT_BYTE; 1 + MAX_PROOF_DATA_LEN];
+        let data = verify_proof.instruction_data(&mut buffer).unwrap();
+
+        assert_eq!(data.len(), 1 + 1064);
+        assert_eq!(data[0], 8);
+        assert_eq!(&data[1..], &proof_data);
+    }
+
+    #[test]
+    fn test_instruction_data_invalid() {
+        let mut buffer = [UNINIT_BYTE; 1 + MAX_PROOF_DATA_LEN];
+
+        // the proof data must match the length of the proof type
+        let proof_data = [7; ProofType::PubkeyValidity.proof_data_len() - 1];
+        let verify_proof = VerifyProof {
+            instruction: ProofInstruction::VerifyPubkeyValidity,
+            proof_data: &proof_data,
+            context_state: None,
+        };
+        assert_eq!(
+            verify_proof.instruction_data(&mut buffer),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // closing a context state does not verify a proof
+        let verify_proof = VerifyProof {
+            instruction: ProofInstruction::CloseContextState,
+            proof_data: &[],
+            context_state: None,
+        };
+        assert_eq!(
+            verify_proof.instruction_data(&mut buffer),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    ProgramResult,
+};
+
+use crate::instructions::{ContextStateInfo, ProofInstruction};
+
+/// Verify a zero-knowledge proof pre-written to an account.
+///
+/// The proof data, the context data followed by the proof, is read from the
+/// proof account at `offset`.
+///
+/// ### Accounts:
+///   0. `[]` Account to read the proof from
+///
+///   * Creating a proof context account
+///   1. `[WRITE]` The proof context account
+///   2. `[]` The proof context account authority
+pub struct VerifyProofFromAccount<'a> {
+    /// Proof verification instruction.
+    pub instruction: ProofInstruction,
+
+    /// Account to read the proof from.
+    pub proof_account: &'a AccountInfo,
+
+    /// Byte offset of the proof data in the proof account.
+    pub offset: u32,
+
+    /// Context state account to store the context data, if any.
+    pub context_state: Option<ContextStateInfo<'a>>,
+}
+
+impl VerifyProofFromAccount<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.instruction.proof_type().is_none() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let instruction_data = instruction_data(self.instruction, self.offset);
+
+        match &self.context_state {
+            Some(context_state) => {
+                // account metadata
+                let account_metas: [AccountMeta; 3] = [
+                    AccountMeta::readonly(self.proof_account.key()),
+                    AccountMeta::writable(context_state.context_state_account.key()),
+                    AccountMeta::readonly(context_state.context_state_authority.key()),
+                ];
+
+                let instruction = Instruction {
+                    program_id: &crate::ID,
+                    accounts: &account_metas,
+                    data: &instruction_data,
+                };
+
+                invoke_signed(
+                    &instruction,
+                    &[
+                        self.proof_account,
+                        context_state.context_state_account,
+                        context_state.context_state_authority,
+                    ],
+                    signers,
+                )
+            }
+            None => {
+                // account metadata
+                let account_metas: [AccountMeta; 1] =
+                    [AccountMeta::readonly(self.proof_account.key())];
+
+                let instruction = Instruction {
+                    program_id: &crate::ID,
+                    accounts: &account_metas,
+                    data: &instruction_data,
+                };
+
+                invoke_signed(&instruction, &[self.proof_account], signers)
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn instruction_data(instruction: ProofInstruction, offset: u32) -> [u8; 5] {
+    // instruction data
+    // -  [0  ]: instruction discriminator
+    // -  [1..5]: offset
+    let mut instruction_data = [0; 5];
+    instruction_data[0] = instruction as u8;
+    instruction_data[1..5].copy_from_slice(&offset.to_le_bytes());
+
+    instruction_data
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instructions::ProofInstruction;
+
+    use super::instruction_data;
+
+    #[test]
+    fn test_instruction_data() {
+        assert_eq!(
+            instruction_data(ProofInstruction::VerifyZeroCiphertext, 0),
+            [1, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            instruction_data(
+                ProofInstruction::VerifyBatchedGroupedCiphertext3HandlesValidity,
+                0x0403_0201
+            ),
+            [12, 1, 2, 3, 4]
+        );
+    }
+}
@@ -0,0 +1,414 @@
+use pinocchio::{
+    account_info::{AccountInfo, Ref},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::ID;
+
+/// Type of a zero-knowledge proof verified by the ZK ElGamal Proof program.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofType {
+    /// Empty proof type used to distinguish if a proof context account is initialized
+    Uninitialized,
+    ZeroCiphertext,
+    CiphertextCiphertextEquality,
+    CiphertextCommitmentEquality,
+    PubkeyValidity,
+    PercentageWithCap,
+    BatchedRangeProofU64,
+    BatchedRangeProofU128,
+    BatchedRangeProofU256,
+    GroupedCiphertext2HandlesValidity,
+    BatchedGroupedCiphertext2HandlesValidity,
+    GroupedCiphertext3HandlesValidity,
+    BatchedGroupedCiphertext3HandlesValidity,
+}
+
+impl ProofType {
+    /// Length of the context data of the proof, which is stored in a context
+    /// state account.
+    pub const fn context_len(self) -> usize {
+        match self {
+            ProofType::Uninitialized => 0,
+            ProofType::ZeroCiphertext => 96,
+            ProofType::CiphertextCiphertextEquality => 192,
+            ProofType::CiphertextCommitmentEquality => 128,
+            ProofType::PubkeyValidity => 32,
+            ProofType::PercentageWithCap => 104,
+            ProofType::BatchedRangeProofU64
+            | ProofType::BatchedRangeProofU128
+            | ProofType::BatchedRangeProofU256 => 264,
+            ProofType::GroupedCiphertext2HandlesValidity => 160,
+            ProofType::BatchedGroupedCiphertext2HandlesValidity => 256,
+            ProofType::GroupedCiphertext3HandlesValidity => 224,
+            ProofType::BatchedGroupedCiphertext3HandlesValidity => 352,
+        }
+    }
+
+    /// Length of the proof data, i.e. the context data followed by the proof
+    /// itself.
+    pub const fn proof_data_len(self) -> usize {
+        let proof_len = match self {
+            ProofType::Uninitialized => 0,
+            ProofType::ZeroCiphertext => 96,
+            ProofType::CiphertextCiphertextEquality => 224,
+            ProofType::CiphertextCommitmentEquality => 192,
+            ProofType::PubkeyValidity => 64,
+            ProofType::PercentageWithCap => 256,
+            ProofType::BatchedRangeProofU64 => 672,
+            ProofType::BatchedRangeProofU128 => 736,
+            ProofType::BatchedRangeProofU256 => 800,
+            ProofType::GroupedCiphertext2HandlesValidity
+            | ProofType::BatchedGroupedCiphertext2HandlesValidity => 160,
+            ProofType::GroupedCiphertext3HandlesValidity
+            | ProofType::BatchedGroupedCiphertext3HandlesValidity => 192,
+        };
+
+        self.context_len() + proof_len
+    }
+
+    /// Length of a context state account holding the context data of the proof.
+    ///
+    /// Context state accounts must be allocated with this exact length before a
+    /// proof verification instruction writes to them.
+    pub const fn context_state_len(self) -> usize {
+        ProofContextStateMeta::LEN + self.context_len()
+    }
+}
+
+impl TryFrom<u8> for ProofType {
+    type Error = ProgramError;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            // SAFETY: `value` is guaranteed to be in the range of the enum variants.
+            0..=12 => Ok(unsafe { core::mem::transmute::<u8, ProofType>(value) }),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Header of a proof context state account, without the context data.
+#[repr(C)]
+pub struct ProofContextStateMeta {
+    /// The proof context authority that can close the account.
+    context_state_authority: Pubkey,
+
+    /// The proof type for the context data.
+    proof_type: u8,
+}
+
+const _: () = assert!(core::mem::size_of::<ProofContextStateMeta>() == 33);
+const _: () = assert!(core::mem::align_of::<ProofContextStateMeta>() == 1);
+
+impl ProofContextStateMeta {
+    /// The length of the `ProofContextStateMeta` header.
+    pub const LEN: usize = core::mem::size_of::<ProofContextStateMeta>();
+
+    /// Return a `ProofContextStateMeta` from the given account info.
+    ///
+    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
+    /// the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<ProofContextStateMeta>, ProgramError> {
+        if account_info.data_len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !account_info.is_owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Ok(Ref::map(account_info.try_borrow_data()?, |data| unsafe {
+            Self::from_bytes_unchecked(data)
+        }))
+    }
+
+    /// Return a `ProofContextStateMeta` from the given account info.
+    ///
+    /// This method performs owner and length validation on `AccountInfo`, but does not
+    /// perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        if account_info.data_len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if account_info.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Ok(Self::from_bytes_unchecked(
+            account_info.borrow_data_unchecked(),
+        ))
+    }
+
+    /// Return a `ProofContextStateMeta` from the given bytes.
+    ///
+    /// This method performs length validation on the bytes.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
+    /// Return a `ProofContextStateMeta` from the given bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains a valid representation of
+    /// `ProofContextStateMeta`.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const ProofContextStateMeta)
+    }
+
+    /// Return the authority that can close the context state account.
+    #[inline(always)]
+    pub fn context_state_authority(&self) -> &Pubkey {
+        &self.context_state_authority
+    }
+
+    /// Return the type of the proof whose context data is stored.
+    #[inline]
+    pub fn proof_type(&self) -> Result<ProofType, ProgramError> {
+        ProofType::try_from(self.proof_type)
+    }
+}
+
+/// Proof context state account: the header followed by the context data of
+/// the verified proof.
+pub struct ProofContextState<'a> {
+    /// The header of the account.
+    meta: &'a ProofContextStateMeta,
+
+    /// The proof type for the context data.
+    proof_type: ProofType,
+
+    /// The context data of the proof.
+    proof_context: &'a [u8],
+}
+
+impl<'a> ProofContextState<'a> {
+    /// Return a `ProofContextState` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo`, but does not perform
+    /// the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &'a AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if account_info.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_bytes(account_info.borrow_data_unchecked())
+    }
+
+    /// Return a `ProofContextState` from the given bytes.
+    ///
+    /// This method validates that the account is initialized and that the length
+    /// of the context data matches its proof type.
+    #[inline]
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        let meta = ProofContextStateMeta::from_bytes(bytes)?;
+        let proof_type = meta.proof_type()?;
+
+        if proof_type == ProofType::Uninitialized {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if bytes.len() != proof_type.context_state_len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            meta,
+            proof_type,
+            proof_context: &bytes[ProofContextStateMeta::LEN..],
+        })
+    }
+
+    /// Return the authority that can close the context state account.
+    #[inline(always)]
+    pub fn context_state_authority(&self) -> &'a Pubkey {
+        self.meta.context_state_authority()
+    }
+
+    /// Return the type of the proof whose context data is stored.
+    #[inline(always)]
+    pub fn proof_type(&self) -> ProofType {
+        self.proof_type
+    }
+
+    /// Return the context data of the proof.
+    #[inline(always)]
+    pub fn proof_context(&self) -> &'a [u8] {
+        self.proof_context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::program_error::ProgramError;
+
+    use crate::state::{ProofContextState, ProofContextStateMeta, ProofType};
+
+    // Lengths of the ElGamal types making up the context data.
+    const PUBKEY_LEN: usize = 32;
+    const CIPHERTEXT_LEN: usize = 64;
+    const COMMITMENT_LEN: usize = 32;
+    const GROUPED_2_HANDLES_CIPHERTEXT_LEN: usize = COMMITMENT_LEN + 2 * 32;
+    const GROUPED_3_HANDLES_CIPHERTEXT_LEN: usize = COMMITMENT_LEN + 3 * 32;
+    const RANGE_PROOF_CONTEXT_LEN: usize = 8 * COMMITMENT_LEN + 8;
+
+    #[test]
+    fn test_context_len() {
+        let expected = [
+            (ProofType::Uninitialized, 0),
+            (ProofType::ZeroCiphertext, PUBKEY_LEN + CIPHERTEXT_LEN),
+            (
+                ProofType::CiphertextCiphertextEquality,
+                2 * PUBKEY_LEN + 2 * CIPHERTEXT_LEN,
+            ),
+            (
+                ProofType::CiphertextCommitmentEquality,
+                PUBKEY_LEN + CIPHERTEXT_LEN + COMMITMENT_LEN,
+            ),
+            (ProofType::PubkeyValidity, PUBKEY_LEN),
+            (ProofType::PercentageWithCap, 3 * COMMITMENT_LEN + 8),
+            (ProofType::BatchedRangeProofU64, RANGE_PROOF_CONTEXT_LEN),
+            (ProofType::BatchedRangeProofU128, RANGE_PROOF_CONTEXT_LEN),
+            (ProofType::BatchedRangeProofU256, RANGE_PROOF_CONTEXT_LEN),
+            (
+                ProofType::GroupedCiphertext2HandlesValidity,
+                2 * PUBKEY_LEN + GROUPED_2_HANDLES_CIPHERTEXT_LEN,
+            ),
+            (
+                ProofType::BatchedGroupedCiphertext2HandlesValidity,
+                2 * PUBKEY_LEN + 2 * GROUPED_2_HANDLES_CIPHERTEXT_LEN,
+            ),
+            (
+                ProofType::GroupedCiphertext3HandlesValidity,
+                3 * PUBKEY_LEN + GROUPED_3_HANDLES_CIPHERTEXT_LEN,
+            ),
+            (
+                ProofType::BatchedGroupedCiphertext3HandlesValidity,
+                3 * PUBKEY_LEN + 2 * GROUPED_3_HANDLES_CIPHERTEXT_LEN,
+            ),
+        ];
+
+        for (proof_type, context_len) in expected {
+            assert_eq!(proof_type.context_len(), context_len, "{proof_type:?}");
+            assert_eq!(
+                proof_type.context_state_len(),
+                ProofContextStateMeta::LEN + context_len
+            );
+        }
+    }
+
+    #[test]
+    fn test_proof_data_len() {
+        // proof data is the context data followed by the proof
+        let expected = [
+            (ProofType::ZeroCiphertext, 96),
+            (ProofType::CiphertextCiphertextEquality, 224),
+            (ProofType::CiphertextCommitmentEquality, 192),
+            (ProofType::PubkeyValidity, 64),
+            (ProofType::PercentageWithCap, 256),
+            (ProofType::BatchedRangeProofU64, 672),
+            (ProofType::BatchedRangeProofU128, 736),
+            (ProofType::BatchedRangeProofU256, 800),
+            (ProofType::GroupedCiphertext2HandlesValidity, 160),
+            (ProofType::BatchedGroupedCiphertext2HandlesValidity, 160),
+            (ProofType::GroupedCiphertext3HandlesValidity, 192),
+            (ProofType::BatchedGroupedCiphertext3HandlesValidity, 192),
+        ];
+
+        for (proof_type, proof_len) in expected {
+            assert_eq!(
+                proof_type.proof_data_len(),
+                proof_type.context_len() + proof_len,
+                "{proof_type:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_proof_type_try_from() {
+        for value in 0..=12u8 {
+            assert_eq!(ProofType::try_from(value).unwrap() as u8, value);
+        }
+        assert_eq!(
+            ProofType::try_from(13),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_context_state_meta_layout() {
+        assert_eq!(ProofContextStateMeta::LEN, 33);
+
+        let mut data = [0; ProofContextStateMeta::LEN];
+        // - [0..32]: context state authority
+        data[..32].copy_from_slice(&[5; 32]);
+        // - [32]: proof type
+        data[32] = ProofType::PubkeyValidity as u8;
+
+        let meta = ProofContextStateMeta::from_bytes(&data).unwrap();
+        assert_eq!(meta.context_state_authority(), &[5; 32]);
+        assert_eq!(meta.proof_type(), Ok(ProofType::PubkeyValidity));
+
+        assert!(matches!(
+            ProofContextStateMeta::from_bytes(&data[..32]),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+
+    #[test]
+    fn test_context_state_layout() {
+        const LEN: usize = ProofType::PubkeyValidity.context_state_len();
+
+        let mut data = [0; LEN];
+        data[..32].copy_from_slice(&[5; 32]);
+        data[32] = ProofType::PubkeyValidity as u8;
+        // - [33..]: context data
+        data[33..].copy_from_slice(&[9; PUBKEY_LEN]);
+
+        let state = ProofContextState::from_bytes(&data).unwrap();
+        assert_eq!(state.context_state_authority(), &[5; 32]);
+        assert_eq!(state.proof_type(), ProofType::PubkeyValidity);
+        assert_eq!(state.proof_context(), &[9; PUBKEY_LEN]);
+
+        // the context data length must match the proof type
+        assert!(matches!(
+            ProofContextState::from_bytes(&data[..LEN - 1]),
+            Err(ProgramError::InvalidAccountData)
+        ));
+
+        data[32] = 13;
+        assert!(matches!(
+            ProofContextState::from_bytes(&data),
+            Err(ProgramError::InvalidAccountData)
+        ));
+
+        data[32] = ProofType::Uninitialized as u8;
+        assert!(matches!(
+            ProofContextState::from_bytes(&data),
+            Err(ProgramError::UninitializedAccount)
+        ));
+    }
+}
@@ -324,8 +324,20 @@ unsafe fn inner_invoke_signed_with_bounds<const MAX_ACCOUNTS: usize>(
             Ok(())
         })?;
 
+    // The host runtime returns the error of a failed invocation, which aborts
+    // the execution on-chain.
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    return unsafe {
+        crate::host::invoke(
+            instruction,
+            from_raw_parts(accounts.as_ptr() as _, instruction.accounts.len()),
+            signers_seeds,
+        )
+    };
+
     // SAFETY: At this point it is guaranteed that account infos are borrowable
     // according to their mutability on the instruction.
+    #[cfg(not(all(feature = "host-runtime", not(target_os = "solana"))))]
     unsafe {
         invoke_signed_unchecked(
             instruction,
@@ -334,6 +346,7 @@ unsafe fn inner_invoke_signed_with_bounds<const MAX_ACCOUNTS: usize>(
         );
     }
 
+    #[cfg(not(all(feature = "host-runtime", not(target_os = "solana"))))]
     Ok(())
 }
 
@@ -429,7 +442,12 @@ pub unsafe fn invoke_signed_unchecked(
         };
     }
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    if let Err(error) = crate::host::invoke(instruction, accounts, signers_seeds) {
+        panic!("cross-program invocation failed: {error:?}");
+    }
+
+    #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
     core::hint::black_box((instruction, accounts, signers_seeds));
 }
 
@@ -450,7 +468,10 @@ pub fn set_return_data(data: &[u8]) {
         crate::syscalls::sol_set_return_data(data.as_ptr(), data.len() as u64)
     };
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    crate::host::set_return_data(data);
+
+    #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
     core::hint::black_box(data);
 }
 
@@ -510,7 +531,25 @@ pub fn get_return_data() -> Option<ReturnData> {
         }
     }
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    {
+        crate::host::return_data().map(|(program_id, bytes)| {
+            const UNINIT_BYTE: core::mem::MaybeUninit<u8> = core::mem::MaybeUninit::<u8>::uninit();
+            let mut data = [UNINIT_BYTE; MAX_RETURN_DATA];
+
+            for (byte, value) in data.iter_mut().zip(bytes.iter()) {
+                byte.write(*value);
+            }
+
+            ReturnData {
+                program_id,
+                data,
+                size: bytes.len(),
+            }
+        })
+    }
+
+    #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
     core::hint::black_box(None)
 }
 
@@ -0,0 +1,242 @@
+//! Operations on the points of the BN254 (alt_bn128) curve.
+//!
+//! Points and scalars use the big-endian encoding of the Ethereum precompiles
+//! ([EIP-196] and [EIP-197]): a [`G1`] point is the concatenation of its `x` and
+//! `y` coordinates, and a [`G2`] point the concatenation of its `x` and `y`
+//! coordinates, each with its imaginary part first. The point at infinity is
+//! encoded as zeros.
+//!
+//! [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+//! [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+
+use crate::program_error::ProgramError;
+
+/// Number of bytes in a field element.
+pub const FIELD_BYTES: usize = 32;
+
+/// Number of bytes in a [`G1`] point.
+pub const G1_BYTES: usize = FIELD_BYTES * 2;
+
+/// Number of bytes in a [`G2`] point.
+pub const G2_BYTES: usize = FIELD_BYTES * 4;
+
+/// Number of bytes in a [`G1Compressed`] point.
+pub const G1_COMPRESSED_BYTES: usize = FIELD_BYTES;
+
+/// Number of bytes in a [`G2Compressed`] point.
+pub const G2_COMPRESSED_BYTES: usize = FIELD_BYTES * 2;
+
+/// A point of the G1 group.
+pub type G1 = [u8; G1_BYTES];
+
+/// A point of the G2 group.
+pub type G2 = [u8; G2_BYTES];
+
+/// A compressed point of the G1 group.
+pub type G1Compressed = [u8; G1_COMPRESSED_BYTES];
+
+/// A compressed point of the G2 group.
+pub type G2Compressed = [u8; G2_COMPRESSED_BYTES];
+
+/// A scalar, as a big-endian integer.
+pub type Scalar = [u8; FIELD_BYTES];
+
+/// Group operation adding two G1 points.
+pub(crate) const ADD: u64 = 0;
+
+/// Group operation multiplying a G1 point by a scalar.
+pub(crate) const MUL: u64 = 2;
+
+/// Group operation checking a pairing.
+pub(crate) const PAIRING: u64 = 3;
+
+/// Compression operation of a G1 point.
+pub(crate) const G1_COMPRESS: u64 = 0;
+
+/// Decompression operation of a G1 point.
+pub(crate) const G1_DECOMPRESS: u64 = 1;
+
+/// Compression operation of a G2 point.
+pub(crate) const G2_COMPRESS: u64 = 2;
+
+/// Decompression operation of a G2 point.
+pub(crate) const G2_DECOMPRESS: u64 = 3;
+
+/// Element of a pairing check, a G1 point and a G2 point.
+///
+/// The layout of the type matches the encoding of the pairing input, so a slice of
+/// elements is passed to the runtime without being copied.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PairingElement {
+    /// The G1 point.
+    pub g1: G1,
+
+    /// The G2 point.
+    pub g2: G2,
+}
+
+/// Error of an operation on BN254 points.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AltBn128Error {
+    /// A point is not a valid encoding of a point of its group.
+    InvalidPoint,
+}
+
+impl From<AltBn128Error> for ProgramError {
+    fn from(_: AltBn128Error) -> Self {
+        ProgramError::InvalidArgument
+    }
+}
+
+/// Return the sum of the given G1 points.
+#[inline]
+pub fn add(left: &G1, right: &G1) -> Result<G1, AltBn128Error> {
+    let mut input = [0u8; G1_BYTES * 2];
+    input[..G1_BYTES].copy_from_slice(left);
+    input[G1_BYTES..].copy_from_slice(right);
+
+    group_op(ADD, &input)
+}
+
+/// Return the product of the given G1 point and scalar.
+///
+/// The scalar is not reduced modulo the order of the group.
+#[inline]
+pub fn multiply(point: &G1, scalar: &Scalar) -> Result<G1, AltBn128Error> {
+    let mut input = [0u8; G1_BYTES + FIELD_BYTES];
+    input[..G1_BYTES].copy_from_slice(point);
+    input[G1_BYTES..].copy_from_slice(scalar);
+
+    group_op(MUL, &input)
+}
+
+/// Indicate whether the product of the pairings of the given elements is the
+/// identity, as the `ecPairing` precompile.
+///
+/// The check succeeds for an empty list of elements.
+#[inline]
+pub fn pairing(elements: &[PairingElement]) -> Result<bool, AltBn128Error> {
+    // SAFETY: `PairingElement` is an array of bytes without padding.
+    let input = unsafe {
+        core::slice::from_raw_parts(
+            elements.as_ptr() as *const u8,
+            core::mem::size_of_val(elements),
+        )
+    };
+
+    group_op::<FIELD_BYTES>(PAIRING, input).map(|result| result[FIELD_BYTES - 1] == 1)
+}
+
+/// Return the compressed encoding of the given G1 point.
+#[inline(always)]
+pub fn g1_compress(point: &G1) -> Result<G1Compressed, AltBn128Error> {
+    compression(G1_COMPRESS, point)
+}
+
+/// Return the G1 point of the given compressed encoding.
+#[inline(always)]
+pub fn g1_decompress(point: &G1Compressed) -> Result<G1, AltBn128Error> {
+    compression(G1_DECOMPRESS, point)
+}
+
+/// Return the compressed encoding of the given G2 point.
+#[inline(always)]
+pub fn g2_compress(point: &G2) -> Result<G2Compressed, AltBn128Error> {
+    compression(G2_COMPRESS, point)
+}
+
+/// Return the G2 point of the given compressed encoding.
+#[inline(always)]
+pub fn g2_decompress(point: &G2Compressed) -> Result<G2, AltBn128Error> {
+    compression(G2_DECOMPRESS, point)
+}
+
+/// Apply the given group operation to the given input.
+#[inline]
+fn group_op<const N: usize>(op: u64, input: &[u8]) -> Result<[u8; N], AltBn128Error> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut result = core::mem::MaybeUninit::<[u8; N]>::uninit();
+
+        // SAFETY: `input` is valid for reads of `input.len()` bytes and `result` is
+        // valid for writes of the result of the operation.
+        let error = unsafe {
+            crate::syscalls::sol_alt_bn128_group_op(
+                op,
+                input.as_ptr(),
+                input.len() as u64,
+                result.as_mut_ptr() as *mut u8,
+            )
+        };
+
+        match error {
+            // SAFETY: The syscall has initialized the result.
+            crate::SUCCESS => Ok(unsafe { result.assume_init() }),
+            _ => Err(AltBn128Error::InvalidPoint),
+        }
+    }
+
+    #[cfg(all(feature = "crypto-fallback", not(target_os = "solana")))]
+    {
+        let mut result = [0u8; N];
+        crate::crypto::fallback::alt_bn128_group_op(op, input, &mut result)?;
+        Ok(result)
+    }
+
+    #[cfg(all(not(feature = "crypto-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box((op, input));
+        panic!("alt_bn128 operations are only available on target `solana` or with the `crypto-fallback` feature")
+    }
+}
+
+/// Apply the given compression operation to the given point.
+#[inline]
+fn compression<const N: usize>(op: u64, input: &[u8]) -> Result<[u8; N], AltBn128Error> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut result = core::mem::MaybeUninit::<[u8; N]>::uninit();
+
+        // SAFETY: `input` is valid for reads of `input.len()` bytes and `result` is
+        // valid for writes of the result of the operation.
+        let error = unsafe {
+            crate::syscalls::sol_alt_bn128_compression(
+                op,
+                input.as_ptr(),
+                input.len() as u64,
+                result.as_mut_ptr() as *mut u8,
+            )
+        };
+
+        match error {
+            // SAFETY: The syscall has initialized the result.
+            crate::SUCCESS => Ok(unsafe { result.assume_init() }),
+            _ => Err(AltBn128Error::InvalidPoint),
+        }
+    }
+
+    #[cfg(all(feature = "crypto-fallback", not(target_os = "solana")))]
+    {
+        let mut result = [0u8; N];
+        crate::crypto::fallback::alt_bn128_compression(op, input, &mut result)?;
+        Ok(result)
+    }
+
+    #[cfg(all(not(feature = "crypto-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box((op, input));
+        panic!("alt_bn128 operations are only available on target `solana` or with the `crypto-fallback` feature")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairing_element_layout() {
+        assert_eq!(core::mem::size_of::<PairingElement>(), G1_BYTES + G2_BYTES);
+        assert_eq!(core::mem::align_of::<PairingElement>(), 1);
+    }
+}
@@ -0,0 +1,90 @@
+//! Modular exponentiation of big integers.
+//!
+//! Integers are big-endian byte arrays of up to [`MAX_LEN`] bytes. This is
+//! typically used to verify RSA signatures.
+
+use crate::program_error::ProgramError;
+
+/// Maximum number of bytes of each integer.
+pub const MAX_LEN: usize = 512;
+
+/// Parameters of the `sol_big_mod_exp` syscall.
+#[cfg(target_os = "solana")]
+#[repr(C)]
+struct BigModExpParams {
+    base: *const u8,
+    base_len: u64,
+    exponent: *const u8,
+    exponent_len: u64,
+    modulus: *const u8,
+    modulus_len: u64,
+}
+
+/// Error of a modular exponentiation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BigModExpError {
+    /// An integer is longer than [`MAX_LEN`] bytes.
+    InvalidLength,
+}
+
+impl From<BigModExpError> for ProgramError {
+    fn from(_: BigModExpError) -> Self {
+        ProgramError::InvalidArgument
+    }
+}
+
+/// Return `base ^ exponent % modulus`.
+///
+/// The result has the same length as the modulus, padded with leading zeros. A zero
+/// modulus results in zero.
+#[inline]
+pub fn big_mod_exp<const N: usize>(
+    base: &[u8],
+    exponent: &[u8],
+    modulus: &[u8; N],
+) -> Result<[u8; N], BigModExpError> {
+    // The runtime aborts the program on an invalid length.
+    if base.len() > MAX_LEN || exponent.len() > MAX_LEN || N > MAX_LEN {
+        return Err(BigModExpError::InvalidLength);
+    }
+
+    #[cfg(target_os = "solana")]
+    {
+        let params = BigModExpParams {
+            base: base.as_ptr(),
+            base_len: base.len() as u64,
+            exponent: exponent.as_ptr(),
+            exponent_len: exponent.len() as u64,
+            modulus: modulus.as_ptr(),
+            modulus_len: N as u64,
+        };
+        let mut result = core::mem::MaybeUninit::<[u8; N]>::uninit();
+
+        // SAFETY: `params` points to valid integers and `result` is valid for writes
+        // of `N` bytes.
+        unsafe {
+            crate::syscalls::sol_big_mod_exp(
+                &params as *const _ as *const u8,
+                result.as_mut_ptr() as *mut u8,
+            );
+        }
+
+        // SAFETY: The syscall has initialized the result.
+        Ok(unsafe { result.assume_init() })
+    }
+
+    #[cfg(all(feature = "crypto-fallback", not(target_os = "solana")))]
+    {
+        let mut result = [0u8; N];
+        crate::crypto::fallback::big_mod_exp(base, exponent, modulus, &mut result);
+        Ok(result)
+    }
+
+    #[cfg(all(not(feature = "crypto-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box((base, exponent, modulus));
+        panic!(
+            "big_mod_exp is only available on target `solana` or with the `crypto-fallback` feature"
+        )
+    }
+}
@@ -0,0 +1,46 @@
+//! Operations on the points of the Edwards form of curve25519, the curve of
+//! ed25519 signatures.
+//!
+//! Note that the Edwards form has a cofactor of 8: a valid point is not necessarily
+//! in the prime-order subgroup.
+
+use super::{Curve25519Error, Scalar, ADD, CURVE25519_EDWARDS, MUL, POINT_BYTES, SUB};
+
+/// A compressed Edwards point.
+pub type Point = [u8; POINT_BYTES];
+
+/// Indicate whether the given bytes are a valid Edwards point.
+#[inline(always)]
+pub fn validate_point(point: &Point) -> bool {
+    super::validate_point(CURVE25519_EDWARDS, point)
+}
+
+/// Return the sum of the given points.
+#[inline(always)]
+pub fn add(left: &Point, right: &Point) -> Result<Point, Curve25519Error> {
+    super::group_op(CURVE25519_EDWARDS, ADD, left, right)
+}
+
+/// Return the difference of the given points.
+#[inline(always)]
+pub fn subtract(left: &Point, right: &Point) -> Result<Point, Curve25519Error> {
+    super::group_op(CURVE25519_EDWARDS, SUB, left, right)
+}
+
+/// Return the product of the given scalar and point.
+#[inline(always)]
+pub fn multiply(scalar: &Scalar, point: &Point) -> Result<Point, Curve25519Error> {
+    super::group_op(CURVE25519_EDWARDS, MUL, scalar, point)
+}
+
+/// Return the sum of the products of the given scalars and points.
+///
+/// The number of scalars and points must be the same, up to
+/// [`MAX_MULTISCALAR_POINTS`](super::MAX_MULTISCALAR_POINTS).
+#[inline(always)]
+pub fn multiscalar_multiply(
+    scalars: &[Scalar],
+    points: &[Point],
+) -> Result<Point, Curve25519Error> {
+    super::multiscalar_mul(CURVE25519_EDWARDS, scalars, points)
+}
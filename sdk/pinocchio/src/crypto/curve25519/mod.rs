@@ -0,0 +1,177 @@
+//! Operations on the points of curve25519.
+//!
+//! Points are represented by their 32-byte compressed encoding, either in the
+//! [`edwards`] form used by ed25519 or in the prime-order [`ristretto`] group.
+//! Scalars are 32-byte little-endian integers, which must be reduced modulo the
+//! order of the group.
+
+pub mod edwards;
+pub mod ristretto;
+
+use crate::program_error::ProgramError;
+
+/// Number of bytes in a compressed point.
+pub const POINT_BYTES: usize = 32;
+
+/// Number of bytes in a scalar.
+pub const SCALAR_BYTES: usize = 32;
+
+/// Maximum number of points of a multiscalar multiplication.
+pub const MAX_MULTISCALAR_POINTS: usize = 512;
+
+/// A scalar, as a little-endian integer.
+pub type Scalar = [u8; SCALAR_BYTES];
+
+/// Curve id of the Edwards form, as expected by the syscalls.
+pub(crate) const CURVE25519_EDWARDS: u64 = 0;
+
+/// Curve id of the Ristretto group, as expected by the syscalls.
+pub(crate) const CURVE25519_RISTRETTO: u64 = 1;
+
+/// Group operation adding two points.
+pub(crate) const ADD: u64 = 0;
+
+/// Group operation subtracting two points.
+pub(crate) const SUB: u64 = 1;
+
+/// Group operation multiplying a point by a scalar.
+pub(crate) const MUL: u64 = 2;
+
+/// Error of an operation on curve25519 points.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Curve25519Error {
+    /// The numbers of scalars and points of a multiscalar multiplication differ,
+    /// or exceed [`MAX_MULTISCALAR_POINTS`].
+    InvalidLength,
+
+    /// A point is not a valid encoding or a scalar is not reduced.
+    InvalidInput,
+}
+
+impl From<Curve25519Error> for ProgramError {
+    fn from(_: Curve25519Error) -> Self {
+        ProgramError::InvalidArgument
+    }
+}
+
+/// Indicate whether the given bytes are a valid point of the given curve.
+#[inline]
+fn validate_point(curve_id: u64, point: &[u8; POINT_BYTES]) -> bool {
+    #[cfg(target_os = "solana")]
+    {
+        let mut result = 0u8;
+
+        // SAFETY: `point` is valid for reads of a point.
+        let error = unsafe {
+            crate::syscalls::sol_curve_validate_point(curve_id, point.as_ptr(), &mut result)
+        };
+
+        error == crate::SUCCESS
+    }
+
+    #[cfg(all(feature = "crypto-fallback", not(target_os = "solana")))]
+    {
+        crate::crypto::fallback::curve_validate_point(curve_id, point)
+    }
+
+    #[cfg(all(not(feature = "crypto-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box((curve_id, point));
+        panic!("curve25519 operations are only available on target `solana` or with the `crypto-fallback` feature")
+    }
+}
+
+/// Apply the given group operation to the points of the given curve.
+///
+/// For a multiplication, the left input is the scalar.
+#[inline]
+fn group_op(
+    curve_id: u64,
+    op: u64,
+    left: &[u8; 32],
+    right: &[u8; POINT_BYTES],
+) -> Result<[u8; POINT_BYTES], Curve25519Error> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut point = core::mem::MaybeUninit::<[u8; POINT_BYTES]>::uninit();
+
+        // SAFETY: `left` and `right` are valid for reads of 32 bytes and `point`
+        // is valid for writes of a point.
+        let result = unsafe {
+            crate::syscalls::sol_curve_group_op(
+                curve_id,
+                op,
+                left.as_ptr(),
+                right.as_ptr(),
+                point.as_mut_ptr() as *mut u8,
+            )
+        };
+
+        match result {
+            // SAFETY: The syscall has initialized the point.
+            crate::SUCCESS => Ok(unsafe { point.assume_init() }),
+            _ => Err(Curve25519Error::InvalidInput),
+        }
+    }
+
+    #[cfg(all(feature = "crypto-fallback", not(target_os = "solana")))]
+    {
+        crate::crypto::fallback::curve_group_op(curve_id, op, left, right)
+            .ok_or(Curve25519Error::InvalidInput)
+    }
+
+    #[cfg(all(not(feature = "crypto-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box((curve_id, op, left, right));
+        panic!("curve25519 operations are only available on target `solana` or with the `crypto-fallback` feature")
+    }
+}
+
+/// Compute the sum of the products of the given scalars and points of the given
+/// curve.
+#[inline]
+fn multiscalar_mul(
+    curve_id: u64,
+    scalars: &[Scalar],
+    points: &[[u8; POINT_BYTES]],
+) -> Result<[u8; POINT_BYTES], Curve25519Error> {
+    // The runtime aborts the program on an invalid number of points.
+    if scalars.len() != points.len() || points.len() > MAX_MULTISCALAR_POINTS {
+        return Err(Curve25519Error::InvalidLength);
+    }
+
+    #[cfg(target_os = "solana")]
+    {
+        let mut point = core::mem::MaybeUninit::<[u8; POINT_BYTES]>::uninit();
+
+        // SAFETY: `scalars` and `points` are valid for reads of `points.len()`
+        // elements and `point` is valid for writes of a point.
+        let result = unsafe {
+            crate::syscalls::sol_curve_multiscalar_mul(
+                curve_id,
+                scalars.as_ptr() as *const u8,
+                points.as_ptr() as *const u8,
+                points.len() as u64,
+                point.as_mut_ptr() as *mut u8,
+            )
+        };
+
+        match result {
+            // SAFETY: The syscall has initialized the point.
+            crate::SUCCESS => Ok(unsafe { point.assume_init() }),
+            _ => Err(Curve25519Error::InvalidInput),
+        }
+    }
+
+    #[cfg(all(feature = "crypto-fallback", not(target_os = "solana")))]
+    {
+        crate::crypto::fallback::curve_multiscalar_mul(curve_id, scalars, points)
+            .ok_or(Curve25519Error::InvalidInput)
+    }
+
+    #[cfg(all(not(feature = "crypto-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box((curve_id, scalars, points));
+        panic!("curve25519 operations are only available on target `solana` or with the `crypto-fallback` feature")
+    }
+}
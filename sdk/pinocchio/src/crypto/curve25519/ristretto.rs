@@ -0,0 +1,43 @@
+//! Operations on the points of the Ristretto group, the prime-order group built
+//! from curve25519.
+
+use super::{Curve25519Error, Scalar, ADD, CURVE25519_RISTRETTO, MUL, POINT_BYTES, SUB};
+
+/// A compressed Ristretto point.
+pub type Point = [u8; POINT_BYTES];
+
+/// Indicate whether the given bytes are a valid Ristretto point.
+#[inline(always)]
+pub fn validate_point(point: &Point) -> bool {
+    super::validate_point(CURVE25519_RISTRETTO, point)
+}
+
+/// Return the sum of the given points.
+#[inline(always)]
+pub fn add(left: &Point, right: &Point) -> Result<Point, Curve25519Error> {
+    super::group_op(CURVE25519_RISTRETTO, ADD, left, right)
+}
+
+/// Return the difference of the given points.
+#[inline(always)]
+pub fn subtract(left: &Point, right: &Point) -> Result<Point, Curve25519Error> {
+    super::group_op(CURVE25519_RISTRETTO, SUB, left, right)
+}
+
+/// Return the product of the given scalar and point.
+#[inline(always)]
+pub fn multiply(scalar: &Scalar, point: &Point) -> Result<Point, Curve25519Error> {
+    super::group_op(CURVE25519_RISTRETTO, MUL, scalar, point)
+}
+
+/// Return the sum of the products of the given scalars and points.
+///
+/// The number of scalars and points must be the same, up to
+/// [`MAX_MULTISCALAR_POINTS`](super::MAX_MULTISCALAR_POINTS).
+#[inline(always)]
+pub fn multiscalar_multiply(
+    scalars: &[Scalar],
+    points: &[Point],
+) -> Result<Point, Curve25519Error> {
+    super::multiscalar_mul(CURVE25519_RISTRETTO, scalars, points)
+}
@@ -0,0 +1,323 @@
+//! Signature and elliptic curve operations computed in Rust, producing the same
+//! results as the runtime.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use ark_bn254::{Bn254, Fq12, G1Affine, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::One;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use curve25519_dalek::{
+    edwards::CompressedEdwardsY, ristretto::CompressedRistretto, scalar::Scalar, traits::Identity,
+    EdwardsPoint, RistrettoPoint,
+};
+use num_bigint::BigUint;
+
+use crate::crypto::{
+    alt_bn128::{
+        AltBn128Error, ADD, FIELD_BYTES, G1_BYTES, G1_COMPRESS, G1_COMPRESSED_BYTES, G1_DECOMPRESS,
+        G2_BYTES, G2_COMPRESS, G2_COMPRESSED_BYTES, G2_DECOMPRESS, MUL, PAIRING,
+    },
+    curve25519::{self, CURVE25519_EDWARDS, CURVE25519_RISTRETTO, POINT_BYTES},
+    secp256k1::{Secp256k1RecoverError, HASH_BYTES, PUBKEY_BYTES, SIGNATURE_BYTES},
+};
+
+pub(crate) fn secp256k1_recover(
+    hash: &[u8; HASH_BYTES],
+    recovery_id: u8,
+    signature: &[u8; SIGNATURE_BYTES],
+) -> Result<[u8; PUBKEY_BYTES], Secp256k1RecoverError> {
+    let message =
+        libsecp256k1::Message::parse_slice(hash).map_err(|_| Secp256k1RecoverError::InvalidHash)?;
+    let recovery_id = libsecp256k1::RecoveryId::parse(recovery_id)
+        .map_err(|_| Secp256k1RecoverError::InvalidRecoveryId)?;
+    let signature = libsecp256k1::Signature::parse_standard_slice(signature)
+        .map_err(|_| Secp256k1RecoverError::InvalidSignature)?;
+
+    let pubkey = libsecp256k1::recover(&message, &signature, &recovery_id)
+        .map_err(|_| Secp256k1RecoverError::InvalidSignature)?;
+
+    let mut result = [0u8; PUBKEY_BYTES];
+    // Skip the `0x04` prefix of the uncompressed encoding.
+    result.copy_from_slice(&pubkey.serialize()[1..]);
+    Ok(result)
+}
+
+/// Return the scalar of the given bytes, if they are reduced.
+fn scalar(bytes: &curve25519::Scalar) -> Option<Scalar> {
+    Scalar::from_canonical_bytes(*bytes).into()
+}
+
+pub(crate) fn curve_validate_point(curve_id: u64, point: &[u8; POINT_BYTES]) -> bool {
+    match curve_id {
+        CURVE25519_EDWARDS => CompressedEdwardsY(*point).decompress().is_some(),
+        CURVE25519_RISTRETTO => CompressedRistretto(*point).decompress().is_some(),
+        _ => false,
+    }
+}
+
+pub(crate) fn curve_group_op(
+    curve_id: u64,
+    op: u64,
+    left: &[u8; 32],
+    right: &[u8; POINT_BYTES],
+) -> Option<[u8; POINT_BYTES]> {
+    match curve_id {
+        CURVE25519_EDWARDS => {
+            let right = CompressedEdwardsY(*right).decompress()?;
+            let result = match op {
+                curve25519::ADD => CompressedEdwardsY(*left).decompress()? + right,
+                curve25519::SUB => CompressedEdwardsY(*left).decompress()? - right,
+                curve25519::MUL => scalar(left)? * right,
+                _ => return None,
+            };
+            Some(result.compress().to_bytes())
+        }
+        CURVE25519_RISTRETTO => {
+            let right = CompressedRistretto(*right).decompress()?;
+            let result = match op {
+                curve25519::ADD => CompressedRistretto(*left).decompress()? + right,
+                curve25519::SUB => CompressedRistretto(*left).decompress()? - right,
+                curve25519::MUL => scalar(left)? * right,
+                _ => return None,
+            };
+            Some(result.compress().to_bytes())
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn curve_multiscalar_mul(
+    curve_id: u64,
+    scalars: &[curve25519::Scalar],
+    points: &[[u8; POINT_BYTES]],
+) -> Option<[u8; POINT_BYTES]> {
+    let scalars = scalars.iter().map(scalar).collect::<Option<Vec<_>>>()?;
+
+    match curve_id {
+        CURVE25519_EDWARDS => {
+            let points = points
+                .iter()
+                .map(|point| CompressedEdwardsY(*point).decompress())
+                .collect::<Option<Vec<_>>>()?;
+            let result = scalars
+                .iter()
+                .zip(points)
+                .fold(EdwardsPoint::identity(), |sum, (scalar, point)| {
+                    sum + scalar * point
+                });
+            Some(result.compress().to_bytes())
+        }
+        CURVE25519_RISTRETTO => {
+            let points = points
+                .iter()
+                .map(|point| CompressedRistretto(*point).decompress())
+                .collect::<Option<Vec<_>>>()?;
+            let result = scalars
+                .iter()
+                .zip(points)
+                .fold(RistrettoPoint::identity(), |sum, (scalar, point)| {
+                    sum + scalar * point
+                });
+            Some(result.compress().to_bytes())
+        }
+        _ => None,
+    }
+}
+
+/// Reverse the byte order of each `CHUNK`-byte chunk of the given bytes, converting
+/// between the big-endian encoding of the runtime and the little-endian encoding
+/// of `ark-bn254`.
+fn convert_endianness<const CHUNK: usize, const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut converted = [0u8; N];
+    for (converted, chunk) in converted
+        .chunks_exact_mut(CHUNK)
+        .zip(bytes.chunks_exact(CHUNK))
+    {
+        converted.copy_from_slice(chunk);
+        converted.reverse();
+    }
+    converted
+}
+
+fn g1_from_bytes(bytes: &[u8]) -> Result<G1Affine, AltBn128Error> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return Ok(G1Affine::zero());
+    }
+
+    G1Affine::deserialize_with_mode(
+        convert_endianness::<FIELD_BYTES, G1_BYTES>(bytes).as_slice(),
+        Compress::No,
+        Validate::Yes,
+    )
+    .map_err(|_| AltBn128Error::InvalidPoint)
+}
+
+fn g2_from_bytes(bytes: &[u8]) -> Result<G2Affine, AltBn128Error> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return Ok(G2Affine::zero());
+    }
+
+    G2Affine::deserialize_with_mode(
+        convert_endianness::<{ FIELD_BYTES * 2 }, G2_BYTES>(bytes).as_slice(),
+        Compress::No,
+        Validate::Yes,
+    )
+    .map_err(|_| AltBn128Error::InvalidPoint)
+}
+
+fn g1_to_bytes(point: &G1Affine, result: &mut [u8]) -> Result<(), AltBn128Error> {
+    let mut bytes = [0u8; G1_BYTES];
+    point
+        .x
+        .serialize_uncompressed(&mut bytes[..FIELD_BYTES])
+        .and_then(|_| point.y.serialize_uncompressed(&mut bytes[FIELD_BYTES..]))
+        .map_err(|_| AltBn128Error::InvalidPoint)?;
+
+    result.copy_from_slice(&convert_endianness::<FIELD_BYTES, G1_BYTES>(&bytes));
+    Ok(())
+}
+
+fn g2_to_bytes(point: &G2Affine, result: &mut [u8]) -> Result<(), AltBn128Error> {
+    let mut bytes = [0u8; G2_BYTES];
+    point
+        .x
+        .serialize_uncompressed(&mut bytes[..FIELD_BYTES * 2])
+        .and_then(|_| {
+            point
+                .y
+                .serialize_uncompressed(&mut bytes[FIELD_BYTES * 2..])
+        })
+        .map_err(|_| AltBn128Error::InvalidPoint)?;
+
+    result.copy_from_slice(&convert_endianness::<{ FIELD_BYTES * 2 }, G2_BYTES>(&bytes));
+    Ok(())
+}
+
+pub(crate) fn alt_bn128_group_op(
+    op: u64,
+    input: &[u8],
+    result: &mut [u8],
+) -> Result<(), AltBn128Error> {
+    match op {
+        ADD => {
+            let (left, right) = input.split_at(G1_BYTES);
+            let sum = g1_from_bytes(left)? + g1_from_bytes(right)?;
+            g1_to_bytes(&sum.into(), result)
+        }
+        MUL => {
+            let (point, scalar) = input.split_at(G1_BYTES);
+            // The scalar is not reduced, so it is multiplied as an integer.
+            let mut limbs = [0u64; 4];
+            for (limb, bytes) in limbs.iter_mut().rev().zip(scalar.chunks_exact(8)) {
+                *limb = u64::from_be_bytes(bytes.try_into().unwrap());
+            }
+            let product = g1_from_bytes(point)?.mul_bigint(limbs);
+            g1_to_bytes(&product.into(), result)
+        }
+        PAIRING => {
+            let mut g1 = Vec::new();
+            let mut g2 = Vec::new();
+            for element in input.chunks_exact(G1_BYTES + G2_BYTES) {
+                let (left, right) = element.split_at(G1_BYTES);
+                g1.push(g1_from_bytes(left)?);
+                g2.push(g2_from_bytes(right)?);
+            }
+
+            result.fill(0);
+            if Bn254::multi_pairing(g1, g2).0 == Fq12::one() {
+                result[FIELD_BYTES - 1] = 1;
+            }
+            Ok(())
+        }
+        _ => Err(AltBn128Error::InvalidPoint),
+    }
+}
+
+pub(crate) fn alt_bn128_compression(
+    op: u64,
+    input: &[u8],
+    result: &mut [u8],
+) -> Result<(), AltBn128Error> {
+    // The point at infinity is encoded as zeros in both forms.
+    if input.iter().all(|byte| *byte == 0) {
+        result.fill(0);
+        return Ok(());
+    }
+
+    match op {
+        G1_COMPRESS => {
+            let point = G1Affine::deserialize_with_mode(
+                convert_endianness::<FIELD_BYTES, G1_BYTES>(input).as_slice(),
+                Compress::No,
+                Validate::No,
+            )
+            .map_err(|_| AltBn128Error::InvalidPoint)?;
+
+            let mut bytes = [0u8; G1_COMPRESSED_BYTES];
+            point
+                .serialize_compressed(bytes.as_mut_slice())
+                .map_err(|_| AltBn128Error::InvalidPoint)?;
+            result.copy_from_slice(&convert_endianness::<FIELD_BYTES, G1_COMPRESSED_BYTES>(
+                &bytes,
+            ));
+            Ok(())
+        }
+        G1_DECOMPRESS => {
+            let point = G1Affine::deserialize_with_mode(
+                convert_endianness::<FIELD_BYTES, G1_COMPRESSED_BYTES>(input).as_slice(),
+                Compress::Yes,
+                Validate::No,
+            )
+            .map_err(|_| AltBn128Error::InvalidPoint)?;
+
+            g1_to_bytes(&point, result)
+        }
+        G2_COMPRESS => {
+            let point = G2Affine::deserialize_with_mode(
+                convert_endianness::<{ FIELD_BYTES * 2 }, G2_BYTES>(input).as_slice(),
+                Compress::No,
+                Validate::No,
+            )
+            .map_err(|_| AltBn128Error::InvalidPoint)?;
+
+            let mut bytes = [0u8; G2_COMPRESSED_BYTES];
+            point
+                .serialize_compressed(bytes.as_mut_slice())
+                .map_err(|_| AltBn128Error::InvalidPoint)?;
+            result.copy_from_slice(&convert_endianness::<
+                { FIELD_BYTES * 2 },
+                G2_COMPRESSED_BYTES,
+            >(&bytes));
+            Ok(())
+        }
+        G2_DECOMPRESS => {
+            let point = G2Affine::deserialize_with_mode(
+                convert_endianness::<{ FIELD_BYTES * 2 }, G2_COMPRESSED_BYTES>(input).as_slice(),
+                Compress::Yes,
+                Validate::No,
+            )
+            .map_err(|_| AltBn128Error::InvalidPoint)?;
+
+            g2_to_bytes(&point, result)
+        }
+        _ => Err(AltBn128Error::InvalidPoint),
+    }
+}
+
+pub(crate) fn big_mod_exp(base: &[u8], exponent: &[u8], modulus: &[u8], result: &mut [u8]) {
+    let modulus = BigUint::from_bytes_be(modulus);
+    result.fill(0);
+
+    // A modulus of zero or one results in zero.
+    if modulus <= BigUint::one() {
+        return;
+    }
+
+    let value = BigUint::from_bytes_be(base)
+        .modpow(&BigUint::from_bytes_be(exponent), &modulus)
+        .to_bytes_be();
+    let offset = result.len() - value.len();
+    result[offset..].copy_from_slice(&value);
+}
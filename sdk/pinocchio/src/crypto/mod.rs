@@ -0,0 +1,29 @@
+//! Signature and elliptic curve cryptography.
+//!
+//! Each operation supported by the runtime is available in its own module:
+//!
+//! * [`secp256k1`] recovers the public key of a secp256k1 signature;
+//! * [`curve25519`] validates, adds, subtracts and multiplies points of the
+//!   Edwards and Ristretto representations of curve25519;
+//! * [`alt_bn128`] adds and multiplies points of the BN254 curve, checks
+//!   pairings and compresses points, following the encoding of the Ethereum
+//!   precompiles;
+//! * [`big_mod_exp`] computes the modular exponentiation of big integers.
+//!
+//! The operations are performed by syscalls on the `solana` target. On other
+//! targets, they are computed in Rust when the `crypto-fallback` feature is enabled,
+//! producing the same results; otherwise, the functions panic.
+//!
+//! Failures are reported with an error type specific to each module, which can be
+//! converted to [`ProgramError::InvalidArgument`].
+//!
+//! [`ProgramError::InvalidArgument`]: crate::program_error::ProgramError::InvalidArgument
+
+pub mod alt_bn128;
+pub mod big_mod_exp;
+pub mod curve25519;
+#[cfg(all(feature = "crypto-fallback", not(target_os = "solana")))]
+pub(crate) mod fallback;
+pub mod secp256k1;
+#[cfg(all(test, feature = "crypto-fallback", not(target_os = "solana")))]
+mod test;
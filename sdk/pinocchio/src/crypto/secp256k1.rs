@@ -0,0 +1,94 @@
+//! Public key recovery of secp256k1 signatures.
+//!
+//! Recovering the public key of a signature is the way to verify secp256k1
+//! signatures, e.g. signatures of Ethereum accounts, within a program. Note that
+//! the runtime does not reject signatures with a high `s` value: programs that
+//! need unique signatures must check it themselves.
+
+use crate::program_error::ProgramError;
+
+/// Number of bytes in a hash of the signed message.
+pub const HASH_BYTES: usize = 32;
+
+/// Number of bytes in a signature, the `r` and `s` values.
+pub const SIGNATURE_BYTES: usize = 64;
+
+/// Number of bytes in a public key, the uncompressed point without its `0x04`
+/// prefix.
+pub const PUBKEY_BYTES: usize = 64;
+
+/// Error of the public key recovery.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Secp256k1RecoverError {
+    /// The hash is not a valid message hash.
+    InvalidHash,
+
+    /// The recovery id is not in the range `0..=3`.
+    InvalidRecoveryId,
+
+    /// The signature is invalid or no public key can be recovered from it.
+    InvalidSignature,
+}
+
+impl From<u64> for Secp256k1RecoverError {
+    fn from(error: u64) -> Self {
+        match error {
+            1 => Self::InvalidHash,
+            2 => Self::InvalidRecoveryId,
+            _ => Self::InvalidSignature,
+        }
+    }
+}
+
+impl From<Secp256k1RecoverError> for ProgramError {
+    fn from(_: Secp256k1RecoverError) -> Self {
+        ProgramError::InvalidArgument
+    }
+}
+
+/// Recover the public key of the signature of the given message hash.
+///
+/// The recovery id is the `v` value of the signature, in the range `0..=3` (i.e.
+/// without the offset of `27` used by Ethereum). Since this function does not
+/// hash the message, it is the responsibility of the caller to hash it with a
+/// cryptographically secure hash function, e.g. [`keccak::hash`].
+///
+/// [`keccak::hash`]: crate::hash::keccak::hash
+#[inline]
+pub fn secp256k1_recover(
+    hash: &[u8; HASH_BYTES],
+    recovery_id: u8,
+    signature: &[u8; SIGNATURE_BYTES],
+) -> Result<[u8; PUBKEY_BYTES], Secp256k1RecoverError> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut pubkey = core::mem::MaybeUninit::<[u8; PUBKEY_BYTES]>::uninit();
+
+        // SAFETY: `pubkey` is valid for writes of a public key.
+        let result = unsafe {
+            crate::syscalls::sol_secp256k1_recover(
+                hash.as_ptr(),
+                recovery_id as u64,
+                signature.as_ptr(),
+                pubkey.as_mut_ptr() as *mut u8,
+            )
+        };
+
+        match result {
+            // SAFETY: The syscall has initialized the public key.
+            crate::SUCCESS => Ok(unsafe { pubkey.assume_init() }),
+            error => Err(error.into()),
+        }
+    }
+
+    #[cfg(all(feature = "crypto-fallback", not(target_os = "solana")))]
+    {
+        crate::crypto::fallback::secp256k1_recover(hash, recovery_id, signature)
+    }
+
+    #[cfg(all(not(feature = "crypto-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box((hash, recovery_id, signature));
+        panic!("secp256k1_recover is only available on target `solana` or with the `crypto-fallback` feature")
+    }
+}
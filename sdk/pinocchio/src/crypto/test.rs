@@ -0,0 +1,222 @@
+/// Decode the given hexadecimal string.
+fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+        *byte = u8::from_str_radix(core::str::from_utf8(digits).unwrap(), 16).unwrap();
+    }
+    bytes
+}
+
+#[test]
+fn test_secp256k1_recover() {
+    use crate::crypto::secp256k1::{secp256k1_recover, Secp256k1RecoverError};
+
+    let hash = from_hex("ce0677bb30baa8cf067c88db9811f4333d131bf8bcf12fe7065d211dce971008");
+    let signature = from_hex(
+        "90f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e54998\
+         4a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93",
+    );
+    let pubkey = from_hex(
+        "e32df42865e97135acfb65f3bae71bdc86f4d49150ad6a440b6f15878109880a\
+         0a2b2667f7e725ceea70c673093bf67663e0312623c8e091b13cf2c0f11ef652",
+    );
+
+    assert_eq!(secp256k1_recover(&hash, 1, &signature), Ok(pubkey));
+    assert_ne!(secp256k1_recover(&hash, 0, &signature), Ok(pubkey));
+
+    // invalid inputs
+    assert_eq!(
+        secp256k1_recover(&hash, 4, &signature),
+        Err(Secp256k1RecoverError::InvalidRecoveryId)
+    );
+    assert_eq!(
+        secp256k1_recover(&hash, 1, &[0xff; 64]),
+        Err(Secp256k1RecoverError::InvalidSignature)
+    );
+    assert_eq!(
+        secp256k1_recover(&hash, 1, &[0; 64]),
+        Err(Secp256k1RecoverError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_curve25519_edwards() {
+    use crate::crypto::curve25519::{edwards::*, Curve25519Error, MAX_MULTISCALAR_POINTS};
+    use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+
+    let point = |scalar: u64| {
+        (curve25519_dalek::Scalar::from(scalar) * ED25519_BASEPOINT_POINT)
+            .compress()
+            .to_bytes()
+    };
+    let scalar = |scalar: u64| {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&scalar.to_le_bytes());
+        bytes
+    };
+
+    assert!(validate_point(&point(1)));
+    assert_eq!(add(&point(2), &point(3)), Ok(point(5)));
+    assert_eq!(
+        subtract(&point(2), &point(3)),
+        subtract(&point(0), &point(1))
+    );
+    assert_eq!(multiply(&scalar(7), &point(3)), Ok(point(21)));
+    assert_eq!(
+        multiscalar_multiply(&[scalar(2), scalar(3)], &[point(5), point(7)]),
+        Ok(point(31))
+    );
+    assert_eq!(multiscalar_multiply(&[], &[]), Ok(point(0)));
+
+    // invalid inputs
+    let invalid = (0u8..)
+        .map(|byte| [byte; 32])
+        .find(|bytes| {
+            curve25519_dalek::edwards::CompressedEdwardsY(*bytes)
+                .decompress()
+                .is_none()
+        })
+        .unwrap();
+    assert!(!validate_point(&invalid));
+    assert_eq!(add(&point(1), &invalid), Err(Curve25519Error::InvalidInput));
+    assert_eq!(
+        multiply(&[0xff; 32], &point(1)),
+        Err(Curve25519Error::InvalidInput)
+    );
+    assert_eq!(
+        multiscalar_multiply(&[scalar(1)], &[]),
+        Err(Curve25519Error::InvalidLength)
+    );
+    assert_eq!(
+        multiscalar_multiply(
+            &[scalar(1); MAX_MULTISCALAR_POINTS + 1],
+            &[point(1); MAX_MULTISCALAR_POINTS + 1]
+        ),
+        Err(Curve25519Error::InvalidLength)
+    );
+}
+
+#[test]
+fn test_curve25519_ristretto() {
+    use crate::crypto::curve25519::{ristretto::*, Curve25519Error};
+    use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+
+    let point = |scalar: u64| {
+        (curve25519_dalek::Scalar::from(scalar) * RISTRETTO_BASEPOINT_POINT)
+            .compress()
+            .to_bytes()
+    };
+    let scalar = |scalar: u64| {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&scalar.to_le_bytes());
+        bytes
+    };
+
+    assert!(validate_point(&point(1)));
+    assert_eq!(add(&point(2), &point(3)), Ok(point(5)));
+    assert_eq!(subtract(&point(5), &point(3)), Ok(point(2)));
+    assert_eq!(multiply(&scalar(7), &point(3)), Ok(point(21)));
+    assert_eq!(
+        multiscalar_multiply(&[scalar(2), scalar(3)], &[point(5), point(7)]),
+        Ok(point(31))
+    );
+
+    // invalid inputs
+    assert!(!validate_point(&[0xff; 32]));
+    assert_eq!(
+        subtract(&[0xff; 32], &point(1)),
+        Err(Curve25519Error::InvalidInput)
+    );
+    assert_eq!(
+        multiscalar_multiply(&[[0xff; 32]], &[point(1)]),
+        Err(Curve25519Error::InvalidInput)
+    );
+}
+
+#[test]
+fn test_alt_bn128() {
+    use crate::crypto::alt_bn128::*;
+
+    // generators of G1 and G2
+    let g1: G1 = from_hex(
+        "0000000000000000000000000000000000000000000000000000000000000001\
+         0000000000000000000000000000000000000000000000000000000000000002",
+    );
+    let g2: G2 = from_hex(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+         1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+         090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+         12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+    );
+    let g1_double: G1 = from_hex(
+        "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+         15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+    );
+    let g1_negated: G1 = from_hex(
+        "0000000000000000000000000000000000000000000000000000000000000001\
+         30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45",
+    );
+    let mut two = [0u8; 32];
+    two[31] = 2;
+
+    assert_eq!(add(&g1, &g1), Ok(g1_double));
+    assert_eq!(add(&g1, &[0; 64]), Ok(g1));
+    assert_eq!(add(&g1, &g1_negated), Ok([0; 64]));
+    assert_eq!(multiply(&g1, &two), Ok(g1_double));
+    assert_eq!(multiply(&g1, &[0; 32]), Ok([0; 64]));
+
+    let element = |g1| PairingElement { g1, g2 };
+    assert_eq!(pairing(&[]), Ok(true));
+    assert_eq!(pairing(&[element(g1)]), Ok(false));
+    assert_eq!(pairing(&[element(g1), element(g1_negated)]), Ok(true));
+    assert_eq!(
+        pairing(&[element(g1_double), element(g1_negated)]),
+        Ok(false)
+    );
+
+    for point in [g1, g1_double, g1_negated, [0; 64]] {
+        assert_eq!(g1_decompress(&g1_compress(&point).unwrap()), Ok(point));
+    }
+    assert_eq!(g1_compress(&[0; 64]), Ok([0; 32]));
+    assert_eq!(g2_decompress(&g2_compress(&g2).unwrap()), Ok(g2));
+    assert_eq!(g2_compress(&[0; 128]), Ok([0; 64]));
+
+    // invalid inputs
+    let mut invalid = g1;
+    invalid[63] = 3;
+    assert_eq!(add(&g1, &invalid), Err(AltBn128Error::InvalidPoint));
+    assert_eq!(multiply(&invalid, &two), Err(AltBn128Error::InvalidPoint));
+    assert_eq!(
+        pairing(&[element(invalid)]),
+        Err(AltBn128Error::InvalidPoint)
+    );
+    assert_eq!(g1_decompress(&[0xff; 32]), Err(AltBn128Error::InvalidPoint));
+}
+
+#[test]
+fn test_big_mod_exp() {
+    use crate::crypto::big_mod_exp::{big_mod_exp, BigModExpError, MAX_LEN};
+
+    assert_eq!(big_mod_exp(&[3], &[5], &[0, 7]), Ok([0, 5]));
+    assert_eq!(big_mod_exp(&[3], &[], &[7]), Ok([1]));
+    assert_eq!(big_mod_exp(&[3], &[5], &[0, 0]), Ok([0, 0]));
+    assert_eq!(big_mod_exp(&[3], &[5], &[1]), Ok([0]));
+
+    // 2^256 mod (2^255 - 19)
+    let mut modulus = [0xff; 32];
+    modulus[0] = 0x7f;
+    modulus[31] = 0xed;
+    let mut expected = [0; 32];
+    expected[31] = 38;
+    assert_eq!(big_mod_exp(&[2], &[1, 0], &modulus), Ok(expected));
+
+    // invalid inputs
+    assert_eq!(
+        big_mod_exp(&[3; MAX_LEN + 1], &[5], &[7]),
+        Err(BigModExpError::InvalidLength)
+    );
+    assert_eq!(
+        big_mod_exp(&[3], &[5], &[7; MAX_LEN + 1]),
+        Err(BigModExpError::InvalidLength)
+    );
+}
@@ -0,0 +1,515 @@
+//! Builder to serialize the program input buffer off-chain.
+//!
+//! The [`InputBuilder`] writes accounts, instruction data and program id in the
+//! same layout as the SVM loader, so [`deserialize`], the program entrypoints and
+//! [`InstructionContext`] can be used in host tests and fuzzers. The accounts
+//! modified by the program can be read back from the [`Input`].
+//!
+//! ```
+//! use pinocchio::entrypoint::input::{InputAccount, InputBuilder};
+//!
+//! let program_id = [1; 32];
+//! let mut input = InputBuilder::new(&program_id)
+//!     .account(InputAccount {
+//!         is_signer: true,
+//!         is_writable: true,
+//!         ..InputAccount::new([2; 32], [0; 32], 1_000, vec![])
+//!     })
+//!     .duplicate(0)
+//!     .instruction_data(&[1, 2, 3])
+//!     .build();
+//!
+//! input
+//!     .process(|program_id, accounts, instruction_data| {
+//!         assert_eq!(program_id, &[1; 32]);
+//!         assert_eq!(accounts.len(), 2);
+//!         assert_eq!(instruction_data, &[1, 2, 3]);
+//!
+//!         *accounts[1].try_borrow_mut_lamports()? -= 400;
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//!
+//! assert_eq!(input.account(0).lamports, 600);
+//! ```
+//!
+//! [`deserialize`]: super::deserialize
+
+use core::mem::{size_of, MaybeUninit};
+use std::{vec, vec::Vec};
+
+use crate::{
+    account_info::{AccountInfo, MAX_PERMITTED_DATA_INCREASE},
+    entrypoint::{deserialize, InstructionContext},
+    pubkey::Pubkey,
+    ProgramResult, BPF_ALIGN_OF_U128, MAX_TX_ACCOUNTS, NON_DUP_MARKER,
+};
+
+/// Offset of the `key` field of an account in the input buffer.
+const KEY_OFFSET: usize = 8;
+
+/// Offset of the `owner` field of an account in the input buffer.
+const OWNER_OFFSET: usize = 40;
+
+/// Offset of the `lamports` field of an account in the input buffer.
+const LAMPORTS_OFFSET: usize = 72;
+
+/// Offset of the `data_len` field of an account in the input buffer.
+const DATA_LEN_OFFSET: usize = 80;
+
+/// Offset of the data of an account in the input buffer.
+const DATA_OFFSET: usize = 88;
+
+/// An account serialized in the input buffer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputAccount {
+    /// Public key of the account.
+    pub key: Pubkey,
+
+    /// Program that owns the account.
+    pub owner: Pubkey,
+
+    /// Lamports in the account.
+    pub lamports: u64,
+
+    /// Data held in the account.
+    pub data: Vec<u8>,
+
+    /// Indicates whether the transaction was signed by the account.
+    pub is_signer: bool,
+
+    /// Indicates whether the account is writable.
+    pub is_writable: bool,
+
+    /// Indicates whether the account contains a program.
+    pub executable: bool,
+
+    /// The epoch at which the account will next owe rent.
+    pub rent_epoch: u64,
+}
+
+impl InputAccount {
+    /// Creates a new read-only and non-signer `InputAccount`.
+    pub fn new(key: Pubkey, owner: Pubkey, lamports: u64, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            owner,
+            lamports,
+            data,
+            ..Self::default()
+        }
+    }
+}
+
+/// An entry of the accounts in the input buffer.
+#[derive(Clone, Debug)]
+enum Entry {
+    /// A non-duplicated account.
+    Account(InputAccount),
+
+    /// A duplicate of the account at the given index.
+    Duplicate(u8),
+}
+
+/// Builder of the program input buffer.
+#[derive(Clone, Debug)]
+pub struct InputBuilder {
+    /// Program id of the instruction.
+    program_id: Pubkey,
+
+    /// Accounts of the instruction.
+    accounts: Vec<Entry>,
+
+    /// Data of the instruction.
+    instruction_data: Vec<u8>,
+}
+
+impl InputBuilder {
+    /// Creates a new `InputBuilder` for an instruction of the given program,
+    /// without accounts and instruction data.
+    pub fn new(program_id: &Pubkey) -> Self {
+        Self {
+            program_id: *program_id,
+            accounts: Vec::new(),
+            instruction_data: Vec::new(),
+        }
+    }
+
+    /// Adds a (non-duplicated) account.
+    pub fn account(mut self, account: InputAccount) -> Self {
+        self.accounts.push(Entry::Account(account));
+        self
+    }
+
+    /// Adds (non-duplicated) accounts.
+    pub fn accounts(mut self, accounts: impl IntoIterator<Item = InputAccount>) -> Self {
+        self.accounts
+            .extend(accounts.into_iter().map(Entry::Account));
+        self
+    }
+
+    /// Adds a duplicate of the account at the given index.
+    ///
+    /// As the SVM loader does, a duplicate of a duplicated account references the
+    /// original account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no account at the given index.
+    pub fn duplicate(mut self, index: usize) -> Self {
+        let original = match self.accounts.get(index) {
+            Some(Entry::Account(_)) => index as u8,
+            Some(Entry::Duplicate(original)) => *original,
+            None => panic!("no account at index {index}"),
+        };
+        self.accounts.push(Entry::Duplicate(original));
+        self
+    }
+
+    /// Sets the instruction data.
+    pub fn instruction_data(mut self, instruction_data: &[u8]) -> Self {
+        self.instruction_data = instruction_data.to_vec();
+        self
+    }
+
+    /// Serializes the input buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than [`MAX_TX_ACCOUNTS`] accounts.
+    pub fn build(&self) -> Input {
+        assert!(
+            self.accounts.len() <= MAX_TX_ACCOUNTS,
+            "number of accounts exceeds MAX_TX_ACCOUNTS"
+        );
+
+        let mut bytes = Vec::new();
+        let mut offsets = Vec::with_capacity(self.accounts.len());
+
+        bytes.extend_from_slice(&(self.accounts.len() as u64).to_le_bytes());
+
+        for entry in self.accounts.iter() {
+            match entry {
+                Entry::Account(account) => {
+                    offsets.push(bytes.len());
+
+                    bytes.extend_from_slice(&[
+                        NON_DUP_MARKER,
+                        account.is_signer as u8,
+                        account.is_writable as u8,
+                        account.executable as u8,
+                    ]);
+                    // Padding used as the resize delta.
+                    bytes.extend_from_slice(&[0; 4]);
+                    bytes.extend_from_slice(&account.key);
+                    bytes.extend_from_slice(&account.owner);
+                    bytes.extend_from_slice(&account.lamports.to_le_bytes());
+                    bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+                    bytes.extend_from_slice(&account.data);
+                    // Realloc padding and alignment of the rent epoch.
+                    let len = bytes.len() + MAX_PERMITTED_DATA_INCREASE;
+                    bytes.resize(
+                        (len + (BPF_ALIGN_OF_U128 - 1)) & !(BPF_ALIGN_OF_U128 - 1),
+                        0,
+                    );
+                    bytes.extend_from_slice(&account.rent_epoch.to_le_bytes());
+                }
+                Entry::Duplicate(index) => {
+                    offsets.push(offsets[*index as usize]);
+                    bytes.extend_from_slice(&[*index, 0, 0, 0, 0, 0, 0, 0]);
+                }
+            }
+        }
+
+        bytes.extend_from_slice(&(self.instruction_data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.instruction_data);
+        bytes.extend_from_slice(&self.program_id);
+
+        let mut buffer = vec![0u64; bytes.len().div_ceil(size_of::<u64>())];
+        // SAFETY: The buffer has at least `bytes.len()` bytes.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                buffer.as_mut_ptr() as *mut u8,
+                bytes.len(),
+            );
+        }
+
+        Input {
+            buffer,
+            len: bytes.len(),
+            offsets,
+        }
+    }
+}
+
+/// A serialized program input buffer.
+///
+/// The buffer is aligned as the input buffer of the SVM loader.
+#[derive(Clone, Debug)]
+pub struct Input {
+    /// Buffer holding the serialized input.
+    buffer: Vec<u64>,
+
+    /// Length of the serialized input in bytes.
+    len: usize,
+
+    /// Offset of the (non-duplicated) account of each account entry.
+    offsets: Vec<usize>,
+}
+
+impl Input {
+    /// Return the serialized input.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The buffer holds `len` initialized bytes.
+        unsafe { core::slice::from_raw_parts(self.buffer.as_ptr() as *const u8, self.len) }
+    }
+
+    /// Return a mutable pointer to the serialized input.
+    ///
+    /// This is the `input` expected by the `entrypoint` function declared by the
+    /// entrypoint macros.
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.buffer.as_mut_ptr() as *mut u8
+    }
+
+    /// Deserializes the input and calls the given `process_instruction` function,
+    /// as the [`crate::program_entrypoint!`] does.
+    pub fn process<F>(&mut self, process_instruction: F) -> ProgramResult
+    where
+        F: FnOnce(&Pubkey, &[AccountInfo], &[u8]) -> ProgramResult,
+    {
+        const UNINIT: MaybeUninit<AccountInfo> = MaybeUninit::<AccountInfo>::uninit();
+        let mut accounts = [UNINIT; MAX_TX_ACCOUNTS];
+
+        // SAFETY: The buffer is a valid input buffer and outlives the call.
+        let (program_id, count, instruction_data) =
+            unsafe { deserialize::<MAX_TX_ACCOUNTS>(self.as_mut_ptr(), &mut accounts) };
+
+        process_instruction(
+            program_id,
+            // SAFETY: `count` account infos were initialized.
+            unsafe { core::slice::from_raw_parts(accounts.as_ptr() as _, count) },
+            instruction_data,
+        )
+    }
+
+    /// Return an [`InstructionContext`] over the input, as the
+    /// [`crate::lazy_program_entrypoint!`] does.
+    ///
+    /// # Safety
+    ///
+    /// The context holds a raw pointer to the input buffer, so the caller must ensure
+    /// that it is not used after the input is dropped, moved or otherwise accessed
+    /// (e.g., through [`Input::account`] or [`Input::process`]) while the context or
+    /// the accounts read from it are still in use.
+    pub unsafe fn instruction_context(&mut self) -> InstructionContext {
+        // SAFETY: The buffer is a valid input buffer.
+        InstructionContext::new_unchecked(self.as_mut_ptr())
+    }
+
+    /// Return the number of accounts in the input.
+    pub fn accounts_len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Read back the account at the given index, including the changes made by
+    /// the program.
+    ///
+    /// A duplicated account returns the state of the original account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no account at the given index.
+    pub fn account(&self, index: usize) -> InputAccount {
+        let bytes = self.as_bytes();
+        let offset = self.offsets[index];
+        let account = &bytes[offset..];
+
+        let read_u64 = |at: usize| u64::from_le_bytes(account[at..at + 8].try_into().unwrap());
+        let data_len = read_u64(DATA_LEN_OFFSET) as usize;
+
+        // The original data length is used to locate the rent epoch since the
+        // data length might have been changed by the program.
+        let original_data_len = (data_len as i64 - self.resize_delta(offset) as i64) as usize;
+        let rent_epoch_offset = (DATA_OFFSET
+            + original_data_len
+            + MAX_PERMITTED_DATA_INCREASE
+            + (BPF_ALIGN_OF_U128 - 1))
+            & !(BPF_ALIGN_OF_U128 - 1);
+
+        InputAccount {
+            key: account[KEY_OFFSET..OWNER_OFFSET].try_into().unwrap(),
+            owner: account[OWNER_OFFSET..LAMPORTS_OFFSET].try_into().unwrap(),
+            lamports: read_u64(LAMPORTS_OFFSET),
+            data: account[DATA_OFFSET..DATA_OFFSET + data_len].to_vec(),
+            is_signer: account[1] != 0,
+            is_writable: account[2] != 0,
+            executable: account[3] != 0,
+            rent_epoch: read_u64(rent_epoch_offset),
+        }
+    }
+
+    /// Read back all accounts, including the changes made by the program.
+    pub fn accounts(&self) -> Vec<InputAccount> {
+        (0..self.accounts_len())
+            .map(|index| self.account(index))
+            .collect()
+    }
+
+    /// Return the resize delta of the account at the given offset.
+    fn resize_delta(&self, offset: usize) -> i32 {
+        let bytes = self.as_bytes();
+        i32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{entrypoint::MaybeAccount, program_error::ProgramError};
+
+    const PROGRAM_ID: Pubkey = [5; 32];
+
+    fn input() -> Input {
+        InputBuilder::new(&PROGRAM_ID)
+            .account(InputAccount {
+                is_signer: true,
+                is_writable: true,
+                ..InputAccount::new([1; 32], [0; 32], 1_000, vec![])
+            })
+            .accounts([
+                InputAccount {
+                    is_writable: true,
+                    rent_epoch: u64::MAX,
+                    ..InputAccount::new([2; 32], PROGRAM_ID, 500, vec![7; 3])
+                },
+                InputAccount {
+                    executable: true,
+                    ..InputAccount::new([3; 32], [6; 32], 1, vec![1; 20])
+                },
+            ])
+            .duplicate(1)
+            .duplicate(3)
+            .instruction_data(&[9; 10])
+            .build()
+    }
+
+    #[test]
+    fn test_process() {
+        let mut input = input();
+        let original = input.accounts();
+
+        let result = input.process(|program_id, accounts, instruction_data| {
+            assert_eq!(program_id, &PROGRAM_ID);
+            assert_eq!(instruction_data, &[9; 10]);
+            assert_eq!(accounts.len(), 5);
+
+            assert_eq!(accounts[0].key(), &[1; 32]);
+            assert!(accounts[0].is_signer());
+            assert!(accounts[0].is_writable());
+            assert_eq!(accounts[1].owner(), &PROGRAM_ID);
+            assert_eq!(*accounts[1].try_borrow_data()?, [7; 3]);
+            assert!(accounts[2].executable());
+            assert_eq!(accounts[2].data_len(), 20);
+
+            // duplicates reference the original account
+            assert_eq!(accounts[3].key(), &[2; 32]);
+            assert_eq!(accounts[4].key(), &[2; 32]);
+            let data = accounts[3].try_borrow_mut_data()?;
+            assert_eq!(
+                accounts[1].try_borrow_data().unwrap_err(),
+                ProgramError::AccountBorrowFailed
+            );
+            drop(data);
+
+            *accounts[0].try_borrow_mut_lamports()? -= 100;
+            *accounts[4].try_borrow_mut_lamports()? += 100;
+            accounts[1].resize(8)?;
+            accounts[1].try_borrow_mut_data()?[3..].copy_from_slice(&[8; 5]);
+
+            Ok(())
+        });
+        assert_eq!(result, Ok(()));
+
+        let accounts = input.accounts();
+        assert_eq!(accounts[0].lamports, 900);
+        assert_eq!(
+            accounts[1],
+            InputAccount {
+                lamports: 600,
+                data: vec![7, 7, 7, 8, 8, 8, 8, 8],
+                ..original[1].clone()
+            }
+        );
+        assert_eq!(accounts[2], original[2]);
+        assert_eq!(accounts[3], accounts[1]);
+        assert_eq!(accounts[4], accounts[1]);
+    }
+
+    #[test]
+    fn test_process_error() {
+        let mut input = input();
+
+        assert_eq!(
+            input.process(|_, _, _| Err(ProgramError::Custom(1))),
+            Err(ProgramError::Custom(1))
+        );
+    }
+
+    #[test]
+    fn test_instruction_context() {
+        let mut input = input();
+        // SAFETY: The input outlives the context and is not accessed while it is used.
+        let mut context = unsafe { input.instruction_context() };
+
+        assert_eq!(context.remaining(), 5);
+        assert_eq!(
+            context.next_account().unwrap().assume_account().key(),
+            &[1; 32]
+        );
+        assert_eq!(
+            context.next_account().unwrap().assume_account().key(),
+            &[2; 32]
+        );
+        assert_eq!(
+            context.next_account().unwrap().assume_account().key(),
+            &[3; 32]
+        );
+        assert!(matches!(
+            context.next_account().unwrap(),
+            MaybeAccount::Duplicated(1)
+        ));
+        assert!(matches!(
+            context.next_account().unwrap(),
+            MaybeAccount::Duplicated(1)
+        ));
+        assert_eq!(context.remaining(), 0);
+
+        assert_eq!(context.instruction_data(), Ok(&[9; 10][..]));
+        assert_eq!(context.program_id(), Ok(&PROGRAM_ID));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let mut input = InputBuilder::new(&PROGRAM_ID).build();
+
+        assert_eq!(input.as_bytes().len(), 8 + 8 + 32);
+        assert_eq!(input.accounts_len(), 0);
+        assert_eq!(
+            input.process(|program_id, accounts, instruction_data| {
+                assert_eq!(program_id, &PROGRAM_ID);
+                assert!(accounts.is_empty());
+                assert!(instruction_data.is_empty());
+                Ok(())
+            }),
+            Ok(())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no account at index 0")]
+    fn test_duplicate_missing_account() {
+        InputBuilder::new(&PROGRAM_ID).duplicate(0);
+    }
+}
@@ -1,6 +1,8 @@
 //! Macros and functions for defining the program entrypoint and setting up
 //! global handlers.
 
+#[cfg(feature = "std")]
+pub mod input;
 pub mod lazy;
 
 pub use lazy::{InstructionContext, MaybeAccount};
@@ -0,0 +1,61 @@
+//! Hashing functions computed in Rust, producing the same results as the runtime.
+
+use ark_bn254::Fr;
+use light_poseidon::{Poseidon, PoseidonBytesHasher};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+use crate::{
+    hash::{
+        poseidon::{Endianness, Parameters},
+        Hash,
+    },
+    program_error::ProgramError,
+};
+
+/// Return the SHA-256 hash of the concatenation of the given values.
+pub(crate) fn sha256v(vals: &[&[u8]]) -> Hash {
+    let mut hasher = Sha256::new();
+    for val in vals {
+        hasher.update(val);
+    }
+    hasher.finalize().into()
+}
+
+/// Return the Keccak-256 hash of the concatenation of the given values.
+pub(crate) fn keccak256v(vals: &[&[u8]]) -> Hash {
+    let mut hasher = Keccak256::new();
+    for val in vals {
+        hasher.update(val);
+    }
+    hasher.finalize().into()
+}
+
+/// Return the BLAKE3 hash of the concatenation of the given values.
+pub(crate) fn blake3v(vals: &[&[u8]]) -> Hash {
+    let mut hasher = blake3::Hasher::new();
+    for val in vals {
+        hasher.update(val);
+    }
+    hasher.finalize().into()
+}
+
+/// Return the Poseidon hash of the given values.
+pub(crate) fn poseidon(
+    parameters: Parameters,
+    endianness: Endianness,
+    vals: &[&[u8]],
+) -> Result<Hash, ProgramError> {
+    match parameters {
+        Parameters::Bn254X5 => {
+            let mut hasher = Poseidon::<Fr>::new_circom(vals.len())
+                .map_err(|_| ProgramError::InvalidArgument)?;
+
+            match endianness {
+                Endianness::BigEndian => hasher.hash_bytes_be(vals),
+                Endianness::LittleEndian => hasher.hash_bytes_le(vals),
+            }
+            .map_err(|_| ProgramError::InvalidArgument)
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Hashing functions.
+//!
+//! Each hashing algorithm supported by the runtime is available in its own module,
+//! with a `hash` function to hash a single value and a `hashv` function to hash the
+//! concatenation of multiple values:
+//!
+//! ```ignore
+//! use pinocchio::hash::{keccak, sha256};
+//!
+//! let leaf = keccak::hashv(&[&index.to_le_bytes(), &recipient, &amount.to_le_bytes()]);
+//! let commitment = sha256::hash(&secret);
+//! ```
+//!
+//! The hashes are computed by syscalls on the `solana` target. On other targets, the
+//! hashes are computed in Rust when the `hash-fallback` feature is enabled, producing
+//! the same results; otherwise, the functions panic.
+
+pub mod blake3;
+#[cfg(all(feature = "hash-fallback", not(target_os = "solana")))]
+pub(crate) mod fallback;
+pub mod keccak;
+pub mod poseidon;
+pub mod sha256;
+#[cfg(all(test, feature = "hash-fallback", not(target_os = "solana")))]
+mod test;
+
+/// Number of bytes in a hash.
+pub const HASH_BYTES: usize = 32;
+
+/// A hash value.
+pub type Hash = [u8; HASH_BYTES];
@@ -0,0 +1,98 @@
+//! Poseidon hashing.
+//!
+//! Poseidon is a hash function designed for zero-knowledge proof systems. The
+//! runtime supports Poseidon over the BN254 scalar field, compatible with the
+//! [circom] implementation. Each input is a value of at most 32 bytes, smaller
+//! than the field modulus.
+//!
+//! [circom]: https://github.com/iden3/circomlibjs/blob/main/src/poseidon_reference.js
+
+use super::Hash;
+use crate::program_error::ProgramError;
+
+/// Maximum number of inputs of a Poseidon hash.
+pub const MAX_INPUTS: usize = 12;
+
+/// Parameters of the Poseidon hash function.
+#[repr(u64)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parameters {
+    /// BN254 curve with the x^5 S-box, `t = n + 1` for `n` inputs, 8 full rounds
+    /// and a number of partial rounds depending on `t`.
+    Bn254X5 = 0,
+}
+
+/// Endianness of the inputs and result of the Poseidon hash function.
+#[repr(u64)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// Big-endian inputs and result.
+    BigEndian = 0,
+
+    /// Little-endian inputs and result.
+    LittleEndian = 1,
+}
+
+/// Return the Poseidon hash of the given value.
+///
+/// See [`hashv`] for the possible errors.
+#[inline(always)]
+pub fn hash(
+    parameters: Parameters,
+    endianness: Endianness,
+    val: &[u8],
+) -> Result<Hash, ProgramError> {
+    hashv(parameters, endianness, &[val])
+}
+
+/// Return the Poseidon hash of the given values.
+///
+/// Unlike the other hash functions, each value is an input of the hash function.
+/// This function returns [`ProgramError::InvalidArgument`] if there are no values,
+/// more than [`MAX_INPUTS`] values, or any value is longer than 32 bytes or not
+/// smaller than the field modulus.
+#[inline]
+pub fn hashv(
+    parameters: Parameters,
+    endianness: Endianness,
+    vals: &[&[u8]],
+) -> Result<Hash, ProgramError> {
+    // The runtime aborts the program on an invalid number of inputs.
+    if vals.is_empty() || vals.len() > MAX_INPUTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = core::mem::MaybeUninit::<Hash>::uninit();
+
+        // SAFETY: `vals` has the layout of the slices expected by the syscall and
+        // `hash` is valid for writes of a hash.
+        let result = unsafe {
+            crate::syscalls::sol_poseidon(
+                parameters as u64,
+                endianness as u64,
+                vals as *const _ as *const u8,
+                vals.len() as u64,
+                hash.as_mut_ptr() as *mut u8,
+            )
+        };
+
+        match result {
+            // SAFETY: The syscall has initialized the hash.
+            crate::SUCCESS => Ok(unsafe { hash.assume_init() }),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+
+    #[cfg(all(feature = "hash-fallback", not(target_os = "solana")))]
+    {
+        super::fallback::poseidon(parameters, endianness, vals)
+    }
+
+    #[cfg(all(not(feature = "hash-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box((parameters, endianness));
+        panic!("poseidon::hashv is only available on target `solana` or with the `hash-fallback` feature")
+    }
+}
@@ -0,0 +1,44 @@
+//! SHA-256 hashing.
+
+use super::Hash;
+
+/// Return the SHA-256 hash of the given value.
+#[inline(always)]
+pub fn hash(val: &[u8]) -> Hash {
+    hashv(&[val])
+}
+
+/// Return the SHA-256 hash of the concatenation of the given values.
+#[inline]
+pub fn hashv(vals: &[&[u8]]) -> Hash {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = core::mem::MaybeUninit::<Hash>::uninit();
+
+        // SAFETY: `vals` has the layout of the slices expected by the syscall and
+        // `hash` is valid for writes of a hash.
+        unsafe {
+            crate::syscalls::sol_sha256(
+                vals as *const _ as *const u8,
+                vals.len() as u64,
+                hash.as_mut_ptr() as *mut u8,
+            );
+        }
+
+        // SAFETY: The syscall has initialized the hash.
+        unsafe { hash.assume_init() }
+    }
+
+    #[cfg(all(feature = "hash-fallback", not(target_os = "solana")))]
+    {
+        super::fallback::sha256v(vals)
+    }
+
+    #[cfg(all(not(feature = "hash-fallback"), not(target_os = "solana")))]
+    {
+        core::hint::black_box(vals);
+        panic!(
+            "sha256::hashv is only available on target `solana` or with the `hash-fallback` feature"
+        )
+    }
+}
@@ -0,0 +1,95 @@
+extern crate std;
+
+use crate::program_error::ProgramError;
+
+#[test]
+fn test_hash() {
+    use crate::hash::{blake3, keccak, sha256};
+
+    let hex = |hash: [u8; 32]| -> std::string::String {
+        use core::fmt::Write;
+
+        hash.iter()
+            .fold(std::string::String::new(), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            })
+    };
+
+    assert_eq!(
+        hex(sha256::hash(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        hex(sha256::hashv(&[b"a", b"bc"])),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        hex(keccak::hash(b"")),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+    assert_eq!(
+        hex(keccak::hashv(&[b"a", b"bc"])),
+        "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+    );
+    assert_eq!(
+        hex(blake3::hash(b"")),
+        "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+    );
+    assert_eq!(
+        hex(blake3::hashv(&[b"a", b"bc"])),
+        "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+    );
+}
+
+#[test]
+fn test_poseidon() {
+    use crate::hash::poseidon::{hash, hashv, Endianness, Parameters};
+
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    let mut two = [0u8; 32];
+    two[31] = 2;
+
+    // circom's `poseidon([1, 2])`
+    let expected = [
+        0x11, 0x5c, 0xc0, 0xf5, 0xe7, 0xd6, 0x90, 0x41, 0x3d, 0xf6, 0x4c, 0x6b, 0x96, 0x62, 0xe9,
+        0xcf, 0x2a, 0x36, 0x17, 0xf2, 0x74, 0x32, 0x45, 0x51, 0x9e, 0x19, 0x60, 0x7a, 0x44, 0x17,
+        0x18, 0x9a,
+    ];
+    assert_eq!(
+        hashv(Parameters::Bn254X5, Endianness::BigEndian, &[&one, &two]),
+        Ok(expected)
+    );
+
+    one.reverse();
+    two.reverse();
+    let mut expected_le = expected;
+    expected_le.reverse();
+    assert_eq!(
+        hashv(Parameters::Bn254X5, Endianness::LittleEndian, &[&one, &two]),
+        Ok(expected_le)
+    );
+
+    // invalid inputs
+    assert_eq!(
+        hashv(Parameters::Bn254X5, Endianness::BigEndian, &[]),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert_eq!(
+        hashv(
+            Parameters::Bn254X5,
+            Endianness::BigEndian,
+            &[one.as_slice(); 13]
+        ),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert_eq!(
+        hash(Parameters::Bn254X5, Endianness::BigEndian, &[1; 33]),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert_eq!(
+        hash(Parameters::Bn254X5, Endianness::BigEndian, &[0xff; 32]),
+        Err(ProgramError::InvalidArgument)
+    );
+}
@@ -0,0 +1,467 @@
+//! Host runtime emulating the syscalls off-chain.
+//!
+//! This module is available with the `host-runtime` feature on non-`solana`
+//! targets. Instead of doing nothing, the syscall wrappers of Pinocchio call into
+//! a thread-local runtime, so program logic can be unit-tested with `cargo test`
+//! without building an SBF binary:
+//!
+//! * sysvars loaded with [`Sysvar::get`] or [`get_sysvar`] are read from the values
+//!   set with [`set_clock`], [`set_rent`], [`set_fees`], [`set_epoch_schedule`],
+//!   [`set_epoch_rewards`], [`set_last_restart_slot`] and [`set_sysvar_data`];
+//! * log messages are captured and returned by [`logs`], formatted as they are
+//!   by the runtime (e.g. `"Program log: Hello"`);
+//! * cross-program invocations are recorded and returned by [`invoked_instructions`].
+//!   By default, invocations succeed without doing anything; a handler set with
+//!   [`stub_program`] can update the accounts, set the return data or fail the
+//!   invocation;
+//! * program addresses are derived as by the runtime, using SHA-256 and an ed25519
+//!   on-curve check;
+//! * return data set with [`set_return_data`] is owned by the program set with
+//!   [`set_program_id`], or by the stubbed program while its handler runs.
+//!
+//! Each test runs on its own thread, so tests do not share their runtime. A test
+//! running several scenarios on the same thread can start over with [`reset`].
+//!
+//! ```ignore
+//! use pinocchio::host;
+//!
+//! host::set_program_id(&crate::ID);
+//! host::stub_program(&pinocchio_system::ID, |instruction, accounts| {
+//!     // move the lamports of a `Transfer` ...
+//!     Ok(())
+//! });
+//!
+//! process_instruction(&crate::ID, &accounts, &instruction_data)?;
+//!
+//! assert_eq!(host::invoked_instructions().len(), 1);
+//! assert_eq!(host::logs(), ["Program log: Transfer completed"]);
+//! ```
+//!
+//! [`Sysvar::get`]: crate::sysvars::Sysvar::get
+//! [`get_sysvar`]: crate::sysvars::get_sysvar
+//! [`set_return_data`]: crate::cpi::set_return_data
+
+mod encoding;
+mod pda;
+#[cfg(test)]
+mod tests;
+
+use std::{
+    boxed::Box, cell::RefCell, collections::HashMap, format, rc::Rc, string::String, vec::Vec,
+};
+
+use crate::{
+    account_info::AccountInfo,
+    instruction::{Account, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{
+        clock::Clock,
+        epoch_rewards::EpochRewards,
+        epoch_schedule::EpochSchedule,
+        fees::{FeeCalculator, FeeRateGovernor, Fees},
+        last_restart_slot::LastRestartSlot,
+        rent::{
+            Rent, DEFAULT_BURN_PERCENT, DEFAULT_EXEMPTION_THRESHOLD, DEFAULT_LAMPORTS_PER_BYTE_YEAR,
+        },
+    },
+    ProgramResult, SUCCESS,
+};
+
+pub(crate) use pda::{create_program_address, try_find_program_address};
+
+/// Handler of the cross-program invocations of a stubbed program.
+type Handler = Rc<dyn Fn(&InvokedInstruction, &[AccountInfo]) -> ProgramResult>;
+
+/// State of the host runtime.
+struct Runtime {
+    /// Stack of the running programs: the program set with [`set_program_id`],
+    /// followed by the stubbed programs being invoked.
+    program_ids: Vec<Pubkey>,
+
+    clock: Clock,
+
+    rent: Rent,
+
+    fees: Fees,
+
+    epoch_schedule: EpochSchedule,
+
+    epoch_rewards: EpochRewards,
+
+    last_restart_slot: LastRestartSlot,
+
+    /// Data of the sysvars read with `sol_get_sysvar`.
+    sysvar_data: HashMap<Pubkey, Box<[u8]>>,
+
+    logs: Vec<String>,
+
+    invoked_instructions: Vec<InvokedInstruction>,
+
+    stubs: HashMap<Pubkey, Handler>,
+
+    /// Return data and the program that set it.
+    return_data: Option<(Pubkey, Box<[u8]>)>,
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self {
+            program_ids: std::vec![Pubkey::default()],
+            clock: Clock {
+                slot: 0,
+                epoch_start_timestamp: 0,
+                epoch: 0,
+                leader_schedule_epoch: 0,
+                unix_timestamp: 0,
+            },
+            rent: Rent {
+                lamports_per_byte_year: DEFAULT_LAMPORTS_PER_BYTE_YEAR,
+                exemption_threshold: DEFAULT_EXEMPTION_THRESHOLD,
+                burn_percent: DEFAULT_BURN_PERCENT,
+            },
+            fees: Fees::new(FeeCalculator::new(0), FeeRateGovernor::default()),
+            epoch_schedule: EpochSchedule::default(),
+            epoch_rewards: EpochRewards::default(),
+            last_restart_slot: LastRestartSlot::default(),
+            sysvar_data: HashMap::new(),
+            logs: Vec::new(),
+            invoked_instructions: Vec::new(),
+            stubs: HashMap::new(),
+            return_data: None,
+        }
+    }
+}
+
+std::thread_local! {
+    static RUNTIME: RefCell<Runtime> = RefCell::new(Runtime::default());
+}
+
+/// Run the given closure with the runtime of the current thread.
+///
+/// The runtime must not be borrowed while a stub handler runs, since the handler
+/// may itself call into the runtime.
+fn with_runtime<R>(f: impl FnOnce(&mut Runtime) -> R) -> R {
+    RUNTIME.with(|runtime| f(&mut runtime.borrow_mut()))
+}
+
+/// Metadata of an account of a recorded cross-program invocation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvokedAccountMeta {
+    /// Public key of the account.
+    pub pubkey: Pubkey,
+
+    /// Indicates whether the account is writable.
+    pub is_writable: bool,
+
+    /// Indicates whether the account is a signer.
+    pub is_signer: bool,
+}
+
+/// A recorded cross-program invocation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvokedInstruction {
+    /// Public key of the invoked program.
+    pub program_id: Pubkey,
+
+    /// Accounts of the instruction.
+    pub accounts: Vec<InvokedAccountMeta>,
+
+    /// Data of the instruction.
+    pub data: Vec<u8>,
+
+    /// Seeds of the program derived addresses signing the instruction.
+    pub signers_seeds: Vec<Vec<Vec<u8>>>,
+}
+
+impl InvokedInstruction {
+    fn new(instruction: &Instruction, signers_seeds: &[Signer]) -> Self {
+        Self {
+            program_id: *instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|account| InvokedAccountMeta {
+                    pubkey: *account.pubkey,
+                    is_writable: account.is_writable,
+                    is_signer: account.is_signer,
+                })
+                .collect(),
+            data: instruction.data.to_vec(),
+            signers_seeds: signers_seeds
+                .iter()
+                .map(|signer| {
+                    // SAFETY: The seeds of a `Signer` are valid while it lives.
+                    let seeds =
+                        unsafe { core::slice::from_raw_parts(signer.seeds, signer.len as usize) };
+                    seeds
+                        .iter()
+                        .map(|seed| {
+                            // SAFETY: The bytes of a `Seed` are valid while it lives.
+                            unsafe { core::slice::from_raw_parts(seed.seed, seed.len as usize) }
+                                .to_vec()
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Return the program derived addresses signing the instruction, derived from
+    /// the signers seeds and the given invoking program.
+    pub fn signer_addresses(&self, program_id: &Pubkey) -> Result<Vec<Pubkey>, ProgramError> {
+        self.signers_seeds
+            .iter()
+            .map(|seeds| {
+                let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+                create_program_address(&seeds, program_id)
+            })
+            .collect()
+    }
+}
+
+/// Reset the runtime of the current thread to its default state.
+pub fn reset() {
+    with_runtime(|runtime| *runtime = Runtime::default());
+}
+
+/// Set the program being run, which owns the return data and signs for its
+/// program derived addresses.
+///
+/// Defaults to the zero public key.
+pub fn set_program_id(program_id: &Pubkey) {
+    with_runtime(|runtime| runtime.program_ids = std::vec![*program_id]);
+}
+
+/// Set the `Clock` sysvar.
+///
+/// Defaults to a clock with all fields set to zero.
+pub fn set_clock(clock: Clock) {
+    with_runtime(|runtime| runtime.clock = clock);
+}
+
+/// Set the `Rent` sysvar.
+///
+/// Defaults to the default rent of the runtime.
+pub fn set_rent(rent: Rent) {
+    with_runtime(|runtime| runtime.rent = rent);
+}
+
+/// Set the `Fees` sysvar.
+///
+/// Defaults to a zero cost per signature and the default fee rate governor.
+pub fn set_fees(fees: Fees) {
+    with_runtime(|runtime| runtime.fees = fees);
+}
+
+/// Set the `EpochSchedule` sysvar.
+///
+/// Defaults to the default epoch schedule of the runtime, with warmup.
+pub fn set_epoch_schedule(epoch_schedule: EpochSchedule) {
+    with_runtime(|runtime| runtime.epoch_schedule = epoch_schedule);
+}
+
+/// Set the `EpochRewards` sysvar.
+///
+/// Defaults to inactive epoch rewards with all fields set to zero.
+pub fn set_epoch_rewards(epoch_rewards: EpochRewards) {
+    with_runtime(|runtime| runtime.epoch_rewards = epoch_rewards);
+}
+
+/// Set the `LastRestartSlot` sysvar.
+///
+/// Defaults to slot zero.
+pub fn set_last_restart_slot(last_restart_slot: LastRestartSlot) {
+    with_runtime(|runtime| runtime.last_restart_slot = last_restart_slot);
+}
+
+/// Set the data of a sysvar account, read with [`get_sysvar`].
+///
+/// Reading a sysvar without data fails with `UnsupportedSysvar`.
+///
+/// [`get_sysvar`]: crate::sysvars::get_sysvar
+pub fn set_sysvar_data(sysvar_id: &Pubkey, data: &[u8]) {
+    with_runtime(|runtime| {
+        runtime.sysvar_data.insert(*sysvar_id, data.into());
+    });
+}
+
+/// Return the messages logged so far.
+pub fn logs() -> Vec<String> {
+    with_runtime(|runtime| runtime.logs.clone())
+}
+
+/// Return the messages logged so far, and clear them.
+pub fn take_logs() -> Vec<String> {
+    with_runtime(|runtime| core::mem::take(&mut runtime.logs))
+}
+
+/// Return the cross-program invocations performed so far.
+pub fn invoked_instructions() -> Vec<InvokedInstruction> {
+    with_runtime(|runtime| runtime.invoked_instructions.clone())
+}
+
+/// Return the cross-program invocations performed so far, and clear them.
+pub fn take_invoked_instructions() -> Vec<InvokedInstruction> {
+    with_runtime(|runtime| core::mem::take(&mut runtime.invoked_instructions))
+}
+
+/// Handle the cross-program invocations of the given program with `handler`.
+///
+/// The handler receives the invoked instruction and its accounts, which it can
+/// update as the invoked program would (e.g. transfer lamports or write data),
+/// following the borrow rules of the accounts. Returning an error fails the
+/// invocation.
+pub fn stub_program<F>(program_id: &Pubkey, handler: F)
+where
+    F: Fn(&InvokedInstruction, &[AccountInfo]) -> ProgramResult + 'static,
+{
+    with_runtime(|runtime| {
+        runtime.stubs.insert(*program_id, Rc::new(handler));
+    });
+}
+
+/// Return the return data and the program that set it.
+pub fn return_data() -> Option<(Pubkey, Vec<u8>)> {
+    with_runtime(|runtime| {
+        runtime
+            .return_data
+            .as_ref()
+            .map(|(program_id, data)| (*program_id, data.to_vec()))
+    })
+}
+
+pub(crate) fn log(message: String) {
+    with_runtime(|runtime| runtime.logs.push(message));
+}
+
+pub(crate) fn sol_log(message: &str) {
+    log(format!("Program log: {message}"));
+}
+
+pub(crate) fn sol_log_64(arg1: u64, arg2: u64, arg3: u64, arg4: u64, arg5: u64) {
+    log(format!(
+        "Program log: {arg1:#x}, {arg2:#x}, {arg3:#x}, {arg4:#x}, {arg5:#x}"
+    ));
+}
+
+pub(crate) fn sol_log_pubkey(pubkey: &Pubkey) {
+    log(format!("Program log: {}", encoding::base58(pubkey)));
+}
+
+pub(crate) fn sol_log_data(data: &[&[u8]]) {
+    let data: Vec<String> = data.iter().map(|bytes| encoding::base64(bytes)).collect();
+    log(format!("Program data: {}", data.join(" ")));
+}
+
+/// Write the sysvar loaded by the given syscall at `var_addr`.
+///
+/// # Safety
+///
+/// `var_addr` must be valid for writes of the sysvar type loaded by the syscall.
+pub(crate) unsafe fn get_sysvar_by_syscall(syscall: &str, var_addr: *mut u8) -> u64 {
+    with_runtime(|runtime| {
+        match syscall {
+            "sol_get_clock_sysvar" => (var_addr as *mut Clock).write(runtime.clock),
+            "sol_get_rent_sysvar" => (var_addr as *mut Rent).write(runtime.rent),
+            "sol_get_fees_sysvar" => (var_addr as *mut Fees).write(runtime.fees),
+            "sol_get_epoch_schedule_sysvar" => {
+                (var_addr as *mut EpochSchedule).write(runtime.epoch_schedule)
+            }
+            "sol_get_epoch_rewards_sysvar" => {
+                (var_addr as *mut EpochRewards).write(runtime.epoch_rewards)
+            }
+            "sol_get_last_restart_slot" => {
+                (var_addr as *mut LastRestartSlot).write(runtime.last_restart_slot)
+            }
+            // Not a `SUCCESS`.
+            _ => return 1,
+        }
+        SUCCESS
+    })
+}
+
+/// Copy `len` bytes of the data of the given sysvar, starting at `offset`.
+///
+/// # Safety
+///
+/// `dst` must be valid for writes of `len` bytes.
+pub(crate) unsafe fn get_sysvar(
+    dst: *mut u8,
+    sysvar_id: &Pubkey,
+    offset: usize,
+    len: usize,
+) -> Result<(), ProgramError> {
+    with_runtime(|runtime| {
+        let data = runtime
+            .sysvar_data
+            .get(sysvar_id)
+            .ok_or(ProgramError::UnsupportedSysvar)?;
+
+        let data = offset
+            .checked_add(len)
+            .and_then(|end| data.get(offset..end))
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        core::ptr::copy_nonoverlapping(data.as_ptr(), dst, len);
+        Ok(())
+    })
+}
+
+pub(crate) fn set_return_data(data: &[u8]) {
+    if data.len() > crate::cpi::MAX_RETURN_DATA {
+        panic!("return data too large: {} bytes", data.len());
+    }
+
+    with_runtime(|runtime| {
+        let program_id = *runtime.program_ids.last().unwrap();
+        runtime.return_data = if data.is_empty() {
+            None
+        } else {
+            Some((program_id, data.into()))
+        };
+    });
+}
+
+/// Record a cross-program invocation and run the handler of the invoked program.
+///
+/// # Safety
+///
+/// The accounts must have been created from `AccountInfo`s that are still valid.
+pub(crate) unsafe fn invoke(
+    instruction: &Instruction,
+    accounts: &[Account],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    let invoked_instruction = InvokedInstruction::new(instruction, signers_seeds);
+
+    // The return data is cleared before every invocation.
+    let handler = with_runtime(|runtime| {
+        runtime.return_data = None;
+        runtime
+            .invoked_instructions
+            .push(invoked_instruction.clone());
+        runtime.stubs.get(instruction.program_id).cloned()
+    });
+
+    let Some(handler) = handler else {
+        return Ok(());
+    };
+
+    let account_infos: Vec<AccountInfo> = accounts.iter().map(Account::account_info).collect();
+
+    with_runtime(|runtime| runtime.program_ids.push(*instruction.program_id));
+    let _guard = InvokedProgramGuard;
+
+    handler(&invoked_instruction, &account_infos)
+}
+
+/// Pops the invoked program from the runtime stack when dropped, so the caller
+/// becomes the running program again even if the stub panics.
+struct InvokedProgramGuard;
+
+impl Drop for InvokedProgramGuard {
+    fn drop(&mut self) {
+        with_runtime(|runtime| runtime.program_ids.pop());
+    }
+}
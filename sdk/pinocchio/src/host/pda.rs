@@ -0,0 +1,62 @@
+//! Program address derivation, as performed by the runtime.
+
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN, PDA_MARKER},
+};
+
+/// Indicates whether the given bytes are a valid point of the ed25519 curve.
+fn is_on_curve(bytes: &[u8; 32]) -> bool {
+    CompressedEdwardsY(*bytes).decompress().is_some()
+}
+
+pub(crate) fn create_program_address(
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    if seeds.len() > MAX_SEEDS || seeds.iter().any(|seed| seed.len() > MAX_SEED_LEN) {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        hasher.update(seed);
+    }
+    hasher.update(program_id);
+    hasher.update(PDA_MARKER);
+    let address = hasher.finalize().into();
+
+    if is_on_curve(&address) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(address)
+}
+
+pub(crate) fn try_find_program_address(
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Option<(Pubkey, u8)> {
+    // The bump seed is an additional seed.
+    if seeds.len() >= MAX_SEEDS {
+        return None;
+    }
+
+    let mut seeds_with_bump: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+    seeds_with_bump[..seeds.len()].copy_from_slice(seeds);
+
+    for bump in (0..=u8::MAX).rev() {
+        let bump_seed = [bump];
+        let mut seeds_with_bump = seeds_with_bump;
+        seeds_with_bump[seeds.len()] = &bump_seed;
+
+        if let Ok(address) = create_program_address(&seeds_with_bump[..=seeds.len()], program_id) {
+            return Some((address, bump));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,391 @@
+use std::{vec, vec::Vec};
+
+use super::*;
+use crate::{
+    cpi::{get_return_data, invoke, invoke_signed, set_return_data},
+    instruction::{AccountMeta, Seed},
+    log::{sol_log, sol_log_64, sol_log_data},
+    memory::{sol_memcmp, sol_memcpy, sol_memmove, sol_memset},
+    pubkey::{create_program_address, create_with_seed, find_program_address},
+    sysvars::{get_sysvar, Sysvar},
+    NON_DUP_MARKER,
+};
+
+/// Size of the runtime `Account` header preceding the account data.
+const ACCOUNT_HEADER_LEN: usize = 88;
+
+/// Return the (8-bytes aligned) memory of an account with the given key, lamports
+/// and data length, as serialized by the runtime.
+fn account_memory(key: Pubkey, lamports: u64, data_len: usize) -> Vec<u64> {
+    let mut memory = vec![0u64; (ACCOUNT_HEADER_LEN + data_len).div_ceil(8)];
+    // SAFETY: The memory is large enough for the account header.
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(memory.as_mut_ptr() as *mut u8, memory.len() * 8)
+    };
+    bytes[0] = NON_DUP_MARKER;
+    // is_signer and is_writable
+    bytes[1] = 1;
+    bytes[2] = 1;
+    bytes[8..40].copy_from_slice(&key);
+    bytes[72..80].copy_from_slice(&lamports.to_le_bytes());
+    bytes[80..88].copy_from_slice(&(data_len as u64).to_le_bytes());
+    memory
+}
+
+fn account_info(memory: &mut [u64]) -> AccountInfo {
+    AccountInfo {
+        raw: memory.as_mut_ptr() as *mut _,
+    }
+}
+
+#[test]
+fn test_find_program_address() {
+    let program_id = [7; 32];
+
+    assert_eq!(
+        find_program_address(&[b"vault", &[1; 32]], &program_id),
+        (
+            [
+                107, 13, 114, 233, 208, 142, 152, 42, 81, 89, 247, 217, 189, 177, 124, 235, 254,
+                96, 29, 127, 46, 130, 56, 153, 15, 86, 122, 214, 104, 75, 239, 62
+            ],
+            255
+        )
+    );
+
+    // the first bump seed derives a point on the curve
+    assert_eq!(
+        create_program_address(&[b"vault", &[1; 32], &[254]], &program_id),
+        Err(ProgramError::InvalidSeeds)
+    );
+}
+
+#[test]
+fn test_find_associated_token_address() {
+    const ATA_PROGRAM_ID: Pubkey = [
+        140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153,
+        218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89,
+    ];
+    const TOKEN_PROGRAM_ID: Pubkey = [
+        6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133,
+        237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+    ];
+
+    let (address, bump) =
+        find_program_address(&[&[2; 32], &TOKEN_PROGRAM_ID, &[3; 32]], &ATA_PROGRAM_ID);
+
+    assert_eq!(
+        address,
+        [
+            153, 88, 219, 56, 195, 187, 139, 240, 79, 109, 168, 29, 89, 211, 227, 79, 126, 23, 222,
+            62, 201, 75, 136, 94, 45, 121, 5, 14, 117, 255, 63, 110
+        ]
+    );
+    assert_eq!(bump, 254);
+    assert_eq!(
+        create_program_address(
+            &[&[2; 32], &TOKEN_PROGRAM_ID, &[3; 32], &[bump]],
+            &ATA_PROGRAM_ID
+        ),
+        Ok(address)
+    );
+}
+
+#[test]
+fn test_create_with_seed() {
+    assert_eq!(
+        create_with_seed(&[1; 32], b"seed", &[7; 32]),
+        Ok([
+            236, 239, 115, 203, 131, 217, 81, 49, 252, 59, 172, 152, 199, 180, 20, 64, 229, 128,
+            229, 100, 240, 221, 197, 121, 33, 160, 46, 109, 155, 141, 215, 197
+        ])
+    );
+}
+
+#[test]
+fn test_logs() {
+    sol_log("Hello");
+    sol_log_64(1, 2, 3, 4, 255);
+    crate::pubkey::log(&[1; 32]);
+    crate::pubkey::log(&[0; 32]);
+    sol_log_data(&[b"hello", b"pinocchio"]);
+
+    assert_eq!(
+        take_logs(),
+        [
+            "Program log: Hello",
+            "Program log: 0x1, 0x2, 0x3, 0x4, 0xff",
+            "Program log: 4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi",
+            "Program log: 11111111111111111111111111111111",
+            "Program data: aGVsbG8= cGlub2NjaGlv",
+        ]
+    );
+    assert!(logs().is_empty());
+}
+
+#[test]
+fn test_sysvars() {
+    assert_eq!(Clock::get().unwrap().slot, 0);
+    assert_eq!(
+        Rent::get().unwrap().lamports_per_byte_year,
+        DEFAULT_LAMPORTS_PER_BYTE_YEAR
+    );
+
+    set_clock(Clock {
+        slot: 10,
+        epoch_start_timestamp: 1,
+        epoch: 2,
+        leader_schedule_epoch: 3,
+        unix_timestamp: 4,
+    });
+    let clock = Clock::get().unwrap();
+    assert_eq!(clock.slot, 10);
+    assert_eq!(clock.unix_timestamp, 4);
+
+    let sysvar_id = [9; 32];
+    let mut buffer = [0u8; 4];
+    assert_eq!(
+        get_sysvar(&mut buffer, &sysvar_id, 0),
+        Err(ProgramError::UnsupportedSysvar)
+    );
+
+    set_sysvar_data(&sysvar_id, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(get_sysvar(&mut buffer, &sysvar_id, 2), Ok(()));
+    assert_eq!(buffer, [3, 4, 5, 6]);
+    assert_eq!(
+        get_sysvar(&mut buffer, &sysvar_id, 3),
+        Err(ProgramError::InvalidArgument)
+    );
+
+    reset();
+    assert_eq!(Clock::get().unwrap().slot, 0);
+}
+
+#[test]
+fn test_invoke() {
+    let program_id = [1; 32];
+    let callee_id = [2; 32];
+    set_program_id(&program_id);
+
+    let mut from_memory = account_memory([3; 32], 1_000, 0);
+    let mut to_memory = account_memory([4; 32], 0, 8);
+    let from = account_info(&mut from_memory);
+    let to = account_info(&mut to_memory);
+
+    // moves the lamports and writes the amount in the data of the destination
+    stub_program(&callee_id, |instruction, accounts| {
+        let amount = u64::from_le_bytes(instruction.data[..8].try_into().unwrap());
+        *accounts[0].try_borrow_mut_lamports()? -= amount;
+        *accounts[1].try_borrow_mut_lamports()? += amount;
+        accounts[1]
+            .try_borrow_mut_data()?
+            .copy_from_slice(&amount.to_le_bytes());
+        set_return_data(b"done");
+        Ok(())
+    });
+
+    let instruction = Instruction {
+        program_id: &callee_id,
+        accounts: &[
+            AccountMeta::writable_signer(from.key()),
+            AccountMeta::writable(to.key()),
+        ],
+        data: &400u64.to_le_bytes(),
+    };
+    invoke(&instruction, &[&from, &to]).unwrap();
+
+    assert_eq!(from.lamports(), 600);
+    assert_eq!(to.lamports(), 400);
+    assert_eq!(*to.try_borrow_data().unwrap(), 400u64.to_le_bytes());
+
+    let return_data = get_return_data().unwrap();
+    assert_eq!(return_data.program_id(), &callee_id);
+    assert_eq!(return_data.as_slice(), b"done");
+
+    assert_eq!(
+        take_invoked_instructions(),
+        [InvokedInstruction {
+            program_id: callee_id,
+            accounts: vec![
+                InvokedAccountMeta {
+                    pubkey: [3; 32],
+                    is_writable: true,
+                    is_signer: true,
+                },
+                InvokedAccountMeta {
+                    pubkey: [4; 32],
+                    is_writable: true,
+                    is_signer: false,
+                },
+            ],
+            data: 400u64.to_le_bytes().to_vec(),
+            signers_seeds: vec![],
+        }]
+    );
+
+    // the borrow rules of the accounts are enforced
+    let data = to.try_borrow_data().unwrap();
+    assert_eq!(
+        invoke(&instruction, &[&from, &to]),
+        Err(ProgramError::AccountBorrowFailed)
+    );
+    drop(data);
+
+    // the return data of the program is cleared by the invocation
+    set_return_data(b"before");
+    assert_eq!(return_data_owner(), Some(program_id));
+    stub_program(&callee_id, |_, _| Err(ProgramError::Custom(7)));
+    assert_eq!(
+        invoke(&instruction, &[&from, &to]),
+        Err(ProgramError::Custom(7))
+    );
+    assert!(get_return_data().is_none());
+
+    // the caller is the running program again after a panicking stub
+    stub_program(&callee_id, |_, _| panic!("stub panicked"));
+    let result = std::panic::catch_unwind(|| invoke(&instruction, &[&from, &to]));
+    assert!(result.is_err());
+    set_return_data(b"after");
+    assert_eq!(return_data_owner(), Some(program_id));
+}
+
+fn return_data_owner() -> Option<Pubkey> {
+    return_data().map(|(program_id, _)| program_id)
+}
+
+#[test]
+fn test_invoke_signed() {
+    let program_id = [7; 32];
+    let (vault, bump) = find_program_address(&[b"vault", &[1; 32]], &program_id);
+    set_program_id(&program_id);
+
+    let mut vault_memory = account_memory(vault, 0, 0);
+    let vault_info = account_info(&mut vault_memory);
+
+    let instruction = Instruction {
+        program_id: &[2; 32],
+        accounts: &[AccountMeta::writable_signer(vault_info.key())],
+        data: &[],
+    };
+    let bump_seed = [bump];
+    let seeds = [
+        Seed::from(b"vault"),
+        Seed::from(&[1; 32]),
+        Seed::from(&bump_seed),
+    ];
+    invoke_signed(&instruction, &[&vault_info], &[Signer::from(&seeds)]).unwrap();
+
+    let invoked_instructions = invoked_instructions();
+    assert_eq!(
+        invoked_instructions[0].signers_seeds,
+        [vec![b"vault".to_vec(), vec![1; 32], vec![bump]]]
+    );
+    assert_eq!(
+        invoked_instructions[0].signer_addresses(&program_id),
+        Ok(vec![vault])
+    );
+}
+
+#[test]
+fn test_memory() {
+    let mut dst = [0u8; 4];
+    unsafe { sol_memcpy(&mut dst, &[1, 2, 3, 4], 4) };
+    assert_eq!(dst, [1, 2, 3, 4]);
+
+    unsafe { sol_memmove(dst.as_mut_ptr().add(1), dst.as_ptr(), 3) };
+    assert_eq!(dst, [1, 1, 2, 3]);
+
+    unsafe { sol_memset(&mut dst, 9, 2) };
+    assert_eq!(dst, [9, 9, 2, 3]);
+
+    assert_eq!(unsafe { sol_memcmp(&dst, &[9, 9, 2, 3], 4) }, 0);
+    assert_eq!(unsafe { sol_memcmp(&dst, &[9, 9, 5, 3], 4) }, -3);
+    assert_eq!(unsafe { sol_memcmp(&dst, &[9, 9, 5, 3], 2) }, 0);
+}
+
+#[test]
+fn test_slot_hashes() {
+    use crate::sysvars::slot_hashes::{SlotHashes, ENTRY_SIZE, MAX_SIZE, SLOTHASHES_ID};
+
+    let mut data = vec![0u8; MAX_SIZE];
+    data[..8].copy_from_slice(&2u64.to_le_bytes());
+    data[8..16].copy_from_slice(&20u64.to_le_bytes());
+    data[16..48].copy_from_slice(&[1; 32]);
+    data[8 + ENTRY_SIZE..16 + ENTRY_SIZE].copy_from_slice(&19u64.to_le_bytes());
+    data[16 + ENTRY_SIZE..48 + ENTRY_SIZE].copy_from_slice(&[2; 32]);
+    set_sysvar_data(&SLOTHASHES_ID, &data);
+
+    let slot_hashes = SlotHashes::<std::boxed::Box<[u8]>>::fetch().unwrap();
+    assert_eq!(slot_hashes.len(), 2);
+    assert_eq!(slot_hashes.get_hash(19), Some(&[2; 32]));
+}
+
+#[test]
+fn test_epoch_sysvars() {
+    use crate::sysvars::{
+        epoch_rewards::EpochRewards, epoch_schedule::EpochSchedule,
+        last_restart_slot::LastRestartSlot,
+    };
+
+    assert_eq!(EpochSchedule::get(), Ok(EpochSchedule::default()));
+    assert_eq!(EpochRewards::get(), Ok(EpochRewards::default()));
+    assert_eq!(LastRestartSlot::get(), Ok(LastRestartSlot::default()));
+
+    set_epoch_schedule(EpochSchedule::new(64));
+    let epoch_rewards = EpochRewards {
+        total_points: u128::MAX,
+        total_rewards: 100,
+        active: true,
+        ..EpochRewards::default()
+    };
+    set_epoch_rewards(epoch_rewards);
+    set_last_restart_slot(LastRestartSlot {
+        last_restart_slot: 42,
+    });
+
+    assert_eq!(EpochSchedule::get().unwrap().get_epoch(100), 2);
+    assert_eq!(EpochRewards::get(), Ok(epoch_rewards));
+    assert_eq!(LastRestartSlot::get().unwrap().last_restart_slot, 42);
+}
+
+#[test]
+fn test_stake_history() {
+    use crate::sysvars::stake_history::{StakeHistory, StakeHistoryEntry, STAKE_HISTORY_ID};
+
+    // entries of the epochs 9 and 8
+    let mut data = 2u64.to_le_bytes().to_vec();
+    for (epoch, effective) in [(9u64, 900u64), (8, 800)] {
+        data.extend_from_slice(&epoch.to_le_bytes());
+        data.extend_from_slice(&effective.to_le_bytes());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&2u64.to_le_bytes());
+    }
+    set_sysvar_data(&STAKE_HISTORY_ID, &data);
+
+    set_clock(Clock {
+        slot: 0,
+        epoch_start_timestamp: 0,
+        epoch: 10,
+        leader_schedule_epoch: 0,
+        unix_timestamp: 0,
+    });
+    let stake_history = StakeHistory::fetch().unwrap();
+
+    assert_eq!(stake_history.current_epoch(), 10);
+    assert_eq!(stake_history.len(), Ok(2));
+    assert_eq!(
+        stake_history.get_entry(8),
+        Some(StakeHistoryEntry {
+            effective: 800,
+            activating: 1,
+            deactivating: 2,
+        })
+    );
+    assert_eq!(stake_history.get_entry(9).unwrap().effective, 900);
+    assert_eq!(stake_history.get_entry(10), None);
+    assert_eq!(stake_history.get_entry(7), None);
+
+    // the entries do not match the epochs of the view
+    assert_eq!(StakeHistory::new(11).get_entry(9), None);
+    assert_eq!(StakeHistory::new(0).get_entry(0), None);
+}
@@ -114,6 +114,19 @@ impl<'a> From<&'a AccountInfo> for Account<'a> {
     }
 }
 
+#[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+impl Account<'_> {
+    /// Return the `AccountInfo` this account was created from.
+    #[inline(always)]
+    pub(crate) fn account_info(&self) -> AccountInfo {
+        AccountInfo {
+            // SAFETY: offset `8` is the `key` field in the `Account` struct the
+            // `AccountInfo` points to.
+            raw: unsafe { (self.key as *const u8).sub(8) as *mut _ },
+        }
+    }
+}
+
 /// Describes a single account read or written by a program during instruction
 /// execution.
 ///
@@ -180,6 +180,39 @@
 //! crate. This crate provides a lightweight `log!` macro with better compute units
 //! consumption than the standard `format!` macro without requiring the `std` library.
 //!
+//! The `std` feature also enables the `entrypoint::input` module, which provides an
+//! `InputBuilder` to serialize accounts, instruction data and program id as the input
+//! buffer of the SVM loader. This allows `process_instruction` functions and the
+//! entrypoints to be driven directly in host tests and fuzzers.
+//!
+//! ## `host-runtime` crate feature
+//!
+//! On non-`solana` targets, the syscall wrappers of Pinocchio do nothing by default.
+//! Enabling the `host-runtime` feature (which implies `std`) replaces them with a
+//! thread-local runtime emulating the syscalls, so program logic can be unit-tested
+//! with `cargo test` without building an SBF binary:
+//! ```ignore
+//! [dev-dependencies]
+//! pinocchio = { git = "https://github.com/anza-xyz/pinocchio", features = ["host-runtime"] }
+//! ```
+//!
+//! The runtime is configured and inspected with the functions of the `host` module.
+//! The feature also enables the `hash-fallback` and `crypto-fallback` features.
+//! The feature has no effect when building for the `solana` target.
+//!
+//! ## `hash-fallback` and `crypto-fallback` crate features
+//!
+//! The functions of the [`hash`] and [`crypto`] modules call syscalls, so they panic
+//! on non-`solana` targets by default. Enabling the `hash-fallback` and
+//! `crypto-fallback` features computes them with pure-Rust implementations of the
+//! same algorithms instead, without the rest of the host runtime:
+//! ```ignore
+//! [dev-dependencies]
+//! pinocchio = { git = "https://github.com/anza-xyz/pinocchio", features = ["hash-fallback", "crypto-fallback"] }
+//! ```
+//!
+//! The features have no effect when building for the `solana` target.
+//!
 //! ## Advanced entrypoint configuration
 //!
 //! The symbols emitted by the entrypoint macros - program entrypoint, global
@@ -225,7 +258,11 @@ extern crate std;
 
 pub mod account_info;
 pub mod cpi;
+pub mod crypto;
 pub mod entrypoint;
+pub mod hash;
+#[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+pub mod host;
 pub mod instruction;
 pub mod log;
 pub mod memory;
@@ -29,7 +29,10 @@ pub fn log(pubkey: &Pubkey) {
         crate::syscalls::sol_log_pubkey(pubkey as *const _ as *const u8)
     };
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    crate::host::sol_log_pubkey(pubkey);
+
+    #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
     core::hint::black_box(pubkey);
 }
 
@@ -144,7 +147,12 @@ pub fn try_find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Option<
         }
     }
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    {
+        crate::host::try_find_program_address(seeds, program_id)
+    }
+
+    #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
     {
         core::hint::black_box((seeds, program_id));
         None
@@ -197,7 +205,12 @@ pub fn create_program_address(
         }
     }
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    {
+        crate::host::create_program_address(seeds, program_id)
+    }
+
+    #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
     {
         core::hint::black_box((seeds, program_id));
         panic!("create_program_address is only available on target `solana`")
@@ -271,7 +284,12 @@ pub fn create_with_seed(
         Ok(unsafe { bytes.assume_init() })
     }
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    {
+        Ok(crate::hash::fallback::sha256v(&[base, seed, program_id]))
+    }
+
+    #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
     {
         core::hint::black_box((base, seed, program_id));
         panic!("create_with_seed is only available on target `solana`")
@@ -0,0 +1,179 @@
+//! Information about the epoch rewards distribution.
+//!
+//! The epoch rewards sysvar is active while the rewards of the previous epoch are
+//! being distributed at the beginning of an epoch.
+
+use super::{slot_hashes::Hash, Sysvar};
+use crate::{
+    account_info::AccountInfo, impl_sysvar_get, program_error::ProgramError, pubkey::Pubkey,
+};
+
+/// The ID of the epoch rewards sysvar.
+pub const EPOCH_REWARDS_ID: Pubkey = [
+    6, 167, 213, 23, 24, 220, 63, 238, 2, 165, 88, 191, 131, 206, 102, 225, 68, 66, 42, 28, 52,
+    149, 11, 39, 193, 134, 155, 90, 156, 0, 0, 0,
+];
+
+/// Epoch rewards sysvar data.
+#[repr(C, align(16))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EpochRewards {
+    /// The starting block height of the rewards distribution in the current
+    /// epoch.
+    pub distribution_starting_block_height: u64,
+
+    /// Number of partitions in the rewards distribution in the current epoch.
+    pub num_partitions: u64,
+
+    /// The blockhash of the parent block of the first block in the epoch.
+    pub parent_blockhash: Hash,
+
+    /// The total rewards points calculated for the current epoch, where points
+    /// equals the sum of (delegated stake * credits observed) for all
+    /// delegations.
+    pub total_points: u128,
+
+    /// The total rewards calculated for the current epoch.
+    ///
+    /// This may be greater than the total `distributed_rewards` at the end of the
+    /// rewards period, due to rounding and inability to deliver rewards smaller
+    /// than 1 lamport.
+    pub total_rewards: u64,
+
+    /// The rewards currently distributed for the current epoch, in lamports.
+    pub distributed_rewards: u64,
+
+    /// Whether the rewards period (including calculation and distribution) is
+    /// active.
+    pub active: bool,
+}
+
+impl Sysvar for EpochRewards {
+    impl_sysvar_get!(sol_get_epoch_rewards_sysvar);
+}
+
+impl EpochRewards {
+    /// The length of the `EpochRewards` sysvar account data.
+    ///
+    /// Note that this is different than the size of the `EpochRewards` type, since
+    /// the account data is not padded.
+    pub const LEN: usize = 8 + 8 + 32 + 16 + 8 + 8 + 1;
+
+    /// Return an `EpochRewards` from the given account info.
+    ///
+    /// This method performs a check on the account info key. Since the account data
+    /// is not aligned as the `EpochRewards` type, the value is read from the account
+    /// data instead of referencing it.
+    #[inline]
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        if account_info.key() != &EPOCH_REWARDS_ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Self::from_bytes(&account_info.try_borrow_data()?)
+    }
+
+    /// Return an `EpochRewards` from the given account info.
+    ///
+    /// This method performs a check on the account info key, but does not
+    /// perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data - e.g., there are
+    /// no mutable borrows of the account data.
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if account_info.key() != &EPOCH_REWARDS_ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Self::from_bytes(account_info.borrow_data_unchecked())
+    }
+
+    /// Return an `EpochRewards` from the given (serialized) bytes.
+    ///
+    /// This method performs a length validation and checks that the `active` flag
+    /// is a valid boolean value.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProgramError> {
+        if bytes.len() < Self::LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let read_u64 = |offset: usize| {
+            // SAFETY: `bytes` has been validated to be at least `Self::LEN` bytes long.
+            u64::from_le_bytes(unsafe { *(bytes.as_ptr().add(offset) as *const [u8; 8]) })
+        };
+
+        let active = match bytes[80] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        // SAFETY: `bytes` has been validated to be at least `Self::LEN` bytes long.
+        let (parent_blockhash, total_points) = unsafe {
+            (
+                *(bytes.as_ptr().add(16) as *const Hash),
+                u128::from_le_bytes(*(bytes.as_ptr().add(48) as *const [u8; 16])),
+            )
+        };
+
+        Ok(Self {
+            distribution_starting_block_height: read_u64(0),
+            num_partitions: read_u64(8),
+            parent_blockhash,
+            total_points,
+            total_rewards: read_u64(64),
+            distributed_rewards: read_u64(72),
+            active,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bytes() {
+        let mut bytes = [0u8; EpochRewards::LEN];
+        bytes[0..8].copy_from_slice(&64u64.to_le_bytes());
+        bytes[8..16].copy_from_slice(&2u64.to_le_bytes());
+        bytes[16..48].copy_from_slice(&[3; 32]);
+        bytes[48..64].copy_from_slice(&(u64::MAX as u128 + 1).to_le_bytes());
+        bytes[64..72].copy_from_slice(&100u64.to_le_bytes());
+        bytes[72..80].copy_from_slice(&40u64.to_le_bytes());
+        bytes[80] = 1;
+
+        assert_eq!(
+            EpochRewards::from_bytes(&bytes),
+            Ok(EpochRewards {
+                distribution_starting_block_height: 64,
+                num_partitions: 2,
+                parent_blockhash: [3; 32],
+                total_points: u64::MAX as u128 + 1,
+                total_rewards: 100,
+                distributed_rewards: 40,
+                active: true,
+            })
+        );
+        assert_eq!(
+            EpochRewards::from_bytes(&bytes[..EpochRewards::LEN - 1]),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        bytes[80] = 2;
+        assert_eq!(
+            EpochRewards::from_bytes(&bytes),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_size_of() {
+        // The size of the type written by the `sol_get_epoch_rewards_sysvar` syscall.
+        assert_eq!(core::mem::size_of::<EpochRewards>(), 96);
+    }
+}
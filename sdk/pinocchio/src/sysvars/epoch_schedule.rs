@@ -0,0 +1,384 @@
+//! Information about epoch duration.
+//!
+//! The epoch schedule determines the number of slots of each epoch. In order to
+//! accelerate the start of a cluster, the first epochs can be shorter (_warmup_):
+//! starting at [`MINIMUM_SLOTS_PER_EPOCH`], the number of slots doubles at each
+//! epoch until it reaches `slots_per_epoch`.
+
+use super::{
+    clock::{Epoch, Slot},
+    Sysvar,
+};
+use crate::{
+    account_info::AccountInfo, impl_sysvar_get, program_error::ProgramError, pubkey::Pubkey,
+};
+
+/// The ID of the epoch schedule sysvar.
+pub const EPOCH_SCHEDULE_ID: Pubkey = [
+    6, 167, 213, 23, 24, 220, 63, 238, 2, 211, 228, 127, 1, 0, 248, 176, 84, 247, 148, 46, 96, 89,
+    30, 63, 80, 135, 25, 168, 5, 0, 0, 0,
+];
+
+/// The default number of slots per epoch (about 2 days at 400 ms per slot).
+pub const DEFAULT_SLOTS_PER_EPOCH: u64 = 432_000;
+
+/// The default number of slots before an epoch starts to calculate its leader schedule.
+pub const DEFAULT_LEADER_SCHEDULE_SLOT_OFFSET: u64 = DEFAULT_SLOTS_PER_EPOCH;
+
+/// The maximum number of epochs for which the leader schedule can be calculated
+/// in advance.
+pub const MAX_LEADER_SCHEDULE_EPOCH_OFFSET: u64 = 3;
+
+/// The minimum number of slots per epoch during the warmup period.
+///
+/// Based on `MAX_LOCKOUT_HISTORY` from the vote program.
+pub const MINIMUM_SLOTS_PER_EPOCH: u64 = 32;
+
+/// Epoch schedule sysvar data.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EpochSchedule {
+    /// The maximum number of slots in each epoch.
+    pub slots_per_epoch: u64,
+
+    /// A number of slots before beginning of an epoch to calculate
+    /// a leader schedule for that epoch.
+    pub leader_schedule_slot_offset: u64,
+
+    /// Whether epochs start short and grow.
+    pub warmup: bool,
+
+    /// The first epoch after the warmup period.
+    ///
+    /// Basically: `log2(slots_per_epoch) - log2(MINIMUM_SLOTS_PER_EPOCH)`.
+    pub first_normal_epoch: Epoch,
+
+    /// The first slot after the warmup period.
+    ///
+    /// Basically: `MINIMUM_SLOTS_PER_EPOCH * (2.pow(first_normal_epoch) - 1)`.
+    pub first_normal_slot: Slot,
+}
+
+impl Default for EpochSchedule {
+    fn default() -> Self {
+        Self::custom(
+            DEFAULT_SLOTS_PER_EPOCH,
+            DEFAULT_LEADER_SCHEDULE_SLOT_OFFSET,
+            true,
+        )
+    }
+}
+
+impl Sysvar for EpochSchedule {
+    impl_sysvar_get!(sol_get_epoch_schedule_sysvar);
+}
+
+impl EpochSchedule {
+    /// The length of the `EpochSchedule` sysvar account data.
+    ///
+    /// Note that this is different than the size of the `EpochSchedule` type, since
+    /// the account data is not padded.
+    pub const LEN: usize = 8 + 8 + 1 + 8 + 8;
+
+    /// Creates a new `EpochSchedule` with warmup, using `slots_per_epoch` as the
+    /// leader schedule slot offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slots_per_epoch` is less than [`MINIMUM_SLOTS_PER_EPOCH`].
+    pub fn new(slots_per_epoch: u64) -> Self {
+        Self::custom(slots_per_epoch, slots_per_epoch, true)
+    }
+
+    /// Creates a new `EpochSchedule` with the default values and no warmup.
+    pub fn without_warmup() -> Self {
+        Self::custom(
+            DEFAULT_SLOTS_PER_EPOCH,
+            DEFAULT_LEADER_SCHEDULE_SLOT_OFFSET,
+            false,
+        )
+    }
+
+    /// Creates a new `EpochSchedule`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slots_per_epoch` is less than [`MINIMUM_SLOTS_PER_EPOCH`].
+    pub fn custom(slots_per_epoch: u64, leader_schedule_slot_offset: u64, warmup: bool) -> Self {
+        assert!(slots_per_epoch >= MINIMUM_SLOTS_PER_EPOCH);
+
+        let (first_normal_epoch, first_normal_slot) = if warmup {
+            let next_power_of_two = slots_per_epoch.next_power_of_two();
+            let log2_slots_per_epoch = next_power_of_two
+                .trailing_zeros()
+                .saturating_sub(MINIMUM_SLOTS_PER_EPOCH.trailing_zeros());
+
+            (
+                u64::from(log2_slots_per_epoch),
+                next_power_of_two.saturating_sub(MINIMUM_SLOTS_PER_EPOCH),
+            )
+        } else {
+            (0, 0)
+        };
+
+        Self {
+            slots_per_epoch,
+            leader_schedule_slot_offset,
+            warmup,
+            first_normal_epoch,
+            first_normal_slot,
+        }
+    }
+
+    /// Return an `EpochSchedule` from the given account info.
+    ///
+    /// This method performs a check on the account info key. Since the layout of the
+    /// account data does not match the layout of the `EpochSchedule` type, the value
+    /// is read from the account data instead of referencing it.
+    #[inline]
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        if account_info.key() != &EPOCH_SCHEDULE_ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Self::from_bytes(&account_info.try_borrow_data()?)
+    }
+
+    /// Return an `EpochSchedule` from the given account info.
+    ///
+    /// This method performs a check on the account info key, but does not
+    /// perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data - e.g., there are
+    /// no mutable borrows of the account data.
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if account_info.key() != &EPOCH_SCHEDULE_ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Self::from_bytes(account_info.borrow_data_unchecked())
+    }
+
+    /// Return an `EpochSchedule` from the given (serialized) bytes.
+    ///
+    /// This method performs a length validation and checks that the `warmup` flag
+    /// is a valid boolean value.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProgramError> {
+        if bytes.len() < Self::LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let read_u64 = |offset: usize| {
+            // SAFETY: `bytes` has been validated to be at least `Self::LEN` bytes long.
+            u64::from_le_bytes(unsafe { *(bytes.as_ptr().add(offset) as *const [u8; 8]) })
+        };
+
+        let warmup = match bytes[16] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        Ok(Self {
+            slots_per_epoch: read_u64(0),
+            leader_schedule_slot_offset: read_u64(8),
+            warmup,
+            first_normal_epoch: read_u64(17),
+            first_normal_slot: read_u64(25),
+        })
+    }
+
+    /// Get the length of the given epoch (in slots).
+    pub fn get_slots_in_epoch(&self, epoch: Epoch) -> u64 {
+        if epoch < self.first_normal_epoch {
+            2u64.saturating_pow(
+                (epoch as u32).saturating_add(MINIMUM_SLOTS_PER_EPOCH.trailing_zeros()),
+            )
+        } else {
+            self.slots_per_epoch
+        }
+    }
+
+    /// Get the epoch for which the given slot should save off information about
+    /// stakers.
+    pub fn get_leader_schedule_epoch(&self, slot: Slot) -> Epoch {
+        if slot < self.first_normal_slot {
+            // until we get to normal slots, behave as if leader_schedule_slot_offset == slots_per_epoch
+            self.get_epoch_and_slot_index(slot).0.saturating_add(1)
+        } else {
+            let new_slots_since_first_normal_slot = slot.saturating_sub(self.first_normal_slot);
+            let new_first_normal_leader_schedule_slot =
+                new_slots_since_first_normal_slot.saturating_add(self.leader_schedule_slot_offset);
+            let new_epochs_since_first_normal_leader_schedule =
+                new_first_normal_leader_schedule_slot
+                    .checked_div(self.slots_per_epoch)
+                    .unwrap_or(0);
+
+            self.first_normal_epoch
+                .saturating_add(new_epochs_since_first_normal_leader_schedule)
+        }
+    }
+
+    /// Get the epoch for the given slot.
+    pub fn get_epoch(&self, slot: Slot) -> Epoch {
+        self.get_epoch_and_slot_index(slot).0
+    }
+
+    /// Get the epoch and offset into the epoch for the given slot.
+    pub fn get_epoch_and_slot_index(&self, slot: Slot) -> (Epoch, u64) {
+        if slot < self.first_normal_slot {
+            let epoch = slot
+                .saturating_add(MINIMUM_SLOTS_PER_EPOCH)
+                .saturating_add(1)
+                .next_power_of_two()
+                .trailing_zeros()
+                .saturating_sub(MINIMUM_SLOTS_PER_EPOCH.trailing_zeros())
+                .saturating_sub(1);
+
+            let epoch_len =
+                2u64.saturating_pow(epoch.saturating_add(MINIMUM_SLOTS_PER_EPOCH.trailing_zeros()));
+
+            (
+                u64::from(epoch),
+                slot.saturating_sub(epoch_len.saturating_sub(MINIMUM_SLOTS_PER_EPOCH)),
+            )
+        } else {
+            let normal_slot_index = slot.saturating_sub(self.first_normal_slot);
+            let normal_epoch_index = normal_slot_index
+                .checked_div(self.slots_per_epoch)
+                .unwrap_or(0);
+            let epoch = self.first_normal_epoch.saturating_add(normal_epoch_index);
+            let slot_index = normal_slot_index
+                .checked_rem(self.slots_per_epoch)
+                .unwrap_or(0);
+
+            (epoch, slot_index)
+        }
+    }
+
+    /// Get the first slot of the given epoch.
+    pub fn get_first_slot_in_epoch(&self, epoch: Epoch) -> Slot {
+        if epoch <= self.first_normal_epoch {
+            2u64.saturating_pow(epoch as u32)
+                .saturating_sub(1)
+                .saturating_mul(MINIMUM_SLOTS_PER_EPOCH)
+        } else {
+            epoch
+                .saturating_sub(self.first_normal_epoch)
+                .saturating_mul(self.slots_per_epoch)
+                .saturating_add(self.first_normal_slot)
+        }
+    }
+
+    /// Get the last slot of the given epoch.
+    pub fn get_last_slot_in_epoch(&self, epoch: Epoch) -> Slot {
+        self.get_first_slot_in_epoch(epoch)
+            .saturating_add(self.get_slots_in_epoch(epoch))
+            .saturating_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_schedule() {
+        for slots_per_epoch in MINIMUM_SLOTS_PER_EPOCH..=MINIMUM_SLOTS_PER_EPOCH * 16 {
+            let epoch_schedule = EpochSchedule::custom(slots_per_epoch, slots_per_epoch / 2, true);
+
+            assert_eq!(epoch_schedule.get_first_slot_in_epoch(0), 0);
+            assert_eq!(
+                epoch_schedule.get_last_slot_in_epoch(0),
+                MINIMUM_SLOTS_PER_EPOCH - 1
+            );
+
+            let mut last_leader_schedule = 0;
+            let mut last_epoch = 0;
+            let mut last_slots_in_epoch = MINIMUM_SLOTS_PER_EPOCH;
+
+            for slot in 0..(2 * slots_per_epoch) {
+                // verify that the leader schedule epoch is increasing by at most one
+                let leader_schedule = epoch_schedule.get_leader_schedule_epoch(slot);
+                if leader_schedule != last_leader_schedule {
+                    assert_eq!(leader_schedule, last_leader_schedule + 1);
+                    last_leader_schedule = leader_schedule;
+                }
+
+                let (epoch, offset) = epoch_schedule.get_epoch_and_slot_index(slot);
+
+                // verify that the epoch is increasing by at most one
+                if epoch != last_epoch {
+                    assert_eq!(epoch, last_epoch + 1);
+                    last_epoch = epoch;
+                    assert_eq!(epoch_schedule.get_first_slot_in_epoch(epoch), slot);
+                    assert_eq!(epoch_schedule.get_last_slot_in_epoch(epoch - 1), slot - 1);
+
+                    // verify that the slots per epoch are doubling during the warmup
+                    let slots_in_epoch = epoch_schedule.get_slots_in_epoch(epoch);
+                    if slots_in_epoch != last_slots_in_epoch && slots_in_epoch != slots_per_epoch {
+                        assert_eq!(slots_in_epoch, last_slots_in_epoch * 2);
+                    }
+                    last_slots_in_epoch = slots_in_epoch;
+                }
+
+                assert!(offset < last_slots_in_epoch);
+            }
+
+            assert!(last_leader_schedule != 0);
+            assert!(last_epoch != 0);
+            assert!(last_slots_in_epoch == slots_per_epoch);
+        }
+    }
+
+    #[test]
+    fn test_default_epoch_schedule() {
+        let epoch_schedule = EpochSchedule::default();
+
+        assert_eq!(epoch_schedule.first_normal_epoch, 14);
+        assert_eq!(epoch_schedule.first_normal_slot, 524_256);
+        assert_eq!(epoch_schedule.get_epoch(524_255), 13);
+        assert_eq!(epoch_schedule.get_epoch(524_256), 14);
+        assert_eq!(
+            epoch_schedule.get_first_slot_in_epoch(15),
+            524_256 + DEFAULT_SLOTS_PER_EPOCH
+        );
+
+        let epoch_schedule = EpochSchedule::without_warmup();
+
+        assert_eq!(epoch_schedule.get_epoch(DEFAULT_SLOTS_PER_EPOCH * 3 + 1), 3);
+        assert_eq!(
+            epoch_schedule.get_first_slot_in_epoch(3),
+            DEFAULT_SLOTS_PER_EPOCH * 3
+        );
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let mut bytes = [0u8; EpochSchedule::LEN];
+        bytes[0..8].copy_from_slice(&DEFAULT_SLOTS_PER_EPOCH.to_le_bytes());
+        bytes[8..16].copy_from_slice(&DEFAULT_LEADER_SCHEDULE_SLOT_OFFSET.to_le_bytes());
+        bytes[16] = 1;
+        bytes[17..25].copy_from_slice(&14u64.to_le_bytes());
+        bytes[25..33].copy_from_slice(&524_256u64.to_le_bytes());
+
+        assert_eq!(
+            EpochSchedule::from_bytes(&bytes),
+            Ok(EpochSchedule::default())
+        );
+        assert_eq!(
+            EpochSchedule::from_bytes(&bytes[..EpochSchedule::LEN - 1]),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        bytes[16] = 2;
+        assert_eq!(
+            EpochSchedule::from_bytes(&bytes),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
@@ -3,14 +3,18 @@
 #[cfg(target_os = "solana")]
 use crate::syscalls::sol_get_sysvar;
 use crate::{program_error::ProgramError, pubkey::Pubkey};
-#[cfg(not(target_os = "solana"))]
+#[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
 use core::hint::black_box;
 
 pub mod clock;
+pub mod epoch_rewards;
+pub mod epoch_schedule;
 pub mod fees;
 pub mod instructions;
+pub mod last_restart_slot;
 pub mod rent;
 pub mod slot_hashes;
+pub mod stake_history;
 
 /// Return value indicating that the `offset + length` is greater than the length of
 /// the sysvar data.
@@ -52,7 +56,10 @@ macro_rules! impl_sysvar_get {
             let result = unsafe { $crate::syscalls::$syscall_name(var_addr) };
 
             #[cfg(not(target_os = "solana"))]
-            let result = core::hint::black_box(var_addr as *const _ as u64);
+            // SAFETY: `var_addr` is valid for writes of the sysvar loaded by the syscall.
+            let result = unsafe {
+                $crate::sysvars::get_sysvar_off_chain(stringify!($syscall_name), var_addr)
+            };
 
             match result {
                 $crate::SUCCESS => {
@@ -66,6 +73,32 @@ macro_rules! impl_sysvar_get {
     };
 }
 
+/// Load the sysvar of the given syscall on non-`solana` targets, used by
+/// [`impl_sysvar_get`].
+///
+/// The sysvar is read from the host runtime when the `host-runtime` feature is
+/// enabled; otherwise, loading the sysvar fails.
+///
+/// # Safety
+///
+/// The caller must ensure that `var_addr` is valid for writes of the sysvar type
+/// loaded by the syscall.
+#[doc(hidden)]
+#[cfg(not(target_os = "solana"))]
+#[inline(always)]
+pub unsafe fn get_sysvar_off_chain(syscall_name: &str, var_addr: *mut u8) -> u64 {
+    #[cfg(feature = "host-runtime")]
+    {
+        crate::host::get_sysvar_by_syscall(syscall_name, var_addr)
+    }
+
+    #[cfg(not(feature = "host-runtime"))]
+    {
+        black_box(syscall_name);
+        black_box(var_addr as *const _ as u64)
+    }
+}
+
 /// Handler for retrieving a slice of sysvar data from the `sol_get_sysvar`
 /// syscall.
 ///
@@ -100,7 +133,12 @@ pub unsafe fn get_sysvar_unchecked(
         }
     }
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(feature = "host-runtime", not(target_os = "solana")))]
+    {
+        crate::host::get_sysvar(dst, sysvar_id, offset, len)
+    }
+
+    #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
     {
         black_box((dst, sysvar_id, offset, len));
         Ok(())
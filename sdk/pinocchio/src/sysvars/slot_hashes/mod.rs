@@ -300,7 +300,7 @@ impl SlotHashes<Box<[u8]>> {
         crate::sysvars::get_sysvar_unchecked(buffer_ptr, &SLOTHASHES_ID, 0, MAX_SIZE)?;
 
         // For tests on builds that don't actually fill the buffer.
-        #[cfg(not(target_os = "solana"))]
+        #[cfg(all(not(feature = "host-runtime"), not(target_os = "solana")))]
         core::ptr::write_bytes(buffer_ptr, 0, NUM_ENTRIES_SIZE);
 
         Ok(())
@@ -139,6 +139,7 @@ fn test_fetch_into_offset_validation() {
 /// Host-only smoke test for `raw::fetch_into`.
 ///
 /// On a host build the underlying sysvar syscall is stubbed out.
+#[cfg(not(feature = "host-runtime"))]
 #[test]
 fn test_fetch_into_host_stub() {
     // 1. Full-size buffer, offset 0.
@@ -177,6 +178,7 @@ fn test_fetch_into_host_stub() {
 /// Test that `fetch_into` with offset correctly avoids interpreting slot
 /// data as entry count.
 #[cfg(test)]
+#[cfg(not(feature = "host-runtime"))]
 #[test]
 fn test_fetch_into_offset_avoids_incorrect_entry_count() {
     // When fetch_into is called with offset != 0, the first
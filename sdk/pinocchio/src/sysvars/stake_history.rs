@@ -0,0 +1,132 @@
+//! History of stake activations and deactivations.
+//!
+//! The stake history sysvar holds up to [`MAX_ENTRIES`] entries, one per epoch,
+//! ordered from the most recent epoch. Since its data is large, [`StakeHistory`]
+//! reads individual entries from the sysvar with [`get_sysvar`] instead of
+//! loading the whole sysvar.
+
+use super::{
+    clock::{Clock, Epoch},
+    get_sysvar, Sysvar,
+};
+use crate::{program_error::ProgramError, pubkey::Pubkey};
+
+/// The ID of the stake history sysvar.
+pub const STAKE_HISTORY_ID: Pubkey = [
+    6, 167, 213, 23, 25, 53, 132, 208, 254, 237, 155, 179, 67, 29, 19, 32, 107, 229, 68, 40, 27,
+    87, 184, 86, 108, 197, 55, 95, 244, 0, 0, 0,
+];
+
+/// The maximum number of entries in the stake history.
+pub const MAX_ENTRIES: usize = 512;
+
+/// Number of bytes used to store the number of entries.
+const NUM_ENTRIES_SIZE: usize = core::mem::size_of::<u64>();
+
+/// Size of an entry in the sysvar data, including its epoch.
+pub const ENTRY_SIZE: usize = 8 + StakeHistoryEntry::LEN;
+
+/// The stake activations and deactivations of an epoch.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StakeHistoryEntry {
+    /// Effective stake at this epoch.
+    pub effective: u64,
+
+    /// Sum of portion of activations this epoch.
+    pub activating: u64,
+
+    /// Sum of portion of deactivations this epoch.
+    pub deactivating: u64,
+}
+
+impl StakeHistoryEntry {
+    /// The length of a `StakeHistoryEntry`.
+    pub const LEN: usize = 8 + 8 + 8;
+}
+
+/// View of the stake history sysvar.
+///
+/// Entries are read on demand relative to the current epoch, since each epoch
+/// adds the entry of the previous epoch at the start of the history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakeHistory {
+    /// The current epoch.
+    current_epoch: Epoch,
+}
+
+impl StakeHistory {
+    /// Creates a new `StakeHistory` view for the given current epoch.
+    #[inline(always)]
+    pub const fn new(current_epoch: Epoch) -> Self {
+        Self { current_epoch }
+    }
+
+    /// Creates a new `StakeHistory` view, loading the current epoch from the
+    /// [`Clock`] sysvar.
+    #[inline]
+    pub fn fetch() -> Result<Self, ProgramError> {
+        Ok(Self::new(Clock::get()?.epoch))
+    }
+
+    /// Return the current epoch of the view.
+    #[inline(always)]
+    pub fn current_epoch(&self) -> Epoch {
+        self.current_epoch
+    }
+
+    /// Return the number of entries in the stake history.
+    #[inline]
+    pub fn len(&self) -> Result<usize, ProgramError> {
+        let mut num_entries = [0u8; NUM_ENTRIES_SIZE];
+        get_sysvar(&mut num_entries, &STAKE_HISTORY_ID, 0)?;
+        Ok(u64::from_le_bytes(num_entries) as usize)
+    }
+
+    /// Check whether the stake history is empty.
+    #[inline]
+    pub fn is_empty(&self) -> Result<bool, ProgramError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Return the entry of the given epoch.
+    ///
+    /// Return `None` when there is no entry for the epoch, i.e., the epoch is the
+    /// current epoch or a future epoch, the epoch is too old to be in the history
+    /// or the history does not include the epoch.
+    pub fn get_entry(&self, target_epoch: Epoch) -> Option<StakeHistoryEntry> {
+        // there is no history at the first epoch
+        let newest_historical_epoch = self.current_epoch.checked_sub(1)?;
+        let oldest_historical_epoch = self.current_epoch.saturating_sub(MAX_ENTRIES as u64);
+
+        if target_epoch < oldest_historical_epoch {
+            return None;
+        }
+
+        // the current or future epochs are not in the history
+        let index = newest_historical_epoch.checked_sub(target_epoch)? as usize;
+
+        let mut entry = [0u8; ENTRY_SIZE];
+        get_sysvar(
+            &mut entry,
+            &STAKE_HISTORY_ID,
+            NUM_ENTRIES_SIZE + index * ENTRY_SIZE,
+        )
+        .ok()?;
+
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(entry[offset..offset + 8].try_into().unwrap());
+
+        // the history skipped an epoch or the view is not at the current epoch of
+        // the cluster
+        if read_u64(0) != target_epoch {
+            return None;
+        }
+
+        Some(StakeHistoryEntry {
+            effective: read_u64(8),
+            activating: read_u64(16),
+            deactivating: read_u64(24),
+        })
+    }
+}